@@ -7,6 +7,11 @@
     jne _fatal_error
     exit 0
 
+; rax = izhodna koda
+_koncaj:
+    mov rbx, rax
+    exit rbx
+
 _push:
     pop rbx
 _push_loop:
@@ -129,9 +134,345 @@ _read4:
     pop  qword [rsp - 8]
     ret
 
+; rax = seme iz spremenljivke okolja SLJ_SEME ali naključno število
+_seme:
+    mov rsi, [zacetni_sklad]
+    mov rax, [rsi]
+    lea rsi, [rsi + rax*8 + 16]
+_seme_okolje:
+    mov rdi, [rsi]
+    cmp rdi, 0
+    je  _seme_nakljucno
+    add rsi, 8
+    mov rdx, seme_ime
+    mov rcx, seme_ime.len
+_seme_primerjaj:
+    mov al, [rdi]
+    cmp al, [rdx]
+    jne _seme_okolje
+    inc rdi
+    inc rdx
+    dec rcx
+    jnz _seme_primerjaj
+
+    ; razčleni desetiško število za '='
+    xor rax, rax
+    xor rbx, rbx
+    cmp byte [rdi], '-'
+    jne _seme_stevke
+    mov rbx, 1
+    inc rdi
+_seme_stevke:
+    movzx rcx, byte [rdi]
+    sub rcx, '0'
+    cmp rcx, 9
+    ja  _seme_konec
+    imul rax, 10
+    add rax, rcx
+    inc rdi
+    jmp _seme_stevke
+_seme_konec:
+    cmp rbx, 0
+    je  _seme_vrni
+    neg rax
+_seme_vrni:
+    movsxd rax, eax
+    ret
+
+_seme_nakljucno:
+    ; getrandom(buf, 4, 0)
+    push 0
+    mov rax, SYS_getrandom
+    mov rdi, rsp
+    mov rsi, 4
+    xor rdx, rdx
+    syscall
+    cmp rax, 0
+    jl _fatal_error
+    pop rax
+    movsxd rax, eax
+    ret
+
+_argc:
+    mov rax, [zacetni_sklad]
+    mov rax, [rax]
+    ret
+
+; rax = indeks argumenta; vrne rax = naslov, rbx = dolžina (-1, če argumenta ni)
+_argument:
+    mov rsi, [zacetni_sklad]
+    cmp rax, 0
+    jl  _ni_niza
+    cmp rax, [rsi]
+    jge _ni_niza
+    mov rsi, [rsi + rax*8 + 8]
+    jmp _niz_iz_c
+
+; rax = naslov imena, rbx = dolžina imena; vrne vrednost spremenljivke okolja kot _argument
+_okolje:
+    mov rdi, [zacetni_sklad]
+    mov rcx, [rdi]
+    lea rdi, [rdi + rcx*8 + 16]
+    mov rdx, rax
+_okolje_naslednja:
+    mov rsi, [rdi]
+    cmp rsi, 0
+    je  _ni_niza
+    add rdi, 8
+    xor rcx, rcx
+_okolje_primerjaj:
+    cmp rcx, rbx
+    je  _okolje_enacaj
+    call _utf8_znak
+    ; znaki niza si na kopici sledijo navzdol
+    mov rax, rcx
+    neg rax
+    cmp r10d, [rdx + rax*8]
+    jne _okolje_naslednja
+    inc rcx
+    jmp _okolje_primerjaj
+_okolje_enacaj:
+    cmp byte [rsi], '='
+    jne _okolje_naslednja
+    inc rsi
+    jmp _niz_iz_c
+
+_ni_niza:
+    xor rax, rax
+    mov rbx, -1
+    ret
+
+; rsi = UTF-8 niz, zaključen z 0; vrne rax = naslov niza na kopici, rbx = dolžina
+_niz_iz_c:
+    push rsi
+    xor rbx, rbx
+_niz_iz_c_dolzina:
+    movzx rax, byte [rsi]
+    cmp rax, 0
+    je  _niz_iz_c_alociraj
+    inc rsi
+    ; nadaljevalni bajti (10xxxxxx) ne začnejo novega znaka
+    and rax, 11000000b
+    cmp rax, 10000000b
+    je  _niz_iz_c_dolzina
+    inc rbx
+    jmp _niz_iz_c_dolzina
+_niz_iz_c_alociraj:
+    mov rax, rbx
+    call _alociraj
+    pop rsi
+    mov rdx, rax
+    mov rdi, rax
+    mov rcx, rbx
+_niz_iz_c_kopiraj:
+    cmp rcx, 0
+    je  _niz_iz_c_konec
+    call _utf8_znak
+    mov [rdi], r10
+    sub rdi, 8
+    dec rcx
+    jmp _niz_iz_c_kopiraj
+_niz_iz_c_konec:
+    mov rax, rdx
+    ret
+
+; rsi = kazalec na UTF-8 znak; vrne r10 = znak (bajti od najnižjega navzgor), rsi kaže za znakom
+_utf8_znak:
+    push rcx
+    movzx r10, byte [rsi]
+    inc rsi
+    mov rcx, 8
+_utf8_znak_nadaljevanje:
+    movzx rax, byte [rsi]
+    mov r11, rax
+    and r11, 11000000b
+    cmp r11, 10000000b
+    jne _utf8_znak_konec
+    shl rax, cl
+    or  r10, rax
+    add rcx, 8
+    inc rsi
+    jmp _utf8_znak_nadaljevanje
+_utf8_znak_konec:
+    pop rcx
+    ret
+
+; rax = naslov poti, rbx = dolžina poti, rcx = način; vrne rax = deskriptor ali -1
+_odpri:
+    mov rdx, rcx
+    cmp edx, 2
+    ja  _neveljaven_deskriptor
+    cmp ebx, pot_buf.cap / 4 - 1
+    ja  _neveljaven_deskriptor
+    ; pot prepiši v UTF-8 niz, zaključen z 0
+    mov rsi, rax
+    mov rdi, pot_buf
+_odpri_znak:
+    cmp ebx, 0
+    je  _odpri_syscall
+    mov eax, [rsi]
+_odpri_bajt:
+    mov [rdi], al
+    inc rdi
+    shr eax, 8
+    jnz _odpri_bajt
+    sub rsi, 8
+    dec ebx
+    jmp _odpri_znak
+_odpri_syscall:
+    mov byte [rdi], 0
+    ; 0 = branje, 1 = pisanje (O_WRONLY | O_CREAT | O_TRUNC), 2 = dodajanje (O_WRONLY | O_CREAT | O_APPEND)
+    xor rsi, rsi
+    mov rax, 0x241
+    cmp edx, 1
+    cmove rsi, rax
+    mov rax, 0x441
+    cmp edx, 2
+    cmove rsi, rax
+    mov rax, SYS_open
+    mov rdi, pot_buf
+    mov rdx, 644o
+    syscall
+    cmp rax, 0
+    jl  _neveljaven_deskriptor
+    ret
+
+; rax = deskriptor; vrne rax = 0 ali -1
+_zapri:
+    movsxd rdi, eax
+    cmp rdi, 3
+    jl  _neveljaven_deskriptor
+    mov rax, SYS_close
+    syscall
+    cmp rax, 0
+    jl  _neveljaven_deskriptor
+    ret
+
+_neveljaven_deskriptor:
+    mov rax, -1
+    ret
+
+; rax = deskriptor; vrne rax = prebran znak ali 0 na koncu datoteke
+_fgetc:
+    movsxd rdi, eax
+    push 0
+    mov rax, SYS_read
+    mov rsi, rsp
+    mov rdx, 1
+    syscall
+    ; število nadaljevalnih bajtov glede na prvi bajt
+    movzx eax, byte [rsp]
+    mov rdx, 1
+    mov ecx, eax
+    and ecx, 11100000b
+    cmp ecx, 11000000b
+    je  _fgetc_nadaljevanje
+    mov rdx, 2
+    mov ecx, eax
+    and ecx, 11110000b
+    cmp ecx, 11100000b
+    je  _fgetc_nadaljevanje
+    mov rdx, 3
+    mov ecx, eax
+    and ecx, 11111000b
+    cmp ecx, 11110000b
+    je  _fgetc_nadaljevanje
+    pop rax
+    ret
+_fgetc_nadaljevanje:
+    mov rax, SYS_read
+    lea rsi, [rsp + 1]
+    syscall
+    pop rax
+    ret
+
+; rax = znak, rbx = deskriptor
+_fputc:
+    movsxd rdi, ebx
+    cmp rdi, 3
+    jl  _fputc_konec
+    jmp _zapisi_znak
+
+; rax = znak, izpiše ga nepomnjeno na standardni izhod za napake
+_pute:
+    mov rdi, STDERR
+
+; rax = znak, rdi = deskriptor
+_zapisi_znak:
+    mov eax, eax
+    push rax
+    ; število bajtov znaka
+    mov rdx, 1
+    mov ecx, eax
+    shr ecx, 8
+_zapisi_znak_stej:
+    cmp ecx, 0
+    je  _zapisi_znak_pisi
+    inc rdx
+    shr ecx, 8
+    jmp _zapisi_znak_stej
+_zapisi_znak_pisi:
+    mov rax, SYS_write
+    mov rsi, rsp
+    syscall
+    pop rax
+_fputc_konec:
+    ret
+
+; vrne rax = nanosekunde monotone ure
+_cas:
+    sub rsp, 16
+    mov rax, SYS_clock_gettime
+    mov rdi, CLOCK_MONOTONIC
+    mov rsi, rsp
+    syscall
+    mov rax, [rsp]
+    imul rax, rax, 1000000000
+    add rax, [rsp + 8]
+    add rsp, 16
+    ret
+
+; rax = število milisekund
+_spi:
+    movsxd rax, eax
+    cmp rax, 0
+    jle _spi_konec
+    xor rdx, rdx
+    mov rcx, 1000
+    div rcx
+    imul rdx, rdx, 1000000
+    ; struct timespec { tv_sec = rax, tv_nsec = rdx }
+    push rdx
+    push rax
+    mov rax, SYS_nanosleep
+    mov rdi, rsp
+    xor rsi, rsi
+    syscall
+    add rsp, 16
+_spi_konec:
+    ret
+
 _fatal_error:
     exit rax
 
+_slice_error:
+    write STDERR, slice_error_msg, slice_error_msg.len
+    exit 1
+
+; rax = število mest, vrne naslov prvega mesta (mesta si sledijo navzdol)
+_alociraj:
+    mov rdi, [kopica.vrh]
+    lea rdi, [rdi + rax*8]
+    cmp rdi, kopica.konec
+    ja  _heap_error
+    mov [kopica.vrh], rdi
+    lea rax, [rdi - 8]
+    ret
+
+_heap_error:
+    write STDERR, heap_error_msg, heap_error_msg.len
+    exit 1
+
 segment readable writeable
 
 struc vec cap
@@ -143,3 +484,22 @@ struc vec cap
 }
 
 stdout_buf vec 512
+pot_buf rb 4096
+pot_buf.cap = $ - pot_buf
+
+slice_error_msg db "Napaka: rezina je izven meja seznama", 10
+slice_error_msg.len = $ - slice_error_msg
+heap_error_msg db "Napaka: zmanjkalo je prostora na kopici", 10
+heap_error_msg.len = $ - heap_error_msg
+
+seme_ime db "SLJ_SEME="
+seme_ime.len = $ - seme_ime
+zacetni_sklad dq ?
+
+fpu.cw dw ?
+fpu.cw_zaokrozi dw ?
+fpu.polovica dd ?
+
+kopica.vrh dq kopica.data
+kopica.data rq 1 shl 20
+kopica.konec = $
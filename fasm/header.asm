@@ -1,10 +1,17 @@
 SYS_read  equ 0
 SYS_write equ 1
+SYS_open  equ 2
+SYS_close equ 3
+SYS_nanosleep equ 35
+SYS_clock_gettime equ 228
+SYS_getrandom equ 318
 
 STDIN  equ 0
 STDOUT equ 1
 STDERR equ 2
 
+CLOCK_MONOTONIC equ 1
+
 ; write(fd, buf, count)
 macro write fd, buf, count
 {
@@ -77,6 +84,140 @@ macro powf
     fstp dword [rsp]
 }
 
+macro powd
+{
+    fld qword [rsp]
+    fld qword [rsp + 8]
+    fyl2x
+    fld1
+    fld st1
+    fprem
+    f2xm1
+    faddp
+    fscale
+    fxch st1
+    pop  qword [rsp - 8]
+    fstp qword [rsp]
+}
+
+;; matematične funkcije na vrhu stacka (real)
+
+macro sqrtf
+{
+    fld  dword [rsp]
+    fsqrt
+    fstp dword [rsp]
+}
+
+macro sinf
+{
+    fld  dword [rsp]
+    fsin
+    fstp dword [rsp]
+}
+
+macro cosf
+{
+    fld  dword [rsp]
+    fcos
+    fstp dword [rsp]
+}
+
+macro tanf
+{
+    fld  dword [rsp]
+    fptan
+    fstp st0
+    fstp dword [rsp]
+}
+
+macro atan2f
+{
+    fld  dword [rsp + 8]
+    fld  dword [rsp]
+    fpatan
+    pop  qword [rsp - 8]
+    fstp dword [rsp]
+}
+
+; e^x = 2^(x log2 e)
+macro expf
+{
+    fldl2e
+    fmul dword [rsp]
+    fld1
+    fld  st1
+    fprem
+    f2xm1
+    faddp
+    fscale
+    fxch st1
+    fstp st0
+    fstp dword [rsp]
+}
+
+macro lnf
+{
+    fldln2
+    fld  dword [rsp]
+    fyl2x
+    fstp dword [rsp]
+}
+
+; zaokroži st0 v načinu: 1 navzdol, 2 navzgor, 3 proti ničli
+macro frnd nacin
+{
+    fnstcw [fpu.cw]
+    mov    ax, [fpu.cw]
+    and    ax, not 0C00h
+    or     ax, nacin shl 10
+    mov    [fpu.cw_zaokrozi], ax
+    fldcw  [fpu.cw_zaokrozi]
+    frndint
+    fldcw  [fpu.cw]
+}
+
+macro floorf
+{
+    fld  dword [rsp]
+    frnd 1
+    fstp dword [rsp]
+}
+
+macro ceilf
+{
+    fld  dword [rsp]
+    frnd 2
+    fstp dword [rsp]
+}
+
+; polovice zaokroži stran od ničle: trunc(x + copysign(0.5, x))
+macro roundf
+{
+    mov  eax, [rsp]
+    and  eax, 80000000h
+    or   eax, 3F000000h
+    mov  [fpu.polovica], eax
+    fld  dword [rsp]
+    fadd dword [fpu.polovica]
+    frnd 3
+    fstp dword [rsp]
+}
+
+macro absf
+{
+    and dword [rsp], 7FFFFFFFh
+}
+
+macro absi
+{
+    mov  eax, [rsp]
+    mov  ebx, eax
+    neg  eax
+    cmovs eax, ebx
+    mov  [rsp], rax
+}
+
 macro exit code
 {
     ; izprazni write buffer
@@ -93,6 +234,8 @@ use64
 segment readable executable
 
 entry $
+    ; [rsp] = argc, sledijo argv, 0, envp, 0
+    mov [zacetni_sklad], rsp
     ; addroff = SP
 	lea r8, [rsp - 8]
 	lea r9, [rsp - 8]
@@ -0,0 +1,10 @@
+use std::{io, process};
+
+fn main() {
+    let koda = slj::lsp::zaženi(&mut io::stdin().lock(), &mut io::stdout().lock())
+        .unwrap_or_else(|napaka| {
+            eprintln!("Napaka: {napaka}");
+            1
+        });
+    process::exit(koda);
+}
@@ -1,13 +1,27 @@
-use std::{env, fs};
+use std::{env, fs, mem, process};
+use std::io::{self, BufRead, BufWriter, Cursor, IsTerminal, Read, Write};
 
-use slj::parser::{lekser::Razčleni, Parse};
-use slj::program::ToProgram;
+use slj::parser::{lekser::Razčleni, lupina::{Lupina, Vnos}, napaka::OblikaNapak, oblikuj::Oblikuj, Parse};
+use slj::program::{Izid, Stroj, ToProgram};
 
 fn main() {
     let args: Vec<String> = env::args().collect();
     if args.len() < 2 {
-        pomoč(&args[0]);
-        return;
+        process::exit(lupina());
+    }
+
+    // vse za "--" so argumenti programa
+    let (args, argumenti_programa) = match args.iter().position(|arg| arg == "--") {
+        Some(i) => (&args[..i], &args[i + 1..]),
+        None => (&args[..], [].as_slice()),
+    };
+
+    if args[1] == "fmt" {
+        process::exit(oblikuj(&args[2..]));
+    }
+
+    if args[1] == "preizkusi" && args.len() == 3 {
+        process::exit(preizkusi(&args[2]));
     }
 
     let možnosti = analiziraj_možnosti(&args[1..]);
@@ -22,46 +36,240 @@ fn main() {
     let datoteka = fs::read_to_string(ime)
         .expect("Napaka: ne morem odpreti datoteke");
 
-    let drevo = datoteka
+    let žetoni = datoteka
         .as_str()
-        .razčleni(ime)
-        .analiziraj();
+        .razčleni(ime);
+
+    let drevo = if možnosti.izdaja {
+        žetoni.analiziraj_brez_trditev()
+    }
+    else {
+        žetoni.analiziraj()
+    };
 
     match drevo {
         Ok(drevo) => {
-            if možnosti.debug {
-                drevo.v_program().zaženi_debug();
+            // opozorila ne ustavijo prevajanja
+            if !drevo.opozorila.prazno() {
+                drevo.opozorila.izpiši_v_obliki(možnosti.oblika_napak);
             }
-            else {
-                drevo.v_program().zaženi();
+
+            let program = drevo
+                .v_program()
+                .z_argumenti([ime.clone()].into_iter().chain(argumenti_programa.iter().cloned()).collect());
+
+            let koda = if možnosti.debug {
+                program.zaženi_debug()
             }
+            else {
+                program.zaženi()
+            };
+            process::exit(koda);
         },
+        Err(napake) => {
+            napake.izpiši_v_obliki(možnosti.oblika_napak);
+            process::exit(1);
+        }
+    }
+
+}
+
+// zažene vse preizkuse v datoteki, vrne izhodno kodo
+fn preizkusi(ime: &String) -> i32 {
+    let datoteka = fs::read_to_string(ime)
+        .expect("Napaka: ne morem odpreti datoteke");
+
+    let preizkusi = match datoteka.as_str().razčleni(ime).analiziraj_preizkuse() {
+        Ok(preizkusi) => preizkusi,
         Err(napake) => {
             napake.izpiši();
+            return 1;
+        },
+    };
+
+    let št_preizkusov = preizkusi.len();
+    let mut neuspešni = 0;
+    for (ime_preizkusa, drevo) in preizkusi {
+        let mut izhod = Vec::<u8>::new();
+        let mut napake = Vec::<u8>::new();
+
+        let koda = drevo
+            .v_program()
+            .z_argumenti(vec![ime.clone()])
+            .zaženi_z_io(&mut Cursor::new(""), &mut izhod, &mut napake);
+
+        if koda == 0 {
+            println!("preizkus {ime_preizkusa} ... uspel");
+        }
+        else {
+            neuspešni += 1;
+            println!("preizkus {ime_preizkusa} ... NI USPEL (koda {koda})");
+            print!("{}", String::from_utf8_lossy(&izhod));
+            eprint!("{}", String::from_utf8_lossy(&napake));
         }
     }
 
+    println!("\n{} uspelo, {neuspešni} ni uspelo", št_preizkusov - neuspešni);
+    if neuspešni == 0 { 0 } else { 1 }
+}
+
+// oblikuje datoteke na mestu (brez poti standardni vhod na standardni izhod),
+// z --preveri samo izpiše neoblikovane datoteke in vrne 1
+fn oblikuj(args: &[String]) -> i32 {
+    let preveri = args.iter().any(|arg| arg == "--preveri");
+    let poti = args.iter()
+        .filter(|arg| *arg != "--preveri")
+        .collect::<Vec<&String>>();
+
+    if poti.is_empty() {
+        let mut koda = String::new();
+        if io::stdin().read_to_string(&mut koda).is_err() {
+            eprintln!("Napaka: ne morem brati standardnega vhoda");
+            return 1;
+        }
+        let oblikovana = koda.oblikuj();
+        if !preveri {
+            print!("{oblikovana}");
+        }
+        return if preveri && oblikovana != koda { 1 } else { 0 };
+    }
+
+    let mut neoblikovane = 0;
+    for pot in poti {
+        let koda = match fs::read_to_string(pot) {
+            Ok(koda) => koda,
+            Err(_) => {
+                eprintln!("Napaka: ne morem odpreti datoteke '{pot}'");
+                return 1;
+            },
+        };
+
+        let oblikovana = koda.oblikuj();
+        if oblikovana == koda {
+            continue;
+        }
+
+        if preveri {
+            println!("{pot}: ni oblikovana");
+            neoblikovane += 1;
+        }
+        else if fs::write(pot, oblikovana).is_err() {
+            eprintln!("Napaka: ne morem pisati v datoteko '{pot}'");
+            return 1;
+        }
+    }
+
+    if neoblikovane == 0 { 0 } else { 1 }
+}
+
+// interaktivna lupina, bere vnose s standardnega vhoda do konca vhoda ali klica končaj
+fn lupina() -> i32 {
+    let (mut lupina, standard) = match Lupina::new() {
+        Ok(lupina) => lupina,
+        Err(napake) => {
+            napake.izpiši();
+            return 1;
+        },
+    };
+
+    let mut stroj = Stroj::default();
+    let mut vhod = io::stdin().lock();
+    let mut izhod = BufWriter::new(io::stdout());
+    let mut napake = io::stderr();
+    let interaktivno = vhod.is_terminal();
+
+    if let Izid::Končaj(koda) = stroj.zaženi(standard, &mut vhod, &mut izhod, &mut napake) {
+        return koda;
+    }
+
+    let mut vnos = String::new();
+    loop {
+        if interaktivno {
+            let _ = write!(izhod, "{}", if vnos.is_empty() { "> " } else { ". " });
+        }
+        let _ = izhod.flush();
+
+        match vhod.read_line(&mut vnos) {
+            Ok(0) | Err(_) => return 0,
+            Ok(_) => (),
+        }
+        if Lupina::nedokončano(&vnos) {
+            continue;
+        }
+        let vnos = mem::take(&mut vnos);
+
+        if let Some(ukaz) = vnos.trim_start().strip_prefix(':') {
+            let _ = ukaz_lupine(&lupina, ukaz, &mut izhod, &mut napake);
+            continue;
+        }
+
+        let izid = match lupina.analiziraj(vnos) {
+            Ok(Vnos::Izraz(drevo)) => {
+                let izid = stroj.zaženi(drevo, &mut vhod, &mut izhod, &mut napake);
+                // izpisana vrednost se konča z novo vrstico, po napaki vrednosti ni
+                if izid == Izid::Uspeh {
+                    let _ = writeln!(izhod);
+                }
+                izid
+            },
+            Ok(Vnos::Zaporedje(drevo)) => stroj.zaženi(drevo, &mut vhod, &mut izhod, &mut napake),
+            Err(n) => {
+                let _ = n.izpiši_kratko(&mut napake);
+                Izid::Napaka
+            },
+        };
+
+        if let Izid::Končaj(koda) = izid {
+            return koda;
+        }
+    }
+}
+
+// :tip <izraz> izpiše tip izraza, :drevo <izraz> njegovo drevo
+fn ukaz_lupine(lupina: &Lupina, ukaz: &str, izhod: &mut impl Write, napake: &mut impl Write) -> io::Result<()> {
+    let (ukaz, izraz) = ukaz.trim().split_once(char::is_whitespace).unwrap_or((ukaz.trim(), ""));
+
+    match ukaz {
+        "tip" => match lupina.tip(izraz) {
+            Ok(tip) => writeln!(izhod, "{tip}"),
+            Err(n) => n.izpiši_kratko(napake),
+        },
+        "drevo" => match lupina.drevo_izraza(izraz) {
+            Ok(drevo) => write!(izhod, "{drevo}"),
+            Err(n) => n.izpiši_kratko(napake),
+        },
+        _ => writeln!(napake, "Neznan ukaz: ':{ukaz}' (na voljo sta :tip in :drevo)"),
+    }
 }
 
 struct Možnosti {
     pomoč: bool,
     debug: bool,
+    izdaja: bool,
+    oblika_napak: OblikaNapak,
 }
 
 impl Možnosti {
     fn new() -> Možnosti {
         Možnosti {
             pomoč: false,
-            debug: false
+            debug: false,
+            izdaja: false,
+            oblika_napak: OblikaNapak::Besedilo,
         }
     }
 }
 
 fn pomoč(ukaz: &String) {
-        println!("Ukaz: {ukaz} [možnosti] <pot>");
+        println!("Ukaz: {ukaz} [možnosti] <pot> [-- argumenti programa]");
+        println!("      {ukaz}: interaktivna lupina");
+        println!("      {ukaz} preizkusi <pot>: zaženi preizkuse v datoteki");
+        println!("      {ukaz} fmt [--preveri] [poti]: oblikuj izvorno kodo (--preveri: samo preveri, ali je oblikovana)");
         println!("[možnosti]:");
         println!("\t-p, --pomoč: izpiši to pomoč,");
-        println!("\t-d, --debug: namesto izhoda programa izpisuj ukaze in stanje stacka pa vsakem ukazu.");
+        println!("\t-d, --debug: namesto izhoda programa izpisuj ukaze in stanje stacka pa vsakem ukazu,");
+        println!("\t-i, --izdaja: izpusti trditve (trdi),");
+        println!("\t--oblika-napak=<besedilo|json|sarif>: oblika izpisa napak in opozoril.");
 }
 
 fn analiziraj_možnosti(args: &[String]) -> Možnosti {
@@ -70,13 +278,21 @@ fn analiziraj_možnosti(args: &[String]) -> Možnosti {
         match arg.as_str() {
             "--pomoč" => možnosti.pomoč = true,
             "--debug" => možnosti.debug = true,
-            _ => if arg.starts_with("--") {
+            "--izdaja" => možnosti.izdaja = true,
+            _ => if let Some(oblika) = arg.strip_prefix("--oblika-napak=") {
+                možnosti.oblika_napak = OblikaNapak::iz_niza(oblika).unwrap_or_else(|| {
+                    eprintln!("Napaka: neznana oblika napak '{oblika}', možne oblike so: besedilo, json, sarif");
+                    process::exit(2);
+                });
+            }
+            else if arg.starts_with("--") {
                 panic!("Neznana možnost: '{arg}'");
             }
             else if arg.starts_with('-') {
                 for znak in &arg.chars().collect::<Vec<char>>()[1..] {
                     if *znak == 'p' { možnosti.pomoč = true }
                     else if *znak == 'd' { možnosti.debug = true }
+                    else if *znak == 'i' { možnosti.izdaja = true }
                     else { panic!("Neznana možnost: '{znak}'") };
                 }
             },
@@ -4,9 +4,9 @@ use std::fs;
 use std::path::Path;
 use std::process::Stdio;
 use std::{fs::File, io::Write};
-use std::process::Command;
+use std::process::{self, Command};
 
-use slj::parser::{lekser::Razčleni, Parse};
+use slj::parser::{lekser::Razčleni, napaka::OblikaNapak, Parse};
 use slj::program::ToFasmX86;
 
 fn main() -> std::io::Result<()> {
@@ -16,6 +16,12 @@ fn main() -> std::io::Result<()> {
         return Ok(());
     }
 
+    // vse za "--" so argumenti programa
+    let (args, argumenti_programa) = match args.iter().position(|arg| arg == "--") {
+        Some(i) => (&args[..i], &args[i + 1..]),
+        None => (&args[..], [].as_slice()),
+    };
+
     let možnosti = analiziraj_možnosti(&args[1..]);
 
     if možnosti.pomoč {
@@ -28,13 +34,24 @@ fn main() -> std::io::Result<()> {
     let datoteka = fs::read_to_string(ime)
         .expect("Napaka: ne morem odpreti datoteke");
 
-    let drevo = datoteka
+    let žetoni = datoteka
         .as_str()
-        .razčleni(ime)
-        .analiziraj();
+        .razčleni(ime);
+
+    let drevo = if možnosti.izdaja {
+        žetoni.analiziraj_brez_trditev()
+    }
+    else {
+        žetoni.analiziraj()
+    };
 
     match drevo {
         Ok(drevo) => {
+            // opozorila ne ustavijo prevajanja
+            if !drevo.opozorila.prazno() {
+                drevo.opozorila.izpiši_v_obliki(možnosti.oblika_napak);
+            }
+
             // transform AST into native x86_64 assembly
             let fasm = drevo
                 .v_fasm_x86(2);
@@ -72,6 +89,7 @@ fn main() -> std::io::Result<()> {
 
             // run compiled binary
             let status = Command::new(filename)
+                .args(argumenti_programa)
                 .stdin(Stdio::inherit())
                 .stdout(Stdio::inherit())
                 .stderr(Stdio::inherit())
@@ -80,13 +98,14 @@ fn main() -> std::io::Result<()> {
 
             match status.code() {
                 Some(0) => Ok(()),
-                Some(code) => Err(io::Error::new(io::ErrorKind::Other, format!("program failed with exit code {code}"))),
+                // izhodno kodo programa posredujemo naprej
+                Some(code) => process::exit(code),
                 None => Err(io::Error::new(io::ErrorKind::Other, "program failed")),
             }
         },
         Err(napake) => {
-            napake.izpiši();
-            Ok(())
+            napake.izpiši_v_obliki(možnosti.oblika_napak);
+            process::exit(1);
         }
     }
 
@@ -95,22 +114,28 @@ fn main() -> std::io::Result<()> {
 struct Možnosti {
     pomoč: bool,
     zaženi: bool,
+    izdaja: bool,
+    oblika_napak: OblikaNapak,
 }
 
 impl Možnosti {
     fn new() -> Možnosti {
         Možnosti {
             pomoč: false,
-            zaženi: false
+            zaženi: false,
+            izdaja: false,
+            oblika_napak: OblikaNapak::Besedilo,
         }
     }
 }
 
 fn pomoč(ukaz: &String) {
-        println!("Ukaz: {ukaz} [možnosti] <pot>");
+        println!("Ukaz: {ukaz} [možnosti] <pot> [-- argumenti programa]");
         println!("[možnosti]:");
         println!("\t-p, --pomoč: izpiši to pomoč,");
-        println!("\t-r, --run: po prevajanju zeženi program.");
+        println!("\t-r, --run: po prevajanju zeženi program,");
+        println!("\t-i, --izdaja: izpusti trditve (trdi),");
+        println!("\t--oblika-napak=<besedilo|json|sarif>: oblika izpisa napak in opozoril.");
 }
 
 fn analiziraj_možnosti(args: &[String]) -> Možnosti {
@@ -119,13 +144,21 @@ fn analiziraj_možnosti(args: &[String]) -> Možnosti {
         match arg.as_str() {
             "--pomoč" => možnosti.pomoč = true,
             "--zaženi" => možnosti.zaženi = true,
-            _ => if arg.starts_with("--") {
+            "--izdaja" => možnosti.izdaja = true,
+            _ => if let Some(oblika) = arg.strip_prefix("--oblika-napak=") {
+                možnosti.oblika_napak = OblikaNapak::iz_niza(oblika).unwrap_or_else(|| {
+                    eprintln!("Napaka: neznana oblika napak '{oblika}', možne oblike so: besedilo, json, sarif");
+                    process::exit(2);
+                });
+            }
+            else if arg.starts_with("--") {
                 panic!("Neznana možnost: '{arg}'");
             }
             else if arg.starts_with('-') {
                 for znak in &arg.chars().collect::<Vec<char>>()[1..] {
                     if *znak == 'p' { možnosti.pomoč = true }
                     else if *znak == 'z' { možnosti.zaženi = true }
+                    else if *znak == 'i' { možnosti.izdaja = true }
                     else { panic!("Neznana možnost: '{znak}'") };
                 }
             },
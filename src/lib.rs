@@ -1,3 +1,4 @@
 pub mod program;
 pub mod parser;
+pub mod lsp;
 
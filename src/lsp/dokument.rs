@@ -0,0 +1,237 @@
+use std::panic;
+
+use serde_json::{json, Value};
+
+use crate::parser::{Parse, lekser::{Razčleni, Žeton::{self, *}}};
+use crate::parser::simboli::{Analiza, Definicija, Mesto, Vrsta};
+
+// odprta datoteka v urejevalniku in zadnja uspešna analiza njene vsebine
+pub struct Dokument {
+    uri: String,
+    besedilo: String,
+    analiza: Option<Analiza>,
+}
+
+impl Dokument {
+    pub fn new(uri: &str, besedilo: &str) -> Dokument {
+        let mut dokument = Dokument { uri: uri.to_string(), besedilo: String::new(), analiza: None };
+        dokument.posodobi(besedilo);
+        dokument
+    }
+
+    pub fn posodobi(&mut self, besedilo: &str) {
+        self.besedilo = besedilo.to_string();
+
+        // nedokončana koda lahko sproži paniko v razčlenjevalniku, takrat obdržimo prejšnjo analizo
+        let analiza = panic::catch_unwind(|| self.besedilo.as_str().razčleni(&self.uri).analiziraj_simbole());
+        if let Ok(mut analiza) = analiza {
+            // ob napaki, ki ustavi razčlenjevanje (npr. neujemajoči se oklepaji), ostanejo znane funkcije
+            if let (true, Some(prejšnja)) = (analiza.funkcije.is_empty(), self.analiza.take()) {
+                analiza.funkcije = prejšnja.funkcije;
+            }
+            self.analiza = Some(analiza);
+        }
+    }
+
+    pub fn diagnostike(&self) -> Value {
+        let napake = self.analiza.iter()
+            .flat_map(|analiza| analiza.napake.iter())
+            .map(|napaka| {
+                // napake zunaj dokumenta (npr. v jedru) pokažemo na začetku
+                let (začetek, konec) = if napaka.datoteka == self.uri {
+                    (napaka.začetek, napaka.konec)
+                }
+                else {
+                    ((1, 1), (1, 1))
+                };
+
+                json!({
+                    "range": { "start": self.v_lsp(začetek), "end": self.v_lsp(konec) },
+                    "severity": if napaka.oznaka.je_opozorilo() { 2 } else { 1 },
+                    "code": format!("{:?}", napaka.oznaka),
+                    "source": "slj",
+                    "message": napaka.sporočilo,
+                })
+            })
+            .collect::<Vec<Value>>();
+
+        json!(napake)
+    }
+
+    pub fn opis(&self, pozicija: &Value) -> Value {
+        let Some(analiza) = &self.analiza else { return Value::Null };
+        let mesto = self.iz_lsp(pozicija);
+
+        let mut zadetki = analiza.sklici.iter()
+            .map(|sklic| (&sklic.mesto, &sklic.opis))
+            .chain(analiza.definicije.iter().map(|definicija| (&definicija.mesto, &definicija.opis)))
+            .filter(|(m, _)| m.datoteka == self.uri && m.vsebuje(mesto));
+
+        let Some((mesto, opis)) = zadetki.next() else { return Value::Null };
+
+        // klic z več preobložitvami (natisni!(a, b)) ima več opisov na istem mestu
+        let mut opisi = vec![opis.clone()];
+        for (m, opis) in zadetki {
+            if m == mesto && !opisi.contains(opis) {
+                opisi.push(opis.clone());
+            }
+        }
+
+        json!({
+            "contents": { "kind": "markdown", "value": format!("```slj\n{}\n```", opisi.join("\n")) },
+            "range": self.obseg(mesto),
+        })
+    }
+
+    pub fn definicija(&self, pozicija: &Value) -> Value {
+        let Some(analiza) = &self.analiza else { return Value::Null };
+        let mesto = self.iz_lsp(pozicija);
+
+        let sklic = analiza.sklici.iter()
+            .filter(|sklic| sklic.mesto.datoteka == self.uri && sklic.mesto.vsebuje(mesto))
+            .find_map(|sklic| sklic.definicija.as_ref());
+        let definicija = analiza.definicije.iter()
+            .map(|definicija| &definicija.mesto)
+            .find(|m| m.datoteka == self.uri && m.vsebuje(mesto));
+
+        match sklic.or(definicija) {
+            // definicije v jedru niso v nobeni datoteki, ki bi jo urejevalnik lahko odprl
+            Some(definicija) if definicija.datoteka == self.uri => json!({
+                "uri": self.uri,
+                "range": self.obseg(definicija),
+            }),
+            _ => Value::Null,
+        }
+    }
+
+    pub fn dopolnitve(&self, pozicija: &Value) -> Value {
+        let Some(analiza) = &self.analiza else { return Value::Null };
+        let mesto = self.iz_lsp(pozicija);
+
+        // vsaka preobložitev je svoja izbira
+        let funkcije = analiza.funkcije.iter()
+            .filter(|podpis| !podpis.ime.starts_with('_'))
+            .map(|podpis| json!({
+                "label": podpis.ime,
+                "kind": 3,
+                "detail": podpis.to_string(),
+            }));
+
+        // vidne so definicije pred kazalcem, katerih doseg se še ni končal; kasnejše zakrijejo prejšnje
+        let mut vidne: Vec<&Definicija> = Vec::new();
+        for definicija in analiza.definicije.iter().rev() {
+            let vidna = definicija.vrsta != Vrsta::Funkcija
+                && definicija.mesto.datoteka == self.uri
+                && definicija.mesto.konec < mesto && mesto <= definicija.doseg
+                && !definicija.ime.starts_with('_');
+
+            if vidna && !vidne.iter().any(|d| d.ime == definicija.ime) {
+                vidne.push(definicija);
+            }
+        }
+        let spremenljivke = vidne.iter()
+            .map(|definicija| json!({
+                "label": definicija.ime,
+                "kind": if definicija.vrsta == Vrsta::Konstanta { 21 } else { 6 },
+                "detail": definicija.opis,
+            }));
+
+        json!({
+            "isIncomplete": false,
+            "items": spremenljivke.chain(funkcije).collect::<Vec<Value>>(),
+        })
+    }
+
+    pub fn podpisi(&self, pozicija: &Value) -> Value {
+        let Some(analiza) = &self.analiza else { return Value::Null };
+        let mesto = self.iz_lsp(pozicija);
+
+        let žetoni = self.besedilo.as_str().razčleni(&self.uri);
+        let pred = žetoni.iter()
+            .take_while(|žeton| žeton.lokacija() < mesto)
+            .collect::<Vec<&Žeton>>();
+
+        let Some((ime, parameter)) = klic_pod_kazalcem(&pred) else { return Value::Null };
+
+        let podpisi = analiza.funkcije.iter()
+            .filter(|podpis| podpis.ime == ime)
+            .collect::<Vec<_>>();
+        if podpisi.is_empty() {
+            return Value::Null;
+        }
+
+        let aktivni = podpisi.iter()
+            .position(|podpis| podpis.parametri.len() > parameter)
+            .unwrap_or(0);
+
+        json!({
+            "signatures": podpisi.iter().map(|podpis| json!({
+                "label": podpis.to_string(),
+                "parameters": podpis.parametri.iter()
+                    .map(|(ime, tip)| json!({ "label": format!("{ime}: {tip}") }))
+                    .collect::<Vec<Value>>(),
+            })).collect::<Vec<Value>>(),
+            "activeSignature": aktivni,
+            "activeParameter": parameter,
+        })
+    }
+
+    fn obseg(&self, mesto: &Mesto) -> Value {
+        json!({ "start": self.v_lsp(mesto.začetek), "end": self.v_lsp(mesto.konec) })
+    }
+
+    // LSP šteje vrstice od 0, znake pa v enotah UTF-16
+    fn v_lsp(&self, (vrstica, znak): (usize, usize)) -> Value {
+        let znaki = self.besedilo.lines()
+            .nth(vrstica.saturating_sub(1))
+            .unwrap_or("")
+            .chars()
+            .take(znak.saturating_sub(1))
+            .map(char::len_utf16)
+            .sum::<usize>();
+
+        json!({ "line": vrstica.saturating_sub(1), "character": znaki })
+    }
+
+    fn iz_lsp(&self, pozicija: &Value) -> (usize, usize) {
+        let vrstica = pozicija["line"].as_u64().unwrap_or(0) as usize;
+        let enote = pozicija["character"].as_u64().unwrap_or(0) as usize;
+
+        let mut dolžina = 0;
+        let znaki = self.besedilo.lines()
+            .nth(vrstica)
+            .unwrap_or("")
+            .chars()
+            .take_while(|znak| {
+                dolžina += znak.len_utf16();
+                dolžina <= enote
+            })
+            .count();
+
+        (vrstica + 1, znaki + 1)
+    }
+}
+
+// ime funkcije, v klicu katere je kazalec, in indeks parametra pod njim
+fn klic_pod_kazalcem<'a>(pred: &[&Žeton<'a>]) -> Option<(&'a str, usize)> {
+    let mut globina = 0;
+    let mut parameter = 0;
+
+    for (i, žeton) in pred.iter().enumerate().rev() {
+        match žeton {
+            Ločilo(")" | "]", ..) => globina += 1,
+            Ločilo("(" | "[", ..) if globina > 0 => globina -= 1,
+            Ločilo(",", ..) if globina == 0 => parameter += 1,
+            Ločilo("(", ..) => match pred[..i] {
+                [.., Ime(ime, ..)] => return Some((ime, parameter)),
+                // pri multifunkcijskem klicu se vsak argument poda svojemu klicu
+                [.., Ime(ime, ..), Operator("!", ..)] => return Some((ime, 0)),
+                // oklepaj v izrazu je del argumenta zunanjega klica
+                _ => parameter = 0,
+            },
+            Ločilo("[", ..) => parameter = 0,
+            _ => (),
+        }
+    }
+    None
+}
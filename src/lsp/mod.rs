@@ -0,0 +1,369 @@
+mod dokument;
+
+use std::collections::HashMap;
+use std::io::{self, BufRead, Write};
+
+use serde_json::{json, Value};
+
+use dokument::Dokument;
+
+// strežnik za Language Server Protocol; sporočila JSON-RPC obdeluje enega za drugim
+#[derive(Default)]
+pub struct Strežnik {
+    dokumenti: HashMap<String, Dokument>,
+    ugasnjen: bool,
+    končan: bool,
+}
+
+impl Strežnik {
+    // vrne odgovor na zahtevo in obvestila (diagnostike), ki jih je treba poslati odjemalcu
+    pub fn obdelaj(&mut self, sporočilo: &Value) -> Vec<Value> {
+        let id = sporočilo.get("id").cloned();
+        let parametri = &sporočilo["params"];
+
+        let metoda = match sporočilo["method"].as_str() {
+            Some(metoda) => metoda,
+            // odgovori odjemalca na naše zahteve
+            None => return vec![],
+        };
+
+        let Some(id) = id else {
+            return self.obvestilo(metoda, parametri);
+        };
+
+        let rezultat = match metoda {
+            "initialize" => json!({
+                "capabilities": {
+                    "textDocumentSync": 1,
+                    "hoverProvider": true,
+                    "definitionProvider": true,
+                    "completionProvider": { "triggerCharacters": [] },
+                    "signatureHelpProvider": { "triggerCharacters": ["(", ","] },
+                },
+                "serverInfo": { "name": "slj-lsp", "version": env!("CARGO_PKG_VERSION") },
+            }),
+            "shutdown" => {
+                self.ugasnjen = true;
+                Value::Null
+            },
+            "textDocument/hover" => self.z_dokumentom(parametri, Dokument::opis),
+            "textDocument/definition" => self.z_dokumentom(parametri, Dokument::definicija),
+            "textDocument/completion" => self.z_dokumentom(parametri, Dokument::dopolnitve),
+            "textDocument/signatureHelp" => self.z_dokumentom(parametri, Dokument::podpisi),
+            _ => return vec![json!({
+                "jsonrpc": "2.0",
+                "id": id,
+                "error": { "code": -32601, "message": format!("Neznana metoda: '{metoda}'") },
+            })],
+        };
+
+        vec![json!({ "jsonrpc": "2.0", "id": id, "result": rezultat })]
+    }
+
+    pub fn končan(&self) -> bool {
+        self.končan
+    }
+
+    // izhodna koda po obvestilu "exit" je 0 le, če je bil strežnik prej ugasnjen
+    pub fn izhodna_koda(&self) -> i32 {
+        if self.ugasnjen { 0 } else { 1 }
+    }
+
+    fn obvestilo(&mut self, metoda: &str, parametri: &Value) -> Vec<Value> {
+        let uri = parametri["textDocument"]["uri"].as_str().unwrap_or_default();
+
+        match metoda {
+            "exit" => {
+                self.končan = true;
+                vec![]
+            },
+            "textDocument/didOpen" => {
+                let besedilo = parametri["textDocument"]["text"].as_str().unwrap_or_default();
+                self.dokumenti.insert(uri.to_string(), Dokument::new(uri, besedilo));
+                vec![self.diagnostike(uri)]
+            },
+            "textDocument/didChange" => {
+                // dokument se vedno sinhronizira v celoti, zato šteje le zadnja sprememba
+                let besedilo = parametri["contentChanges"].as_array()
+                    .and_then(|spremembe| spremembe.last())
+                    .and_then(|sprememba| sprememba["text"].as_str());
+
+                match (self.dokumenti.get_mut(uri), besedilo) {
+                    (Some(dokument), Some(besedilo)) => {
+                        dokument.posodobi(besedilo);
+                        vec![self.diagnostike(uri)]
+                    },
+                    _ => vec![],
+                }
+            },
+            "textDocument/didClose" => {
+                self.dokumenti.remove(uri);
+                vec![self.diagnostike(uri)]
+            },
+            _ => vec![],
+        }
+    }
+
+    fn diagnostike(&self, uri: &str) -> Value {
+        let diagnostike = match self.dokumenti.get(uri) {
+            Some(dokument) => dokument.diagnostike(),
+            None => json!([]),
+        };
+
+        json!({
+            "jsonrpc": "2.0",
+            "method": "textDocument/publishDiagnostics",
+            "params": { "uri": uri, "diagnostics": diagnostike },
+        })
+    }
+
+    fn z_dokumentom(&self, parametri: &Value, f: fn(&Dokument, &Value) -> Value) -> Value {
+        let uri = parametri["textDocument"]["uri"].as_str().unwrap_or_default();
+        match self.dokumenti.get(uri) {
+            Some(dokument) => f(dokument, &parametri["position"]),
+            None => Value::Null,
+        }
+    }
+}
+
+// bere sporočila z vhoda in odgovarja na izhod, dokler ne prejme "exit"; vrne izhodno kodo
+pub fn zaženi(vhod: &mut impl BufRead, izhod: &mut impl Write) -> io::Result<i32> {
+    let mut strežnik = Strežnik::default();
+
+    while let Some(sporočilo) = preberi_sporočilo(vhod)? {
+        let odgovori = match serde_json::from_slice::<Value>(&sporočilo) {
+            Ok(sporočilo) => strežnik.obdelaj(&sporočilo),
+            Err(napaka) => vec![json!({
+                "jsonrpc": "2.0",
+                "id": null,
+                "error": { "code": -32700, "message": napaka.to_string() },
+            })],
+        };
+
+        for odgovor in odgovori {
+            pošlji_sporočilo(izhod, &odgovor)?;
+        }
+        if strežnik.končan() {
+            return Ok(strežnik.izhodna_koda());
+        }
+    }
+    Ok(strežnik.izhodna_koda())
+}
+
+// sporočilo je glava "Content-Length: n", prazna vrstica in n bajtov vsebine
+fn preberi_sporočilo(vhod: &mut impl BufRead) -> io::Result<Option<Vec<u8>>> {
+    let mut dolžina = None;
+
+    loop {
+        let mut vrstica = String::new();
+        if vhod.read_line(&mut vrstica)? == 0 {
+            return Ok(None);
+        }
+
+        let vrstica = vrstica.trim_end();
+        if vrstica.is_empty() {
+            break;
+        }
+        if let Some((ime, vrednost)) = vrstica.split_once(':') {
+            if ime.eq_ignore_ascii_case("Content-Length") {
+                dolžina = vrednost.trim().parse::<usize>().ok();
+            }
+        }
+    }
+
+    let Some(dolžina) = dolžina else {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "Manjka glava Content-Length"));
+    };
+
+    let mut vsebina = vec![0; dolžina];
+    vhod.read_exact(&mut vsebina)?;
+    Ok(Some(vsebina))
+}
+
+fn pošlji_sporočilo(izhod: &mut impl Write, sporočilo: &Value) -> io::Result<()> {
+    let vsebina = sporočilo.to_string();
+    write!(izhod, "Content-Length: {}\r\n\r\n{vsebina}", vsebina.len())?;
+    izhod.flush()
+}
+
+#[cfg(test)]
+mod testi {
+    use std::io::Cursor;
+
+    use super::*;
+
+    const URI: &str = "file:///test.slj";
+    const PROGRAM: &str = "funkcija kvadrat(a: celo) -> celo {\n    vrni a * a\n}\nnaj x = 5\nnatisni(kvadrat(x))\nnaj y = z\n";
+
+    fn zahteva(id: i32, metoda: &str, parametri: Value) -> Value {
+        json!({ "jsonrpc": "2.0", "id": id, "method": metoda, "params": parametri })
+    }
+
+    fn obvestilo(metoda: &str, parametri: Value) -> Value {
+        json!({ "jsonrpc": "2.0", "method": metoda, "params": parametri })
+    }
+
+    fn na_mestu(vrstica: u32, znak: u32) -> Value {
+        json!({ "textDocument": { "uri": URI }, "position": { "line": vrstica, "character": znak } })
+    }
+
+    fn odprt(program: &str) -> Strežnik {
+        let mut strežnik = Strežnik::default();
+        strežnik.obdelaj(&zahteva(1, "initialize", json!({})));
+        strežnik.obdelaj(&obvestilo("textDocument/didOpen", json!({
+            "textDocument": { "uri": URI, "languageId": "slj", "version": 1, "text": program },
+        })));
+        strežnik
+    }
+
+    fn rezultat(strežnik: &mut Strežnik, metoda: &str, parametri: Value) -> Value {
+        strežnik.obdelaj(&zahteva(2, metoda, parametri)).remove(0)["result"].take()
+    }
+
+    #[test]
+    fn sporočila() {
+        let vhod = [
+            zahteva(1, "initialize", json!({})),
+            obvestilo("initialized", json!({})),
+            zahteva(2, "neznano", json!({})),
+            zahteva(3, "shutdown", Value::Null),
+            obvestilo("exit", Value::Null),
+        ]
+        .iter()
+        .map(|sporočilo| {
+            let vsebina = sporočilo.to_string();
+            format!("Content-Length: {}\r\n\r\n{vsebina}", vsebina.len())
+        })
+        .collect::<String>();
+
+        let mut izhod = Vec::<u8>::new();
+        let koda = zaženi(&mut Cursor::new(vhod), &mut izhod).unwrap();
+        let izhod = String::from_utf8(izhod).unwrap();
+
+        let odgovori = izhod
+            .split("Content-Length: ")
+            .skip(1)
+            .map(|sporočilo| serde_json::from_str::<Value>(sporočilo.split_once("\r\n\r\n").unwrap().1).unwrap())
+            .collect::<Vec<Value>>();
+
+        assert_eq!(koda, 0);
+        assert_eq!(odgovori.len(), 3);
+        assert_eq!(odgovori[0]["result"]["capabilities"]["hoverProvider"], json!(true));
+        assert_eq!(odgovori[1]["error"]["code"], json!(-32601));
+        assert_eq!(odgovori[2], json!({ "jsonrpc": "2.0", "id": 3, "result": null }));
+
+        let mut strežnik = Strežnik::default();
+        strežnik.obdelaj(&obvestilo("exit", Value::Null));
+        assert!(strežnik.končan());
+        assert_eq!(strežnik.izhodna_koda(), 1);
+    }
+
+    #[test]
+    fn diagnostike() {
+        let mut strežnik = Strežnik::default();
+        let obvestila = strežnik.obdelaj(&obvestilo("textDocument/didOpen", json!({
+            "textDocument": { "uri": URI, "languageId": "slj", "version": 1, "text": PROGRAM },
+        })));
+
+        assert_eq!(obvestila, [json!({
+            "jsonrpc": "2.0",
+            "method": "textDocument/publishDiagnostics",
+            "params": {
+                "uri": URI,
+                "diagnostics": [{
+                    "range": { "start": { "line": 5, "character": 8 }, "end": { "line": 5, "character": 9 } },
+                    "severity": 1,
+                    "code": "E2",
+                    "source": "slj",
+                    "message": "Neznana spremenljivka",
+                }],
+            },
+        })]);
+
+        let obvestila = strežnik.obdelaj(&obvestilo("textDocument/didChange", json!({
+            "textDocument": { "uri": URI, "version": 2 },
+            "contentChanges": [{ "text": "naj z = 1\n" }],
+        })));
+        // opozorila so manj resna od napak
+        assert_eq!(obvestila[0]["params"]["diagnostics"], json!([{
+            "range": { "start": { "line": 0, "character": 4 }, "end": { "line": 0, "character": 5 } },
+            "severity": 2,
+            "code": "W1",
+            "source": "slj",
+            "message": "Neuporabljena spremenljivka 'z'",
+        }]));
+
+        // prazen dokument ne sme ustaviti strežnika
+        let obvestila = strežnik.obdelaj(&obvestilo("textDocument/didChange", json!({
+            "textDocument": { "uri": URI, "version": 3 },
+            "contentChanges": [{ "text": "" }],
+        })));
+        assert_eq!(obvestila[0]["params"]["diagnostics"], json!([]));
+    }
+
+    #[test]
+    fn opis() {
+        let mut strežnik = odprt(PROGRAM);
+
+        assert_eq!(rezultat(&mut strežnik, "textDocument/hover", na_mestu(4, 10)), json!({
+            "contents": { "kind": "markdown", "value": "```slj\nfunkcija kvadrat(a: celo) -> celo\n```" },
+            "range": { "start": { "line": 4, "character": 8 }, "end": { "line": 4, "character": 15 } },
+        }));
+        assert_eq!(rezultat(&mut strežnik, "textDocument/hover", na_mestu(1, 9))["contents"]["value"], json!("```slj\na: celo\n```"));
+        assert_eq!(rezultat(&mut strežnik, "textDocument/hover", na_mestu(2, 0)), Value::Null);
+    }
+
+    #[test]
+    fn definicija() {
+        let mut strežnik = odprt(PROGRAM);
+
+        assert_eq!(rezultat(&mut strežnik, "textDocument/definition", na_mestu(4, 16)), json!({
+            "uri": URI,
+            "range": { "start": { "line": 3, "character": 4 }, "end": { "line": 3, "character": 5 } },
+        }));
+        assert_eq!(rezultat(&mut strežnik, "textDocument/definition", na_mestu(4, 9)), json!({
+            "uri": URI,
+            "range": { "start": { "line": 0, "character": 9 }, "end": { "line": 0, "character": 16 } },
+        }));
+        // natisni je definirana v jedru
+        assert_eq!(rezultat(&mut strežnik, "textDocument/definition", na_mestu(4, 2)), Value::Null);
+    }
+
+    #[test]
+    fn dopolnitve() {
+        let mut strežnik = odprt(PROGRAM);
+
+        let dopolnitve = rezultat(&mut strežnik, "textDocument/completion", na_mestu(1, 10));
+        let dopolnitve = dopolnitve["items"].as_array().unwrap();
+        let imena = dopolnitve.iter().map(|d| d["label"].as_str().unwrap()).collect::<Vec<&str>>();
+
+        // x je deklariran za funkcijo, notranje funkcije jedra pa niso na voljo
+        assert!(imena.contains(&"a"));
+        assert!(imena.contains(&"kvadrat"));
+        assert!(!imena.contains(&"x"));
+        assert!(!imena.iter().any(|ime| ime.starts_with('_')));
+
+        let natisni = dopolnitve.iter()
+            .filter(|d| d["label"] == "natisni")
+            .map(|d| d["detail"].as_str().unwrap())
+            .collect::<Vec<&str>>();
+        assert!(natisni.len() > 1);
+        assert!(natisni.contains(&"funkcija natisni(št: celo)"));
+    }
+
+    #[test]
+    fn podpisi() {
+        let mut strežnik = odprt("funkcija deli(a: celo, b: celo) -> celo {\n    vrni a / b\n}\nnaj x = deli(6, (1 + 2))\n");
+
+        assert_eq!(rezultat(&mut strežnik, "textDocument/signatureHelp", na_mestu(3, 13)), json!({
+            "signatures": [{
+                "label": "funkcija deli(a: celo, b: celo) -> celo",
+                "parameters": [{ "label": "a: celo" }, { "label": "b: celo" }],
+            }],
+            "activeSignature": 0,
+            "activeParameter": 0,
+        }));
+        assert_eq!(rezultat(&mut strežnik, "textDocument/signatureHelp", na_mestu(3, 20))["activeParameter"], json!(1));
+        assert_eq!(rezultat(&mut strežnik, "textDocument/signatureHelp", na_mestu(3, 2)), Value::Null);
+    }
+}
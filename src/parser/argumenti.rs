@@ -38,18 +38,15 @@ impl<'a> Parser<'a> {
                 [ Operator("@", ..), literal @ Literal(..) ]
                     | [ literal @ Literal(L::Niz(..)) ] => {
                     match self.drevo(&[*literal]) {
+                        // niz z vstavljenimi izrazi je že na kopici
+                        Ok(drevo) if drevo.tip() == Tip::Niz => {
+                            tipi.push(drevo.tip());
+                            argumenti.push(drevo);
+                            spremenljivke.push(Prazno.rc());
+                        },
                         Ok(drevo) => {
                             let tip = drevo.tip();
-
-                            let mut i = 0;
-                            let ime = loop {
-                                if !self.spremenljivke.contains_key(ŠTEVILKE[i]) {
-                                    break ŠTEVILKE[i];
-                                }
-                                i += 1;
-                            };
-
-                            let spr = self.dodaj_spremenljivko(ime, tip.clone(), false);
+                            let spr = self.začasna_spremenljivka(tip.clone());
                             let prirejanje = Prirejanje { spremenljivka: spr.clone(), izraz: drevo }.rc();
 
                             let referenca = match tip {
@@ -84,4 +81,17 @@ impl<'a> Parser<'a> {
             Err(napake)
         }
     }
+
+    // spremenljivka z imenom, ki ga v programu ni mogoče zapisati
+    pub fn začasna_spremenljivka(&mut self, tip: Tip) -> Rc<Vozlišče> {
+        let mut i = 0;
+        let ime = loop {
+            if !self.spremenljivke.contains_key(ŠTEVILKE[i]) {
+                break ŠTEVILKE[i];
+            }
+            i += 1;
+        };
+
+        self.dodaj_spremenljivko(ime, tip, false)
+    }
 }
@@ -22,6 +22,8 @@ pub struct Drevo {
     pub funkcije: Vec<Rc<Vozlišče>>,
     pub št_klicev: HashMap<String, usize>,
     pub prostor: i32,
+    // opozorila, zbrana med razčlenjevanjem
+    pub opozorila: Napake,
 }
 
 impl Display for Drevo {
@@ -52,7 +54,12 @@ pub enum Vozlišče {
     Celo(i32),
     Real(f32),
     Znak(char),
+    Celo64(i64),
+    Real64(f64),
+    Naravno(u32),
     Niz(String),
+    Seznam(Vec<Rc<Vozlišče>>),
+    Varianta(Tip, i32),
 
     Spremenljivka{ tip: Tip, ime: String, naslov: i32, z_odmikom: bool, spremenljiva: bool },
     Referenca(Rc<Vozlišče>),
@@ -60,6 +67,7 @@ pub enum Vozlišče {
 
     Dereferenciraj(Rc<Vozlišče>),
     Indeksiraj{ seznam_ref: Rc<Vozlišče>, indeks: Rc<Vozlišče> },
+    Rezina{ seznam_ref: Rc<Vozlišče>, začetek: Rc<Vozlišče>, konec: Rc<Vozlišče> },
     Dolžina(Rc<Vozlišče>),
 
     Resnica,
@@ -76,6 +84,16 @@ pub enum Vozlišče {
     RealVCelo(Rc<Vozlišče>),
     CeloVZnak(Rc<Vozlišče>),
     ZnakVCelo(Rc<Vozlišče>),
+    NaštevanjeVCelo(Rc<Vozlišče>),
+    CeloVCelo64(Rc<Vozlišče>),
+    Celo64VCelo(Rc<Vozlišče>),
+    RealVReal64(Rc<Vozlišče>),
+    Real64VReal(Rc<Vozlišče>),
+    Celo64VReal64(Rc<Vozlišče>),
+    Real64VCelo64(Rc<Vozlišče>),
+    CeloVNaravno(Rc<Vozlišče>),
+    NaravnoVCelo(Rc<Vozlišče>),
+    NaravnoVCelo64(Rc<Vozlišče>),
 
     Zanikaj(Rc<Vozlišče>),
     Konjunkcija(Rc<Vozlišče>, Rc<Vozlišče>),
@@ -114,8 +132,79 @@ pub enum Vozlišče {
     FunkcijskiKlic{ funkcija: Rc<Vozlišče>, spremenljivke: Rc<Vozlišče>, argumenti: Rc<Vozlišče> },
 
     Natisni(Rc<Vozlišče>),
+    NatisniNapako(Rc<Vozlišče>),
     Preberi,
+    Seme,
+    ŠteviloArgumentov,
+    Argument(Rc<Vozlišče>),
+    Okolje(Rc<Vozlišče>),
+    Odpri(Rc<Vozlišče>, Rc<Vozlišče>),
+    Zapri(Rc<Vozlišče>),
+    PreberiZnak(Rc<Vozlišče>),
+    ZapišiZnak(Rc<Vozlišče>, Rc<Vozlišče>),
+    Čas,
+    Spi(Rc<Vozlišče>),
     Splakni,
+    Končaj(Rc<Vozlišče>),
+    Alociraj(Rc<Vozlišče>),
+    Matematična(Matematika, Vec<Rc<Vozlišče>>),
+}
+
+// matematične funkcije, ki jih izračuna procesor
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Matematika {
+    Koren,
+    Sin,
+    Cos,
+    Tan,
+    Atan2,
+    Exp,
+    Ln,
+    Navzdol,
+    Navzgor,
+    Zaokroži,
+    AbsReal,
+    AbsCelo,
+}
+
+impl Matematika {
+    const UKAZI: [(Matematika, &'static str); 12] = [
+        (Matematika::Koren,    "SQRT"),
+        (Matematika::Sin,      "SINF"),
+        (Matematika::Cos,      "COSF"),
+        (Matematika::Tan,      "TANF"),
+        (Matematika::Atan2,    "ATNF"),
+        (Matematika::Exp,      "EXPF"),
+        (Matematika::Ln,       "LOGF"),
+        (Matematika::Navzdol,  "FLRF"),
+        (Matematika::Navzgor,  "CEIF"),
+        (Matematika::Zaokroži, "RNDF"),
+        (Matematika::AbsReal,  "ABSF"),
+        (Matematika::AbsCelo,  "ABSI"),
+    ];
+
+    pub fn from_ukaz(ukaz: &str) -> Option<Matematika> {
+        Self::UKAZI.iter().find(|(_, u)| *u == ukaz).map(|(m, _)| *m)
+    }
+
+    pub fn ukaz(&self) -> &'static str {
+        Self::UKAZI.iter().find(|(m, _)| m == self).unwrap().1
+    }
+
+    pub fn parametri(&self) -> Vec<Tip> {
+        match self {
+            Matematika::Atan2 => vec![Tip::Real, Tip::Real],
+            Matematika::AbsCelo => vec![Tip::Celo],
+            _ => vec![Tip::Real],
+        }
+    }
+
+    pub fn tip(&self) -> Tip {
+        match self {
+            Matematika::AbsCelo => Tip::Celo,
+            _ => Tip::Real,
+        }
+    }
 }
 
 use Vozlišče::*;
@@ -126,15 +215,24 @@ impl Display for Vozlišče {
             Prazno => "()".to_owned(),
 
             Niz(niz) => format!(r#""{}""#, niz.escape()),
+            Seznam(elementi) => format!("[{}]", elementi.iter().map(|e| e.to_string()).collect::<Vec<String>>().join(", ")),
             Celo(število) => število.to_string(),
             Real(število) => število.to_string(),
             Znak(znak)    => znak.to_string(),
+            Celo64(število) => število.to_string(),
+            Real64(število) => število.to_string(),
+            Naravno(število) => število.to_string(),
+            Varianta(tip, vrednost) => match tip {
+                Tip::Naštevanje(ime, variante) => format!("{ime}.{}", variante[*vrednost as usize]),
+                _ => unreachable!("Varianta je vedno tipa naštevanje"),
+            },
 
             Spremenljivka{ tip, ime, naslov, z_odmikom, .. } => format!("{ime}: {tip} ({}{naslov})", if *z_odmikom { "+" } else { "@" }),
             Referenca(spremenljivka) | RefSeznama(spremenljivka) => "@".to_string() + &spremenljivka.to_string(),
 
             Dereferenciraj(spremenljivka) => spremenljivka.to_string() + &"@".to_string(),
             Dolžina(spr) => format!("{}.dolžina", spr.to_string()),
+            Rezina { seznam_ref, začetek, konec } => format!("{seznam_ref}[{začetek}..{konec}]"),
 
             Resnica => "resnica".to_owned(),
             Laž     => "laž".to_owned(),
@@ -183,7 +281,22 @@ impl Display for Vozlišče {
             },
             FunkcijskiKlic{ funkcija, .. } => if let Funkcija { tip: _, ime, .. } = &**funkcija { ime.clone() } else { "".to_string() },
             Natisni(znak) => format!("natisni({znak})"),
+            NatisniNapako(znak) => format!("asm(\"PUTE\", {znak})"),
             Preberi => "preberi()".to_string(),
+            Seme => "asm(\"SEED\")".to_string(),
+            ŠteviloArgumentov => "asm(\"ARGC\")".to_string(),
+            Argument(indeks) => format!("asm(\"ARGV\", {indeks})"),
+            Okolje(ime) => format!("asm(\"ENVV\", {ime})"),
+            Odpri(pot, način) => format!("asm(\"OPEN\", {pot}, {način})"),
+            Zapri(deskriptor) => format!("asm(\"CLOS\", {deskriptor})"),
+            PreberiZnak(deskriptor) => format!("asm(\"FGTC\", {deskriptor})"),
+            ZapišiZnak(deskriptor, znak) => format!("asm(\"FPTC\", {deskriptor}, {znak})"),
+            Čas => "asm(\"TIME\")".to_string(),
+            Spi(ms) => format!("asm(\"SLEP\", {ms})"),
+            Alociraj(dolžina) => format!("asm(\"ALHP\", {dolžina})"),
+            Matematična(funkcija, argumenti) => format!("asm(\"{}\", {})", funkcija.ukaz(),
+                argumenti.iter().map(|a| a.to_string()).collect::<Vec<String>>().join(", ")),
+            Končaj(koda) => format!("končaj({koda})"),
             _ => "".to_owned(),
         })
     }
@@ -208,7 +321,12 @@ impl PartialEq for Vozlišče {
             (Celo(l), Celo(d)) => l == d,
             (Real(l), Real(d)) => l == d,
             (Znak(l), Znak(d)) => l == d,
+            (Celo64(l), Celo64(d)) => l == d,
+            (Real64(l), Real64(d)) => l == d,
+            (Naravno(l), Naravno(d)) => l == d,
             (Niz(l), Niz(d)) => l == d,
+            (Seznam(l), Seznam(d)) => l == d,
+            (Varianta(lt, l), Varianta(dt, d)) => lt == dt && l == d,
 
             (Spremenljivka{ tip: lt, ime: li, naslov: ln, z_odmikom: lz, spremenljiva: ls },
              Spremenljivka{ tip: dt, ime: di, naslov: dn, z_odmikom: dz, spremenljiva: ds }) =>
@@ -228,6 +346,16 @@ impl PartialEq for Vozlišče {
 
             (CeloVReal(l), CeloVReal(d)) => l == d,
             (RealVCelo(l), RealVCelo(d)) => l == d,
+            (NaštevanjeVCelo(l), NaštevanjeVCelo(d)) => l == d,
+            (CeloVCelo64(l), CeloVCelo64(d)) => l == d,
+            (Celo64VCelo(l), Celo64VCelo(d)) => l == d,
+            (RealVReal64(l), RealVReal64(d)) => l == d,
+            (Real64VReal(l), Real64VReal(d)) => l == d,
+            (Celo64VReal64(l), Celo64VReal64(d)) => l == d,
+            (Real64VCelo64(l), Real64VCelo64(d)) => l == d,
+            (CeloVNaravno(l), CeloVNaravno(d)) => l == d,
+            (NaravnoVCelo(l), NaravnoVCelo(d)) => l == d,
+            (NaravnoVCelo64(l), NaravnoVCelo64(d)) => l == d,
 
             (Zanikaj(l), Zanikaj(d)) => l == d,
             (Konjunkcija(ll, ld), Konjunkcija(dl, dd)) |
@@ -290,8 +418,8 @@ impl Vozlišče {
                 | ProgramskiŠtevec(_) | Skok(_) | Klic(_) | PogojniSkok(..) | DinamičniSkok =>
                 "".to_string(),
 
-            Niz(_) | Celo(_) | Real(_) | Znak(_) | Resnica | Laž
-                | Spremenljivka {..} | Referenca(..) | RefSeznama(..) | Dereferenciraj(..) | Dolžina(..) =>
+            Niz(_) | Seznam(_) | Celo(_) | Real(_) | Znak(_) | Celo64(_) | Real64(_) | Naravno(_) | Varianta(..) | Resnica | Laž
+                | Spremenljivka {..} | Referenca(..) | RefSeznama(..) | Dereferenciraj(..) | Dolžina(..) | Rezina {..} =>
                 "  ".repeat(globina) + &self.to_string() + "\n",
 
             Indeksiraj { seznam_ref, indeks } => match &**seznam_ref {
@@ -299,8 +427,10 @@ impl Vozlišče {
                     " ".repeat(globina) + &format!("{ime}[")
                     + &indeks.drevo(globina + 1)
                     + &" ".repeat(globina) + "]\n",
-                RefSeznama(seznam) => seznam.drevo(globina),
-                vozl @ _ => unreachable!("Referenca mora vsebovati spremenljivko, ne pa {:?}", vozl),
+                _ => seznam_ref.drevo(globina)
+                    + &" ".repeat(globina) + "[\n"
+                    + &indeks.drevo(globina + 1)
+                    + &" ".repeat(globina) + "]\n",
             }
 
             Konjunkcija(l, d) | Disjunkcija(l, d) | BitniAli(l, d) | BitniXor(l, d) | BitniIn(l, d)
@@ -327,6 +457,24 @@ impl Vozlišče {
             ZnakVCelo(vozlišče) =>
                 vozlišče.drevo(globina) 
                 + " kot celo\n",
+            NaštevanjeVCelo(vozlišče) =>
+                vozlišče.drevo(globina) 
+                + " kot celo\n",
+            CeloVCelo64(vozlišče) | Real64VCelo64(vozlišče) | NaravnoVCelo64(vozlišče) =>
+                vozlišče.drevo(globina) 
+                + " kot celo64\n",
+            CeloVNaravno(vozlišče) =>
+                vozlišče.drevo(globina) 
+                + " kot naravno\n",
+            Celo64VCelo(vozlišče) | NaravnoVCelo(vozlišče) =>
+                vozlišče.drevo(globina) 
+                + " kot celo\n",
+            RealVReal64(vozlišče) | Celo64VReal64(vozlišče) =>
+                vozlišče.drevo(globina) 
+                + " kot real64\n",
+            Real64VReal(vozlišče) =>
+                vozlišče.drevo(globina) 
+                + " kot real\n",
 
 
             Zanikaj(vozlišče) =>
@@ -380,26 +528,35 @@ impl Vozlišče {
 
             Natisni(znak) => 
                 "  ".repeat(globina) + &znak.to_string() + "\n",
-            Preberi => " ".repeat(globina) + &self.to_string(),
-            Splakni => " ".repeat(globina) + &self.to_string() + "()\n"
+            NatisniNapako(..) => "  ".repeat(globina) + &self.to_string() + "\n",
+            Preberi | Seme | ŠteviloArgumentov | Čas => " ".repeat(globina) + &self.to_string(),
+            Argument(..) | Okolje(..) => "  ".repeat(globina) + &self.to_string() + "\n",
+            Odpri(..) | Zapri(..) | PreberiZnak(..) | ZapišiZnak(..) => "  ".repeat(globina) + &self.to_string() + "\n",
+            Spi(..) => "  ".repeat(globina) + &self.to_string() + "\n",
+            Alociraj(..) | Matematična(..) => "  ".repeat(globina) + &self.to_string() + "\n",
+            Splakni => " ".repeat(globina) + &self.to_string() + "()\n",
+            Končaj(..) => "  ".repeat(globina) + &self.to_string() + "\n",
         }
     }
 
     pub fn eval(&self, izraz: &[Žeton]) -> Result<Vozlišče, Napake> {
         match self {
-            Celo(_) | Real(_) | Znak(_) | Niz(_) | Resnica | Laž => Ok(self.clone()),
+            Celo(_) | Real(_) | Znak(_) | Celo64(_) | Real64(_) | Naravno(_) | Niz(_) | Varianta(..) | Resnica | Laž => Ok(self.clone()),
+            Seznam(elementi) => Ok(Seznam(elementi.iter()
+                    .map(|e| Ok(e.eval(izraz)?.rc()))
+                    .collect::<Result<Vec<Rc<Vozlišče>>, Napake>>()?)),
 
             Spremenljivka{ ime, tip, .. } => Err(Napake::from_zaporedje(izraz, E2, &format!("Vrednost spremenljivke {ime}: {tip} ni znana vnaprej."))),
             Referenca(spr) => spr.eval(izraz),
             RefSeznama(spr) => spr.eval(izraz),
 
             Dereferenciraj(spr) => spr.eval(izraz),
-            Indeksiraj{ seznam_ref, .. } => seznam_ref.eval(izraz),
+            Indeksiraj{ seznam_ref, .. } | Rezina { seznam_ref, .. } => seznam_ref.eval(izraz),
             Dolžina(spr) => match spr.tip() {
                 Tip::Seznam(_, dolžina) => Ok(Celo(dolžina)),
-                Tip::RefSeznama(_) => match &**spr {
+                Tip::RefSeznama(_) | Tip::Niz => match &**spr {
                     Spremenljivka { tip, ime, .. } => Err(Napake::from_zaporedje(izraz, E2, &format!("Dolžina seznama {ime}: {tip} ni znana vnaprej."))),
-                    _ => unreachable!(),
+                    _ => Err(Napake::from_zaporedje(izraz, E2, "Dolžina seznama ni znana vnaprej.")),
                 }
                 _ => unreachable!(),
             },
@@ -407,31 +564,49 @@ impl Vozlišče {
             Add(_, l, d) => match (l.eval(izraz)?, d.eval(izraz)?) {
                     (Celo(l), Celo(d)) => Ok(Celo(l + d)),
                     (Real(l), Real(d)) => Ok(Real(l + d)),
+                    (Celo64(l), Celo64(d)) => Ok(Celo64(l + d)),
+                    (Real64(l), Real64(d)) => Ok(Real64(l + d)),
+                    (Naravno(l), Naravno(d)) => Ok(Naravno(l.wrapping_add(d))),
                     _ => unreachable!(),
             },
             Sub(_, l, d) => match (l.eval(izraz)?, d.eval(izraz)?) {
                     (Celo(l), Celo(d)) => Ok(Celo(l - d)),
                     (Real(l), Real(d)) => Ok(Real(l - d)),
+                    (Celo64(l), Celo64(d)) => Ok(Celo64(l - d)),
+                    (Real64(l), Real64(d)) => Ok(Real64(l - d)),
+                    (Naravno(l), Naravno(d)) => Ok(Naravno(l.wrapping_sub(d))),
                     _ => unreachable!(),
             },
             Mul(_, l, d) => match (l.eval(izraz)?, d.eval(izraz)?) {
                     (Celo(l), Celo(d)) => Ok(Celo(l * d)),
                     (Real(l), Real(d)) => Ok(Real(l * d)),
+                    (Celo64(l), Celo64(d)) => Ok(Celo64(l * d)),
+                    (Real64(l), Real64(d)) => Ok(Real64(l * d)),
+                    (Naravno(l), Naravno(d)) => Ok(Naravno(l.wrapping_mul(d))),
                     _ => unreachable!(),
             },
             Div(_, l, d) => match (l.eval(izraz)?, d.eval(izraz)?) {
                     (Celo(l), Celo(d)) => Ok(Celo(l / d)),
                     (Real(l), Real(d)) => Ok(Real(l / d)),
+                    (Celo64(l), Celo64(d)) => Ok(Celo64(l / d)),
+                    (Real64(l), Real64(d)) => Ok(Real64(l / d)),
+                    (Naravno(l), Naravno(d)) => Ok(Naravno(l / d)),
                     _ => unreachable!(),
             },
             Mod(_, l, d) => match (l.eval(izraz)?, d.eval(izraz)?) {
                 (Celo(l), Celo(d)) => Ok(Celo(l % d)),
                 (Real(l), Real(d)) => Ok(Real(l % d)),
+                (Celo64(l), Celo64(d)) => Ok(Celo64(l % d)),
+                (Real64(l), Real64(d)) => Ok(Real64(l % d)),
+                (Naravno(l), Naravno(d)) => Ok(Naravno(l % d)),
                 _ => unreachable!(),
             },
             Pow(_, l, d) => match (l.eval(izraz)?, d.eval(izraz)?) {
                 (Celo(l), Celo(d)) => Ok(Celo(l.pow(d as u32))),
                 (Real(l), Real(d)) => Ok(Real(l.powf(d))),
+                (Celo64(l), Celo64(d)) => Ok(Celo64(l.pow(d as u32))),
+                (Real64(l), Real64(d)) => Ok(Real64(l.powf(d))),
+                (Naravno(l), Naravno(d)) => Ok(Naravno(l.wrapping_pow(d))),
                 _ => unreachable!(),
             },
 
@@ -451,6 +626,46 @@ impl Vozlišče {
                 Znak(št) => Ok(Celo(št as i32)),
                 _ => unreachable!(),
             },
+            NaštevanjeVCelo(varianta) => match varianta.eval(izraz)? {
+                Varianta(_, vrednost) => Ok(Celo(vrednost)),
+                _ => unreachable!(),
+            },
+            CeloVCelo64(št) => match št.eval(izraz)? {
+                Celo(št) => Ok(Celo64(št as i64)),
+                _ => unreachable!(),
+            },
+            Celo64VCelo(št) => match št.eval(izraz)? {
+                Celo64(št) => Ok(Celo(št as i32)),
+                _ => unreachable!(),
+            },
+            RealVReal64(št) => match št.eval(izraz)? {
+                Real(št) => Ok(Real64(št as f64)),
+                _ => unreachable!(),
+            },
+            Real64VReal(št) => match št.eval(izraz)? {
+                Real64(št) => Ok(Real(št as f32)),
+                _ => unreachable!(),
+            },
+            Celo64VReal64(št) => match št.eval(izraz)? {
+                Celo64(št) => Ok(Real64(št as f64)),
+                _ => unreachable!(),
+            },
+            Real64VCelo64(št) => match št.eval(izraz)? {
+                Real64(št) => Ok(Celo64(št as i64)),
+                _ => unreachable!(),
+            },
+            CeloVNaravno(št) => match št.eval(izraz)? {
+                Celo(št) => Ok(Naravno(št as u32)),
+                _ => unreachable!(),
+            },
+            NaravnoVCelo(št) => match št.eval(izraz)? {
+                Naravno(št) => Ok(Celo(št as i32)),
+                _ => unreachable!(),
+            },
+            NaravnoVCelo64(št) => match št.eval(izraz)? {
+                Naravno(št) => Ok(Celo64(št as i64)),
+                _ => unreachable!(),
+            },
 
             Zanikaj(bool) => match bool.eval(izraz)? {
                 Resnica => Ok(Laž),
@@ -474,56 +689,84 @@ impl Vozlišče {
 
             BitniAli(l, d) => match (l.eval(izraz)?, d.eval(izraz)?) {
                 (Celo(l), Celo(d)) => Ok(Celo(l | d)),
+                (Naravno(l), Naravno(d)) => Ok(Naravno(l | d)),
                 _ => unreachable!(),
             },
             BitniXor(l, d) => match (l.eval(izraz)?, d.eval(izraz)?) {
                 (Celo(l), Celo(d)) => Ok(Celo(l ^ d)),
+                (Naravno(l), Naravno(d)) => Ok(Naravno(l ^ d)),
                 _ => unreachable!(),
             },
             BitniIn(l, d) => match (l.eval(izraz)?, d.eval(izraz)?) {
                 (Celo(l), Celo(d)) => Ok(Celo(l & d)),
+                (Naravno(l), Naravno(d)) => Ok(Naravno(l & d)),
                 _ => unreachable!(),
             },
             BitniPremikLevo(l, d) => match (l.eval(izraz)?, d.eval(izraz)?) {
                 (Celo(l), Celo(d)) => Ok(Celo(l << d)),
+                (Naravno(l), Celo(d)) => Ok(Naravno(l << d)),
                 _ => unreachable!(),
             },
             BitniPremikDesno(l, d) => match (l.eval(izraz)?, d.eval(izraz)?) {
                 (Celo(l), Celo(d)) => Ok(Celo(l >> d)),
+                (Naravno(l), Celo(d)) => Ok(Naravno(l >> d)),
                 _ => unreachable!(),
             },
 
             Enako(_, l, d) => match (l.eval(izraz)?, d.eval(izraz)?) {
                 (Celo(l), Celo(d)) => Ok(if l == d { Resnica } else { Laž }),
                 (Real(l), Real(d)) => Ok(if l == d { Resnica } else { Laž }),
+                (Celo64(l), Celo64(d)) => Ok(if l == d { Resnica } else { Laž }),
+                (Real64(l), Real64(d)) => Ok(if l == d { Resnica } else { Laž }),
+                (Naravno(l), Naravno(d)) => Ok(if l == d { Resnica } else { Laž }),
+                (Seznam(l), Seznam(d)) => Ok(if l == d { Resnica } else { Laž }),
                 _ => unreachable!(),
             },
             NiEnako(_, l, d) => match (l.eval(izraz)?, d.eval(izraz)?) {
                 (Celo(l), Celo(d)) => Ok(if l != d { Resnica } else { Laž }),
                 (Real(l), Real(d)) => Ok(if l != d { Resnica } else { Laž }),
+                (Celo64(l), Celo64(d)) => Ok(if l != d { Resnica } else { Laž }),
+                (Real64(l), Real64(d)) => Ok(if l != d { Resnica } else { Laž }),
+                (Naravno(l), Naravno(d)) => Ok(if l != d { Resnica } else { Laž }),
+                (Seznam(l), Seznam(d)) => Ok(if l != d { Resnica } else { Laž }),
                 _ => unreachable!(),
             },
             Večje(_, l, d) => match (l.eval(izraz)?, d.eval(izraz)?) {
                 (Celo(l), Celo(d)) => Ok(if l > d { Resnica } else { Laž }),
                 (Real(l), Real(d)) => Ok(if l > d { Resnica } else { Laž }),
+                (Celo64(l), Celo64(d)) => Ok(if l > d { Resnica } else { Laž }),
+                (Real64(l), Real64(d)) => Ok(if l > d { Resnica } else { Laž }),
+                (Naravno(l), Naravno(d)) => Ok(if l > d { Resnica } else { Laž }),
                 _ => unreachable!(),
             },
             VečjeEnako(_, l, d) => match (l.eval(izraz)?, d.eval(izraz)?) {
                 (Celo(l), Celo(d)) => Ok(if l >= d { Resnica } else { Laž }),
                 (Real(l), Real(d)) => Ok(if l >= d { Resnica } else { Laž }),
+                (Celo64(l), Celo64(d)) => Ok(if l >= d { Resnica } else { Laž }),
+                (Real64(l), Real64(d)) => Ok(if l >= d { Resnica } else { Laž }),
+                (Naravno(l), Naravno(d)) => Ok(if l >= d { Resnica } else { Laž }),
                 _ => unreachable!(),
             },
             Manjše(_, l, d) => match (l.eval(izraz)?, d.eval(izraz)?) {
                 (Celo(l), Celo(d)) => Ok(if l < d { Resnica } else { Laž }),
                 (Real(l), Real(d)) => Ok(if l < d { Resnica } else { Laž }),
+                (Celo64(l), Celo64(d)) => Ok(if l < d { Resnica } else { Laž }),
+                (Real64(l), Real64(d)) => Ok(if l < d { Resnica } else { Laž }),
+                (Naravno(l), Naravno(d)) => Ok(if l < d { Resnica } else { Laž }),
                 _ => unreachable!(),
             },
             ManjšeEnako(_, l, d) => match (l.eval(izraz)?, d.eval(izraz)?) {
                 (Celo(l), Celo(d)) => Ok(if l <= d { Resnica } else { Laž }),
                 (Real(l), Real(d)) => Ok(if l <= d { Resnica } else { Laž }),
+                (Celo64(l), Celo64(d)) => Ok(if l <= d { Resnica } else { Laž }),
+                (Real64(l), Real64(d)) => Ok(if l <= d { Resnica } else { Laž }),
+                (Naravno(l), Naravno(d)) => Ok(if l <= d { Resnica } else { Laž }),
                 _ => unreachable!(),
             },
 
+            FunkcijskiKlic { .. } | Alociraj(..) | Matematična(..) | Argument(..) | Okolje(..) |
+                Odpri(..) | Zapri(..) | PreberiZnak(..) | ZapišiZnak(..) | Spi(..) => Err(Napake::from_zaporedje(izraz, E2, "Vrednost izraza ni znana vnaprej.")),
+
             _ => unreachable!(),
         }
     }
@@ -540,11 +783,15 @@ impl Vozlišče {
             NaložiOdmik => 1,
 
             Celo(_) | Real(_) | Znak(_) => 1,
+            Celo64(_) | Real64(_) | Naravno(_) => 1,
+            Varianta(..) => 1,
             Resnica | Laž => 1,
             Niz(niz) => niz.chars().count() as i32,
+            Seznam(elementi) => elementi.iter().map(|e| e.sprememba_stacka()).sum::<i32>() + 1,
 
             Spremenljivka{ tip, .. } => tip.sprememba_stacka(),
-            Referenca(_) | RefSeznama(_) => 1,
+            Referenca(_) => 1,
+            RefSeznama(_) | Rezina { .. } => 2,
 
             Dereferenciraj(spr) => match &**spr {
                 Spremenljivka { tip, .. } => tip.vsebuje_tip().sprememba_stacka(),
@@ -554,21 +801,19 @@ impl Vozlišče {
                 },
                 _ => unreachable!("Zakaj indeksiraš tip '{spr:?}'??"),
             },
-            Indeksiraj { seznam_ref, .. } => match &**seznam_ref {
-                Spremenljivka { tip, .. } => tip.vsebuje_tip().sprememba_stacka(),
-                Referenca(spr) | RefSeznama(spr) => match &**spr {
-                    Spremenljivka { tip, .. } => tip.vsebuje_tip().sprememba_stacka(),
-                    _ => unreachable!("Zakaj indeksiraš tip '{seznam_ref:?}'??"),
-                },
-                _ => unreachable!("Zakaj indeksiraš tip '{seznam_ref:?}'??"),
-            },
+            Indeksiraj { .. } => self.tip().sprememba_stacka(),
             Dolžina(..) => 1,
 
-            Add(_, l, d) | Sub(_, l, d) | Mul(_, l, d) | Div(_, l, d) | Mod(_, l, d) | Pow(_, l, d) |
-                Enako(_, l, d) | NiEnako(_, l, d) | Večje(_, l, d) | VečjeEnako(_, l, d) | Manjše(_, l, d) | ManjšeEnako(_, l, d)
+            Add(_, l, d) | Sub(_, l, d) | Mul(_, l, d) | Div(_, l, d) | Mod(_, l, d) | Pow(_, l, d)
                 => l.sprememba_stacka() + d.sprememba_stacka() - 1,
+            Enako(..) | NiEnako(..) | Večje(..) | VečjeEnako(..) | Manjše(..) | ManjšeEnako(..) => 1,
 
-            CeloVReal(..) | RealVCelo(..) | CeloVZnak(..) | ZnakVCelo(..) => 0,
+            // pretvorba zamenja vrednost na vrhu stacka, sprememba je enaka kot pri pretvorjenem izrazu
+            CeloVReal(vozlišče) | RealVCelo(vozlišče) | CeloVZnak(vozlišče) | ZnakVCelo(vozlišče) | NaštevanjeVCelo(vozlišče)
+                | CeloVCelo64(vozlišče) | Celo64VCelo(vozlišče) | RealVReal64(vozlišče) | Real64VReal(vozlišče)
+                | Celo64VReal64(vozlišče) | Real64VCelo64(vozlišče)
+                | CeloVNaravno(vozlišče) | NaravnoVCelo(vozlišče) | NaravnoVCelo64(vozlišče)
+                => vozlišče.sprememba_stacka(),
 
             Zanikaj(izraz)
                 => izraz.sprememba_stacka(),
@@ -593,11 +838,17 @@ impl Vozlišče {
             Okvir{ .. }         => 0,
 
             Funkcija{ .. } => 0,
-            FunkcijskiKlic{ .. } => 1,
-
-            Natisni(_) => 0,
-            Preberi => 1,
+            FunkcijskiKlic{ .. } => self.tip().sprememba_stacka(),
+
+            Natisni(_) | NatisniNapako(_) => 0,
+            Preberi | Seme | ŠteviloArgumentov | Čas => 1,
+            Argument(..) | Okolje(..) => 2,
+            Odpri(..) | Zapri(..) | PreberiZnak(..) => 1,
+            ZapišiZnak(..) | Spi(..) => 0,
+            Alociraj(..) => 2,
+            Matematična(funkcija, _) => funkcija.tip().sprememba_stacka(),
             Splakni => 0,
+            Končaj(..) => 0,
         }
     }
 
@@ -615,7 +866,12 @@ impl Vozlišče {
             Celo(_) => Tip::Celo,
             Real(_) => Tip::Real,
             Znak(_) => Tip::Znak,
+            Celo64(_) => Tip::Celo64,
+            Real64(_) => Tip::Real64,
+            Naravno(_) => Tip::Naravno,
             Niz(niz)  => Tip::Seznam(Box::new(Tip::Znak), niz.chars().count() as i32),
+            Seznam(elementi) => Tip::Seznam(Box::new(elementi[0].tip()), elementi.len() as i32),
+            Varianta(tip, _) => tip.clone(),
             
             Spremenljivka{ tip, .. } => tip.clone(),
             Referenca(vozlišče) => Tip::Referenca(Box::new(vozlišče.tip())),
@@ -630,14 +886,16 @@ impl Vozlišče {
                 match seznam_ref.tip() {
                     Tip::Seznam(tip, ..) => *tip.clone(),
                     Tip::RefSeznama(tip, ..) => *tip.clone(),
+                    Tip::Niz => Tip::Znak,
                     _ => unreachable!("Vedno indeksiramo referenco na seznam."),
                 }
             },
+            Rezina { seznam_ref, .. } => Tip::RefSeznama(Box::new(seznam_ref.tip().vsebuje_tip())),
             Dolžina(..) => Tip::Celo,
 
             Resnica | Laž => Tip::Bool,
             Zanikaj(..) | Konjunkcija(..) | Disjunkcija(..) => Tip::Bool,
-            BitniAli(..) | BitniXor(..) | BitniIn(..) | BitniPremikLevo(..) | BitniPremikDesno(..) => Tip::Celo,
+            BitniAli(l, _) | BitniXor(l, _) | BitniIn(l, _) | BitniPremikLevo(l, _) | BitniPremikDesno(l, _) => l.tip(),
             Enako(..) | NiEnako(..) | Večje(..) | VečjeEnako(..) | Manjše(..) | ManjšeEnako(..) => Tip::Bool,
 
             Add(tip, ..) | Sub(tip, ..) | Mul(tip, ..) | Div(tip, ..) | Mod(tip, ..) | Pow(tip,..) => tip.clone(),
@@ -646,6 +904,12 @@ impl Vozlišče {
             RealVCelo(..) => Tip::Celo,
             CeloVZnak(..) => Tip::Znak,
             ZnakVCelo(..) => Tip::Celo,
+            NaštevanjeVCelo(..) => Tip::Celo,
+            CeloVCelo64(..) | Real64VCelo64(..) | NaravnoVCelo64(..) => Tip::Celo64,
+            Celo64VCelo(..) | NaravnoVCelo(..) => Tip::Celo,
+            CeloVNaravno(..) => Tip::Naravno,
+            RealVReal64(..) | Celo64VReal64(..) => Tip::Real64,
+            Real64VReal(..) => Tip::Real,
 
             ProgramskiŠtevec(..) => Tip::Celo,
             Skok(..) => Tip::Brez,
@@ -664,9 +928,19 @@ impl Vozlišče {
             Funkcija{ .. } => Tip::Brez,
             FunkcijskiKlic{ funkcija, .. } => if let Funkcija { tip, .. } = &**funkcija { tip.clone() } else { Tip::Brez },
 
-            Natisni(..) => Tip::Brez,
+            Natisni(..) | NatisniNapako(..) => Tip::Brez,
             Preberi => Tip::Znak,
+            Seme | ŠteviloArgumentov => Tip::Celo,
+            Argument(..) | Okolje(..) => Tip::Niz,
+            Odpri(..) | Zapri(..) => Tip::Celo,
+            PreberiZnak(..) => Tip::Znak,
+            ZapišiZnak(..) => Tip::Brez,
+            Čas => Tip::Celo64,
+            Spi(..) => Tip::Brez,
+            Alociraj(..) => Tip::Niz,
+            Matematična(funkcija, _) => funkcija.tip(),
             Splakni => Tip::Brez,
+            Končaj(..) => Tip::Brez,
         }
     }
 
@@ -688,7 +962,17 @@ impl Vozlišče {
         }
         else {
             match self {
-                Referenca(vozlišče) => vozlišče.vsebuje(other),
+                Referenca(vozlišče) | RefSeznama(vozlišče) => vozlišče.vsebuje(other),
+                Indeksiraj { seznam_ref, indeks } => seznam_ref.vsebuje(other) || indeks.vsebuje(other),
+                Rezina { seznam_ref, začetek, konec } => seznam_ref.vsebuje(other) || začetek.vsebuje(other) || konec.vsebuje(other),
+                Alociraj(dolžina) => dolžina.vsebuje(other),
+                Argument(indeks) => indeks.vsebuje(other),
+                Okolje(ime) => ime.vsebuje(other),
+                Odpri(pot, način) => pot.vsebuje(other) || način.vsebuje(other),
+                Zapri(deskriptor) | PreberiZnak(deskriptor) => deskriptor.vsebuje(other),
+                Spi(ms) => ms.vsebuje(other),
+                ZapišiZnak(deskriptor, znak) => deskriptor.vsebuje(other) || znak.vsebuje(other),
+                Matematična(_, argumenti) => argumenti.iter().any(|a| a.vsebuje(other)),
 
                 Zanikaj(a) => a.vsebuje(other),
                 Konjunkcija(a, b) | Disjunkcija(a, b) => a.vsebuje(other) || b.vsebuje(other),
@@ -700,14 +984,17 @@ impl Vozlišče {
                 Add(_, a, b) | Sub(_, a, b) | Mul(_, a, b) | Div(_, a, b) 
                     | Mod(_, a, b) | Pow(_, a, b) => a.vsebuje(other) || b.vsebuje(other),
 
-                CeloVReal(a) | RealVCelo(a) => a.vsebuje(other),
+                CeloVReal(a) | RealVCelo(a) | NaštevanjeVCelo(a) => a.vsebuje(other),
+                CeloVCelo64(a) | Celo64VCelo(a) | RealVReal64(a) | Real64VReal(a)
+                    | Celo64VReal64(a) | Real64VCelo64(a) => a.vsebuje(other),
+                CeloVNaravno(a) | NaravnoVCelo(a) | NaravnoVCelo64(a) => a.vsebuje(other),
 
                 PogojniStavek { pogoj, resnica, laž } => pogoj.vsebuje(other) || resnica.vsebuje(other) || laž.vsebuje(other),
                 Zanka { pogoj, telo } => pogoj.vsebuje(other) || telo.vsebuje(other),
                 Prirejanje { spremenljivka: _, izraz } => izraz.vsebuje(other),
 
                 Vrni(a) => a.vsebuje(other),
-                Zaporedje(a) => a.iter().any(|s| s.vsebuje(other)),
+                Zaporedje(a) | Seznam(a) => a.iter().any(|s| s.vsebuje(other)),
                 Okvir { zaporedje, št_spr: _ } => zaporedje.vsebuje(other),
 
                 Funkcija { tip: _, ime: _, parametri: _, telo, .. } => telo.vsebuje(other),
@@ -786,7 +1073,7 @@ mod testi {
             naslov: 0,
             z_odmikom: false,
             spremenljiva: false,
-        }.rc()).sprememba_stacka(), 1);
+        }.rc()).sprememba_stacka(), 2);
 
         assert_eq!(Dereferenciraj(Spremenljivka {
             tip: Tip::Referenca(Box::new(Tip::Seznam(Box::new(Tip::Znak), 4))),
@@ -823,6 +1110,14 @@ mod testi {
             }.rc()).rc(),
             indeks: Celo(1).rc()
         }.sprememba_stacka(), 1);
+
+        assert_eq!(CeloVReal(Celo(1).rc()).sprememba_stacka(), 1);
+        assert_eq!(ZnakVCelo(Znak('a').rc()).sprememba_stacka(), 1);
+        assert_eq!(Add(Tip::Real, CeloVReal(Celo(1).rc()).rc(), Real(2.0).rc()).sprememba_stacka(), 1);
+        assert_eq!(Prirejanje {
+            spremenljivka: Spremenljivka { tip: Tip::Celo, ime: "x".to_string(), naslov: 0, z_odmikom: false, spremenljiva: true }.rc(),
+            izraz: RealVCelo(Real(1.5).rc()).rc(),
+        }.sprememba_stacka(), 0);
     }
 
     #[test]
@@ -853,6 +1148,18 @@ mod testi {
         assert_eq!(RealVCelo(Real(3.14).rc()).eval(&[]).unwrap(), Celo(3));
         assert_eq!(CeloVZnak(Celo(32).rc()).eval(&[Žeton::Literal(L::Celo("32", 1, 1, "[test]"))]).unwrap(), Znak(' '));
         assert_eq!(ZnakVCelo(Znak('\n').rc()).eval(&[]).unwrap(), Celo(10));
+        assert_eq!(CeloVCelo64(Celo(-7).rc()).eval(&[]).unwrap(), Celo64(-7));
+        assert_eq!(Celo64VCelo(Celo64(1 << 32 | 5).rc()).eval(&[]).unwrap(), Celo(5));
+        assert_eq!(Celo64VReal64(Celo64(1 << 40).rc()).eval(&[]).unwrap(), Real64(1099511627776.0));
+        assert_eq!(Real64VCelo64(Real64(-2.75).rc()).eval(&[]).unwrap(), Celo64(-2));
+        assert_eq!(Real64VReal(Real64(0.5).rc()).eval(&[]).unwrap(), Real(0.5));
+        assert_eq!(Mul(Tip::Celo64, Celo64(1 << 31).rc(), Celo64(4).rc()).eval(&[]).unwrap(), Celo64(1 << 33));
+        assert_eq!(Div(Tip::Real64, Real64(1.0).rc(), Real64(3.0).rc()).eval(&[]).unwrap(), Real64(1.0 / 3.0));
+        assert_eq!(Večje(Tip::Celo64, Celo64(1 << 40).rc(), Celo64(1 << 39).rc()).eval(&[]).unwrap(), Resnica);
+        assert_eq!(CeloVNaravno(Celo(-1).rc()).eval(&[]).unwrap(), Naravno(u32::MAX));
+        assert_eq!(NaravnoVCelo64(Naravno(u32::MAX).rc()).eval(&[]).unwrap(), Celo64(u32::MAX as i64));
+        assert_eq!(Sub(Tip::Naravno, Naravno(0).rc(), Naravno(1).rc()).eval(&[]).unwrap(), Naravno(u32::MAX));
+        assert_eq!(Večje(Tip::Naravno, Naravno(1 << 31).rc(), Naravno(1).rc()).eval(&[]).unwrap(), Resnica);
 
         assert_eq!(Zanikaj(Resnica.rc()).eval(&[]).unwrap(), Laž);
         assert_eq!(Zanikaj(Laž.rc()).eval(&[]).unwrap(), Resnica);
@@ -881,6 +1188,8 @@ mod testi {
 
         assert_eq!(BitniPremikDesno(Celo(0b10).rc(), Celo(1).rc()).eval(&[]).unwrap(), Celo(0b1));
         assert_eq!(BitniPremikDesno(Celo(0b1100).rc(), Celo(2).rc()).eval(&[]).unwrap(), Celo(0b11));
+        assert_eq!(BitniPremikDesno(Celo(-8).rc(), Celo(1).rc()).eval(&[]).unwrap(), Celo(-4));
+        assert_eq!(BitniPremikDesno(Naravno(1 << 31).rc(), Celo(31).rc()).eval(&[]).unwrap(), Naravno(1));
 
         assert_eq!(Enako(Tip::Celo, Celo(12).rc(), Celo(12).rc()).eval(&[]).unwrap(), Resnica);
         assert_eq!(Enako(Tip::Celo, Celo(13).rc(), Celo(14).rc()).eval(&[]).unwrap(), Laž);
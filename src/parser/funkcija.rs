@@ -48,6 +48,7 @@ impl<'a> Parser<'a> {
         ]);
 
         let mut parametri = Vec::new(); 
+        let mut imena_parametrov = Vec::new();
         let mut napake = Napake::new();
 
         for parameter in parametri_izraz.split(|p| if let Ločilo(",", ..) = p { true } else { false }) {
@@ -75,10 +76,11 @@ impl<'a> Parser<'a> {
                 let spr = spremenljivka(&tip, ime.as_str());
                 spr_funkcije.insert(ime.as_str(), spr.clone());
                 parametri.push(spr);
+                imena_parametrov.push(*ime);
             }
         }
 
-        let podpis_funkcije = Self::podpis_funkcije(ime, parametri.iter()
+        let podpis_funkcije = Self::podpis_funkcije(ime.as_str(), parametri.iter()
             .map(|p| p.tip())
             .collect::<Vec<Tip>>()
             .as_slice());
@@ -86,20 +88,30 @@ impl<'a> Parser<'a> {
         spr_funkcije.insert("0_PC", spremenljivka(&Tip::Celo, "0_PC"));
         spr_funkcije.insert("0_OF", spremenljivka(&Tip::Celo, "0_OF"));
 
-        let mut okolje_funkcije = self.clone();
-        okolje_funkcije.znotraj_funkcije = true;
-
-        okolje_funkcije.spremenljivke_stack.push(spr_funkcije.clone());
-        okolje_funkcije.spremenljivke.extend(spr_funkcije);
-        okolje_funkcije.funkcije.insert(podpis_funkcije.clone(), Funkcija { 
+        let deklaracija = Funkcija { 
             tip: tip.clone(),
             ime: podpis_funkcije.clone(),
             parametri: parametri.clone(),
             telo: Prazno.rc(),
             prostor: 0,
-        }.rc());
+        }.rc();
+        self.zabeleži_funkcijo(ime, &deklaracija);
+        self.zabeleži_deklaracijo_funkcije(ime, &podpis_funkcije);
 
+        let mut okolje_funkcije = self.clone();
+        okolje_funkcije.znotraj_funkcije = true;
+
+        okolje_funkcije.spremenljivke_stack.push(spr_funkcije.clone());
+        okolje_funkcije.spremenljivke.extend(spr_funkcije);
+        okolje_funkcije.funkcije.insert(podpis_funkcije.clone(), deklaracija);
+
+        // parametri so vidni do konca telesa funkcije
+        okolje_funkcije.v_doseg(izraz);
+        for (ime_parametra, parameter) in iter::zip(imena_parametrov, &parametri) {
+            okolje_funkcije.zabeleži_definicijo(&ime_parametra, parameter);
+        }
         let telo = okolje_funkcije.zaporedje(telo)?;
+        okolje_funkcije.iz_dosega();
         let spr_funkcije = okolje_funkcije.spremenljivke_stack.last().unwrap();
         let prostor = spr_funkcije.values().map(|s| s.sprememba_stacka()).sum::<i32>()
             - spr_funkcije["0_vrni"].sprememba_stacka()
@@ -108,12 +120,10 @@ impl<'a> Parser<'a> {
             - spr_funkcije["0_OF"].sprememba_stacka();
         let fun = Funkcija { tip, ime: podpis_funkcije.clone(), parametri, telo, prostor }.rc();
 
-        for (podpis, št_klicev) in okolje_funkcije.št_klicev {
-            match self.št_klicev.get_mut(&podpis) {
-                Some(št) => *št += št_klicev,
-                None => { self.št_klicev.insert(podpis, št_klicev); }
-            }
-        }
+        // okolje funkcije je kopija trenutnega, zato že vsebuje vse dosedanje klice in simbole
+        self.št_klicev = okolje_funkcije.št_klicev;
+        self.simboli = okolje_funkcije.simboli;
+        self.opozorila = okolje_funkcije.opozorila;
 
         self.funkcije.insert(podpis_funkcije, fun.clone());
         self.funkcije_vec.push(fun.clone());
@@ -140,11 +150,38 @@ impl<'a> Parser<'a> {
         argumenti: &'b[Žeton<'a>]
     ) -> Result<Rc<Vozlišče>, Napake> {
         let Argumenti { tipi, spremenljivke, argumenti } = self.argumenti(argumenti)?;
-        let podpis_funkcije = Self::podpis_funkcije(ime, tipi.as_slice());
+        let podpis_funkcije = Self::podpis_funkcije(ime.as_str(), tipi.as_slice());
 
         let funkcija = self.funkcije.get(&podpis_funkcije)
             .ok_or(Napake::from_zaporedje(&[*ime], E2, &format!("Funkcija '{podpis_funkcije}' ne obstaja")))?
             .clone();
+        self.zabeleži_klic(ime, &funkcija);
+
+        match self.št_klicev.get_mut(&podpis_funkcije) {
+            Some(št_klicev) => *št_klicev += 1,
+            None => { self.št_klicev.insert(podpis_funkcije, 1); },
+        }
+
+        Ok(FunkcijskiKlic {
+            funkcija,
+            spremenljivke: Zaporedje(spremenljivke).rc(),
+            argumenti: Zaporedje(argumenti).rc() }.rc())
+    }
+
+    // klic funkcije iz jedra namesto operacije (npr. združevanje nizov)
+    pub fn klic_jedra(
+        &mut self,
+        ime: &str,
+        izraz: &[Žeton],
+        spremenljivke: Vec<Rc<Vozlišče>>,
+        argumenti: Vec<Rc<Vozlišče>>,
+    ) -> Result<Rc<Vozlišče>, Napake> {
+        let tipi = argumenti.iter().map(|a| a.tip()).collect::<Vec<Tip>>();
+        let podpis_funkcije = Self::podpis_funkcije(ime, tipi.as_slice());
+
+        let funkcija = self.funkcije.get(&podpis_funkcije)
+            .ok_or(Napake::from_zaporedje(izraz, E2, &format!("Funkcija '{podpis_funkcije}' ne obstaja")))?
+            .clone();
 
         match self.št_klicev.get_mut(&podpis_funkcije) {
             Some(št_klicev) => *št_klicev += 1,
@@ -167,11 +204,12 @@ impl<'a> Parser<'a> {
         let mut napake = Napake::new();
 
         for (tip, (spremenljivka, argument)) in iter::zip(tipi, iter::zip(spremenljivke, argumenti)) {
-            let podpis_funkcije = Self::podpis_funkcije(ime, &[tip]);
+            let podpis_funkcije = Self::podpis_funkcije(ime.as_str(), &[tip]);
             let funkcija = self.funkcije.get(&podpis_funkcije);
 
-            match funkcija {
+            match funkcija.cloned() {
                 Some(funkcija) => {
+                    self.zabeleži_klic(ime, &funkcija);
                     funkcijski_klici.push(FunkcijskiKlic {
                         funkcija: funkcija.clone(),
                         spremenljivke: Zaporedje(vec![spremenljivka.rc()]).rc(),
@@ -198,8 +236,8 @@ impl<'a> Parser<'a> {
         }
     }
 
-    fn podpis_funkcije(ime: &Žeton, tipi: &[Tip]) -> String {
-        format!("{}({})", ime.as_str(), tipi.iter()
+    fn podpis_funkcije(ime: &str, tipi: &[Tip]) -> String {
+        format!("{}({})", ime, tipi.iter()
             .map(|t| t.to_string())
             .collect::<Vec<String>>()
             .join(", "))
@@ -1,5 +1,5 @@
 use super::*;
-use crate::parser::loci::Escape;
+use crate::parser::{loci::Escape, lekser::Del};
 
 impl<'a> Parser<'a> {
     pub fn drevo(&mut self, izraz: &[Žeton<'a>]) -> Result<Rc<Vozlišče>, Napake> {
@@ -41,6 +41,7 @@ impl<'a> Parser<'a> {
                 let d = self.bitni(d_izraz)?;
                 match (l.tip(), d.tip()) {
                     (Tip::Celo, Tip::Celo) => Ok(bitni_op(op.as_str())(l, d).rc()),
+                    (Tip::Naravno, Tip::Naravno) => Ok(bitni_op(op.as_str())(l, d).rc()),
                     _ => Err(Napake::from_zaporedje(&[*op], E5, &format!("Neveljavna tipa za operacijo: {} {} {}", l.tip(), op.as_str(), d.tip()))),
                 }
             },
@@ -51,6 +52,7 @@ impl<'a> Parser<'a> {
                     let d = self.bitni(d_izraz)?;
                     match (l.tip(), d.tip()) {
                         (Tip::Celo, Tip::Celo) => Ok(bitni_op(op.as_str())(l, d).rc()),
+                        (Tip::Naravno, Tip::Naravno) => Ok(bitni_op(op.as_str())(l, d).rc()),
                         _ => Err(Napake::from_zaporedje(&[*op], E5, &format!("Neveljavna tipa za operacijo: {} {} {}", l.tip(), op.as_str(), d.tip()))),
                     }
                 },
@@ -61,6 +63,7 @@ impl<'a> Parser<'a> {
                         let d = self.bitni(d_izraz)?;
                         match (l.tip(), d.tip()) {
                             (Tip::Celo, Tip::Celo) => Ok(bitni_op(op.as_str())(l, d).rc()),
+                            (Tip::Naravno, Tip::Naravno) => Ok(bitni_op(op.as_str())(l, d).rc()),
                             _ => Err(Napake::from_zaporedje(&[*op], E5, &format!("Neveljavna tipa za operacijo: {} {} {}", l.tip(), op.as_str(), d.tip()))),
                         }
                     },
@@ -71,6 +74,7 @@ impl<'a> Parser<'a> {
                             let d = self.bitni(d_izraz)?;
                             match (l.tip(), d.tip()) {
                                 (Tip::Celo, Tip::Celo) => Ok(bitni_op(op.as_str())(l, d).rc()),
+                                (Tip::Naravno, Tip::Celo) => Ok(bitni_op(op.as_str())(l, d).rc()),
                                 _ => Err(Napake::from_zaporedje(&[*op], E5, &format!("Neveljavna tipa za operacijo: {} {} {}", l.tip(), op.as_str(), d.tip()))),
                             }
                         },
@@ -88,18 +92,39 @@ impl<'a> Parser<'a> {
             Some(Ok((l_izraz, op, d_izraz))) => {
                 let l = self.primerjalni(l_izraz)?;
                 let d = self.primerjalni(d_izraz)?;
-                match (l.tip(), d.tip()) {
-                    (Tip::Celo, Tip::Celo) => Ok(primerjalni_op(op.as_str()).unwrap()(Tip::Celo, l, d).rc()),
-                    (Tip::Znak, Tip::Znak) => Ok(primerjalni_op(op.as_str()).unwrap()(Tip::Celo, ZnakVCelo(l).rc(), ZnakVCelo(d).rc()).rc()),
-                    (Tip::Real, Tip::Real) => Ok(primerjalni_op(op.as_str()).unwrap()(Tip::Real, l, d).rc()),
-                    _ => Err(Napake::from_zaporedje(&[*op], E5, &format!("Nemogoča operacija: {} {} {}", l.tip(), op.as_str(), d.tip()))),
-                }
+                let (l, d) = self.poravnaj_niza(&[*op], l, d)?;
+                let primerjava = self.primerjaj(op, l, d)?;
+                self.preveri_primerjavo(izraz, &primerjava);
+                Ok(primerjava)
             },
             Some(Err(napaka)) => Err(napaka),
             None => self.aditivni(izraz)
         }
     }
 
+    pub fn primerjaj(&mut self, op: &Žeton, l: Rc<Vozlišče>, d: Rc<Vozlišče>) -> Result<Rc<Vozlišče>, Napake> {
+        match (l.tip(), d.tip()) {
+            // nize primerjamo leksikografsko, funkcija vrne predznak razlike
+            (Tip::Niz, Tip::Niz) => {
+                let razlika = self.klic_jedra("_primerjaj", &[*op], vec![], vec![l, d])?;
+                Ok(primerjalni_op(op.as_str()).unwrap()(Tip::Celo, razlika, Celo(0).rc()).rc())
+            },
+            (Tip::Celo, Tip::Celo) => Ok(primerjalni_op(op.as_str()).unwrap()(Tip::Celo, l, d).rc()),
+            (Tip::Znak, Tip::Znak) => Ok(primerjalni_op(op.as_str()).unwrap()(Tip::Celo, ZnakVCelo(l).rc(), ZnakVCelo(d).rc()).rc()),
+            (Tip::Real, Tip::Real) => Ok(primerjalni_op(op.as_str()).unwrap()(Tip::Real, l, d).rc()),
+            (Tip::Celo64, Tip::Celo64) => Ok(primerjalni_op(op.as_str()).unwrap()(Tip::Celo64, l, d).rc()),
+            (Tip::Real64, Tip::Real64) => Ok(primerjalni_op(op.as_str()).unwrap()(Tip::Real64, l, d).rc()),
+            (Tip::Naravno, Tip::Naravno) => Ok(primerjalni_op(op.as_str()).unwrap()(Tip::Naravno, l, d).rc()),
+            // sezname lahko samo enačimo
+            (l_tip @ Tip::Seznam(..), d_tip @ Tip::Seznam(..)) if l_tip == d_tip && matches!(op.as_str(), "==" | "!=") =>
+                Ok(primerjalni_op(op.as_str()).unwrap()(l_tip, l, d).rc()),
+            // naštevanja lahko samo enačimo
+            (l_tip @ Tip::Naštevanje(..), d_tip @ Tip::Naštevanje(..)) if l_tip == d_tip && matches!(op.as_str(), "==" | "!=") =>
+                Ok(primerjalni_op(op.as_str()).unwrap()(Tip::Celo, NaštevanjeVCelo(l).rc(), NaštevanjeVCelo(d).rc()).rc()),
+            _ => Err(Napake::from_zaporedje(&[*op], E5, &format!("Nemogoča operacija: {} {} {}", l.tip(), op.as_str(), d.tip()))),
+        }
+    }
+
     // aritmetični izrazi
 
     fn aditivni(&mut self, izraz: &[Žeton<'a>]) -> Result<Rc<Vozlišče>, Napake> {
@@ -111,11 +136,16 @@ impl<'a> Parser<'a> {
 
             // "-" kot binarni operator
             Some(Ok((l_izraz, op, d_izraz))) => {
-                let l = self.aritmetični(l_izraz)?;
+                let l = self.aditivni(l_izraz)?;
                 let d = self.aritmetični(d_izraz)?;
+                let (l, d) = self.poravnaj_niza(&[*op], l, d)?;
                 match (l.tip(), d.tip()) {
+                    (Tip::Niz, Tip::Niz) if op.as_str() == "+" => self.klic_jedra("_združi", &[*op], vec![], vec![l, d]),
                     (Tip::Celo, Tip::Celo) => Ok(aritmetični_op(op.as_str())(Tip::Celo, l, d).rc()),
                     (Tip::Real, Tip::Real) => Ok(aritmetični_op(op.as_str())(Tip::Real, l, d).rc()),
+                    (Tip::Celo64, Tip::Celo64) => Ok(aritmetični_op(op.as_str())(Tip::Celo64, l, d).rc()),
+                    (Tip::Real64, Tip::Real64) => Ok(aritmetični_op(op.as_str())(Tip::Real64, l, d).rc()),
+                    (Tip::Naravno, Tip::Naravno) => Ok(aritmetični_op(op.as_str())(Tip::Naravno, l, d).rc()),
                     _ => Err(Napake::from_zaporedje(&[*op], E5, &format!("Nemogoča operacija: {} {} {}", l.tip(), op.as_str(), d.tip()))),
                 }
             },
@@ -132,6 +162,9 @@ impl<'a> Parser<'a> {
                 match (l.tip(), d.tip()) {
                     (Tip::Celo, Tip::Celo) => Ok(aritmetični_op(op.as_str())(Tip::Celo, l, d).rc()),
                     (Tip::Real, Tip::Real) => Ok(aritmetični_op(op.as_str())(Tip::Real, l, d).rc()),
+                    (Tip::Celo64, Tip::Celo64) => Ok(aritmetični_op(op.as_str())(Tip::Celo64, l, d).rc()),
+                    (Tip::Real64, Tip::Real64) => Ok(aritmetični_op(op.as_str())(Tip::Real64, l, d).rc()),
+                    (Tip::Naravno, Tip::Naravno) => Ok(aritmetični_op(op.as_str())(Tip::Naravno, l, d).rc()),
                     _ => Err(Napake::from_zaporedje(&[*op], E5, &format!("Nemogoča operacija: {} {} {}", l.tip(), op.as_str(), d.tip()))),
                 }
             },
@@ -143,6 +176,9 @@ impl<'a> Parser<'a> {
                     match (l.tip(), d.tip()) {
                         (Tip::Celo, Tip::Celo) => Ok(aritmetični_op(op.as_str())(Tip::Celo, l, d).rc()),
                         (Tip::Real, Tip::Real) => Ok(aritmetični_op(op.as_str())(Tip::Real, l, d).rc()),
+                        (Tip::Celo64, Tip::Celo64) => Ok(aritmetični_op(op.as_str())(Tip::Celo64, l, d).rc()),
+                        (Tip::Real64, Tip::Real64) => Ok(aritmetični_op(op.as_str())(Tip::Real64, l, d).rc()),
+                        (Tip::Naravno, Tip::Naravno) => Ok(aritmetični_op(op.as_str())(Tip::Naravno, l, d).rc()),
                         _ => Err(Napake::from_zaporedje(&[*op], E5, &format!("Nemogoča operacija: {} {} {}", l.tip(), op.as_str(), d.tip()))),
                     }
                 },
@@ -158,14 +194,19 @@ impl<'a> Parser<'a> {
             [ Literal(L::Bool("resnica", ..)) ] => Ok(Resnica.rc()),
             [ Literal(L::Bool("laž", ..)) ] => Ok(Laž.rc()),
             // števila
-            [ Literal(L::Celo(število, ..)) ] => Ok(Vozlišče::Celo(število.replace("_", "").parse().unwrap()).rc()),
+            [ Literal(L::Celo(število, ..)) ] => Self::celo_število(izraz, število, false),
             [ Literal(L::Real(število, ..)) ] => Ok(Vozlišče::Real(število.replace("_", "").parse().unwrap()).rc()),
-            [ Operator("-", ..), Literal(L::Celo(str, ..)) ] => Ok(Vozlišče::Celo(-str.replace("_", "").parse::<i32>().unwrap()).rc()),
+            [ Operator("-", ..), Literal(L::Celo(str, ..)) ] => Self::celo_število(izraz, str, true),
             [ Operator("-", ..), Literal(L::Real(str, ..)) ] => Ok(Vozlišče::Real(-str.replace("_", "").parse::<f32>().unwrap()).rc()),
             // znak
             [ Literal(L::Znak(str, ..)) ] => Ok(Vozlišče::Znak((&str[1..str.len()-1]).unescape().chars().nth(0).unwrap()).rc()),
             // niz
-            [ Literal(L::Niz(niz, ..)) ] => Ok(Vozlišče::Niz((&niz[1..niz.len()-1]).unescape()).rc()),
+            [ literal @ Literal(L::Niz(niz, ..)) ] => match literal.interpolacija() {
+                Some(deli) => self.interpoliran_niz(literal, deli?),
+                None => Ok(Vozlišče::Niz((&niz[1..niz.len()-1]).unescape()).rc()),
+            },
+            // seznam
+            [ Ločilo("[", ..), elementi @ .., Ločilo("]", ..) ] => self.seznam(izraz, elementi),
             // izraz v oklepaju
             [ Ločilo("(", ..), ostanek @ .., Ločilo(")", ..) ] => self.drevo(ostanek),
             // funkcija asm(str)
@@ -173,7 +214,7 @@ impl<'a> Parser<'a> {
             // klic funkcije
             [ ime @ Ime(..), Ločilo("(", ..), argumenti @ .., Ločilo(")", ..) ] => self.funkcijski_klic(ime, argumenti),
             // pretvorba tipa 
-            [ izraz @ .., Operator("kot", ..), tip @ Tip(..) ] => self.pretvorba(izraz, tip),
+            [ izraz @ .., Operator("kot", ..), tip @ (Tip(..) | Ime("niz", ..)) ] => self.pretvorba(izraz, tip),
             // zanikanje
             [ Operator("!", ..), ostanek @ .. ] => {
                 let drevo = self.drevo(ostanek)?;
@@ -188,19 +229,32 @@ impl<'a> Parser<'a> {
                 match drevo.tip() {
                     Tip::Celo => Ok(Sub(Tip::Celo, Celo(0).rc(), drevo).rc()),
                     Tip::Real => Ok(Sub(Tip::Real, Celo(0).rc(), drevo).rc()),
+                    Tip::Celo64 => Ok(Sub(Tip::Celo64, Celo64(0).rc(), drevo).rc()),
+                    Tip::Real64 => Ok(Sub(Tip::Real64, Real64(0.0).rc(), drevo).rc()),
                     _ => Err(Napake::from_zaporedje(ostanek, E5, &format!("Nemogoča operacija: -{}", drevo.tip()))),
                 }
             },
             // spremenljivka
             [ ime @ Ime(..) ] => self.poišči_spr(ime),
 
-            // referenciraj
-            [ Operator("@", ..), ime @ Ime(..) ] => {
-                let spremenljivka = self.poišči_spr(ime)?;
+            // rezina seznama
+            [ Operator("@", ..), ostanek @ .. ] if je_rezina(ostanek) => self.rezina(ostanek),
 
-                match spremenljivka.tip() {
-                    Tip::Seznam(..) => Ok(RefSeznama(spremenljivka).rc()),
-                    _ => Ok(Referenca(spremenljivka).rc())
+            // referenciraj
+            [ Operator("@", ..), ostanek @ .. ] => {
+                let spremenljivka = self.osnovni(ostanek)?;
+                self.zabeleži_spremembo(&spremenljivka);
+
+                match (&*spremenljivka, spremenljivka.tip()) {
+                    (Spremenljivka { .. } | Indeksiraj { .. }, Tip::Seznam(..)) => Ok(RefSeznama(spremenljivka).rc()),
+                    // referenca na niz je rezina celega niza
+                    (_, Tip::Niz) => Ok(Rezina {
+                        seznam_ref: spremenljivka.clone(),
+                        začetek: Celo(0).rc(),
+                        konec: Dolžina(spremenljivka).rc(),
+                    }.rc()),
+                    (Spremenljivka { .. } | Indeksiraj { .. }, _) => Ok(Referenca(spremenljivka).rc()),
+                    _ => Err(Napake::from_zaporedje(ostanek, E2, "Referenciramo lahko samo spremenljivko ali element seznama.")),
                 }
             }
 
@@ -218,27 +272,23 @@ impl<'a> Parser<'a> {
             }
 
             // indeksiraj
-            [ ime @ Ime(..), Ločilo("[", ..), indeks @ .., Ločilo("]", ..) ] => {
-                let indeks = self.drevo(indeks)?.rc();
-                let spremenljivka = self.poišči_spr(ime)?;
-
-                match &*spremenljivka {
-                    Spremenljivka { tip: Tip::Seznam(..), .. } =>
-                        Ok(Indeksiraj{ seznam_ref: RefSeznama(spremenljivka.clone()).rc(), indeks }.rc()),
-                    Spremenljivka { tip: Tip::RefSeznama(..), .. } =>
-                        Ok(Indeksiraj{ seznam_ref: spremenljivka.clone(), indeks }.rc()),
-                    _ => Err(Napake::from_zaporedje(izraz, E2, 
-                            &format!("V spremenljivko tipa '{}' ni mogoče indeksirati.", spremenljivka.tip()))),
-                }
-            }
+            [ Ime(..), Ločilo("[", ..), .., Ločilo("]", ..) ] => self.indeksiraj(izraz),
+
+            // varianta naštevanja
+            [ tip @ Ime(ime_tipa, ..), Ločilo(".", ..), varianta @ Ime(ime_variante, ..) ] if Tip::naštevanje(ime_tipa, &self.konstante).is_some() =>
+                match self.konstante.get(&format!("{ime_tipa}.{ime_variante}")) {
+                    Some(varianta) => Ok(varianta.clone()),
+                    None => Err(Napake::from_zaporedje(&[*tip, *varianta], E2,
+                            &format!("Naštevanje '{ime_tipa}' nima variante '{ime_variante}'"))),
+                },
 
-            [ ime @ Ime(..), Ločilo(".", ..), Ime("dolžina", ..) ] => {
-                let spremenljivka = self.poišči_spr(ime)?;
+            [ seznam @ .., Ločilo(".", ..), Ime("dolžina", ..) ] => {
+                let spremenljivka = self.osnovni(seznam)?;
 
                 match spremenljivka.tip() {
                     Tip::Seznam(..) => Ok(Dolžina(spremenljivka.clone()).rc()),
-                    Tip::RefSeznama(..) => Ok(Dolžina(spremenljivka.clone()).rc()),
-                    _ => Err(Napake::from_zaporedje(&[*ime], E2, 
+                    Tip::RefSeznama(..) | Tip::Niz => Ok(Dolžina(spremenljivka.clone()).rc()),
+                    _ => Err(Napake::from_zaporedje(seznam, E2, 
                             &format!("Tip '{}' nima dolžine", spremenljivka.tip())))
                 }
             },
@@ -250,34 +300,396 @@ impl<'a> Parser<'a> {
         }
     }
 
+    // indeksiranje je postfiksni izraz: seznam[i][j] ...
+    pub fn indeksiraj(&mut self, izraz: &[Žeton<'a>]) -> Result<Rc<Vozlišče>, Napake> {
+        let (seznam_izraz, indeks_izraz) = loči_indeks(izraz)
+            .ok_or(Napake::from_zaporedje(izraz, E1, "Oklepaji se ne ujemajo"))?;
+
+        let seznam = self.osnovni(seznam_izraz)?;
+        let indeks = self.drevo(indeks_izraz)?;
+
+        if indeks.tip() != Tip::Celo {
+            return Err(Napake::from_zaporedje(indeks_izraz, E3,
+                    &format!("Neveljaven tip indeksa: '{}'", indeks.tip())));
+        }
+
+        match (&*seznam, seznam.tip()) {
+            (Spremenljivka { .. } | Indeksiraj { .. }, Tip::Seznam(..)) =>
+                Ok(Indeksiraj{ seznam_ref: RefSeznama(seznam.clone()).rc(), indeks }.rc()),
+            (_, Tip::RefSeznama(..) | Tip::Niz) =>
+                Ok(Indeksiraj{ seznam_ref: seznam.clone(), indeks }.rc()),
+            _ => Err(Napake::from_zaporedje(seznam_izraz, E2,
+                    &format!("V spremenljivko tipa '{}' ni mogoče indeksirati.", seznam.tip()))),
+        }
+    }
+
+    // rezina je referenca na del seznama: @seznam[od..do]
+    fn rezina(&mut self, izraz: &[Žeton<'a>]) -> Result<Rc<Vozlišče>, Napake> {
+        let (seznam_izraz, meje) = loči_indeks(izraz)
+            .ok_or(Napake::from_zaporedje(izraz, E1, "Oklepaji se ne ujemajo"))?;
+        let (začetek_izraz, _, konec_izraz) = loči_spredaj(meje, &[".."]).unwrap()?;
+
+        let seznam = self.osnovni(seznam_izraz)?;
+        self.zabeleži_spremembo(&seznam);
+        let seznam_ref = match (&*seznam, seznam.tip()) {
+            (Spremenljivka { .. } | Indeksiraj { .. }, Tip::Seznam(..)) => RefSeznama(seznam.clone()).rc(),
+            (_, Tip::RefSeznama(..) | Tip::Niz) => seznam.clone(),
+            _ => return Err(Napake::from_zaporedje(seznam_izraz, E2,
+                    &format!("Iz spremenljivke tipa '{}' ni mogoče narediti rezine.", seznam.tip()))),
+        };
+
+        let začetek = match začetek_izraz {
+            [] => Celo(0).rc(),
+            _ => self.drevo(začetek_izraz)?,
+        };
+        let konec = match konec_izraz {
+            [] => Dolžina(seznam.clone()).rc(),
+            _ => self.drevo(konec_izraz)?,
+        };
+
+        for (meja, meja_izraz) in [(&začetek, začetek_izraz), (&konec, konec_izraz)] {
+            if meja.tip() != Tip::Celo {
+                return Err(Napake::from_zaporedje(meja_izraz, E3,
+                        &format!("Neveljaven tip meje rezine: '{}'", meja.tip())));
+            }
+        }
+
+        Ok(Rezina { seznam_ref, začetek, konec }.rc())
+    }
+
+    // vsi elementi seznama morajo biti enakega tipa
+    fn seznam(&mut self, izraz: &[Žeton<'a>], elementi: &[Žeton<'a>]) -> Result<Rc<Vozlišče>, Napake> {
+        let elementi = razdeli(elementi, &[","])?
+            .into_iter()
+            .filter(|element| !element.is_empty())
+            .map(|element| Ok((element, self.drevo(element)?)))
+            .collect::<Result<Vec<(&[Žeton], Rc<Vozlišče>)>, Napake>>()?;
+
+        let tip = match elementi.first() {
+            Some((_, prvi)) => prvi.tip(),
+            None => return Err(Napake::from_zaporedje(izraz, E5, "Seznam mora imeti vsaj en element")),
+        };
+
+        for (element_izraz, element) in &elementi {
+            if element.tip() != tip {
+                return Err(Napake::from_zaporedje(element_izraz, E3,
+                        &format!("Element tipa '{}' ni mogoče dodati v seznam tipa '{}'", element.tip(), tip)));
+            }
+        }
+
+        Ok(Vozlišče::Seznam(elementi.into_iter().map(|(_, element)| element).collect()).rc())
+    }
+
+    // celo število, ki ne gre v 32 bitov, je tipa celo64
+    fn celo_število(izraz: &[Žeton], število: &str, negativno: bool) -> Result<Rc<Vozlišče>, Napake> {
+        let število = match število.replace("_", "").parse::<i64>() {
+            Ok(število) if negativno => -število,
+            Ok(število) => število,
+            Err(err) => return Err(Napake::from_zaporedje(izraz, E1, &format!("Iz vrednosti ni mogoče ustvariti števila: {err}"))),
+        };
+
+        match i32::try_from(število) {
+            Ok(število) => Ok(Celo(število).rc()),
+            Err(_) => Ok(Celo64(število).rc()),
+        }
+    }
+
     fn pretvorba(&mut self, izraz: &[Žeton<'a>], tip_ven_izraz: &Žeton) -> Result<Rc<Vozlišče>, Napake> {
+        let tip_ven = Tip::from(&[*tip_ven_izraz], &self.konstante)?;
+
+        // literale preberemo neposredno v 64 bitih, da ne izgubimo natančnosti
+        match (izraz, &tip_ven) {
+            ([ Literal(L::Real(število, ..)) ], Tip::Real64) =>
+                return Ok(Real64(število.replace("_", "").parse().unwrap()).rc()),
+            ([ Operator("-", ..), Literal(L::Real(število, ..)) ], Tip::Real64) =>
+                return Ok(Real64(-število.replace("_", "").parse::<f64>().unwrap()).rc()),
+            ([ Literal(L::Celo(število, ..)) ], Tip::Naravno) =>
+                return match število.replace("_", "").parse() {
+                    Ok(število) => Ok(Naravno(število).rc()),
+                    Err(err) => Err(Napake::from_zaporedje(izraz, E1, &format!("Iz vrednosti ni mogoče ustvariti naravnega števila: {err}"))),
+                },
+            ([ Literal(L::Celo(..)) ] | [ Operator("-", ..), Literal(L::Celo(..)) ], Tip::Celo64) =>
+                return match &*self.osnovni(izraz)? {
+                    Celo(število) => Ok(Celo64(*število as i64).rc()),
+                    število => Ok(število.rc()),
+                },
+            _ => (),
+        }
+
         let drevo = self.drevo(izraz)?.rc();
         let tip_noter = drevo.tip();
-        let tip_ven = Tip::from(&[*tip_ven_izraz], &self.konstante)?;
 
         match (tip_noter.clone(), tip_ven.clone()) {
+            // nizi se pretvarjajo s funkcijami iz jedra
+            (_, Tip::Niz) => self.v_niz(izraz, drevo),
+            (Tip::Niz, Tip::Celo) => self.klic_jedra("v_celo", izraz, vec![], vec![drevo]),
+            (Tip::Niz, Tip::Real) => self.klic_jedra("v_real", izraz, vec![], vec![drevo]),
             (Tip::Real, Tip::Celo) => Ok(RealVCelo(drevo).rc()),
             (Tip::Celo, Tip::Real) => Ok(CeloVReal(drevo).rc()),
             (Tip::Celo, Tip::Znak) => Ok(CeloVZnak(drevo).rc()),
             (Tip::Znak, Tip::Celo) => Ok(ZnakVCelo(drevo).rc()),
+            (Tip::Naštevanje(..), Tip::Celo) => Ok(NaštevanjeVCelo(drevo).rc()),
+            (Tip::Celo, Tip::Celo64) => Ok(CeloVCelo64(drevo).rc()),
+            (Tip::Celo64, Tip::Celo) => Ok(Celo64VCelo(drevo).rc()),
+            (Tip::Real, Tip::Real64) => Ok(RealVReal64(drevo).rc()),
+            (Tip::Real64, Tip::Real) => Ok(Real64VReal(drevo).rc()),
+            (Tip::Celo64, Tip::Real64) => Ok(Celo64VReal64(drevo).rc()),
+            (Tip::Real64, Tip::Celo64) => Ok(Real64VCelo64(drevo).rc()),
+            (Tip::Celo, Tip::Naravno) => Ok(CeloVNaravno(drevo).rc()),
+            (Tip::Naravno, Tip::Celo) => Ok(NaravnoVCelo(drevo).rc()),
+            (Tip::Naravno, Tip::Celo64) => Ok(NaravnoVCelo64(drevo).rc()),
+            (Tip::Celo64, Tip::Naravno) => Ok(CeloVNaravno(Celo64VCelo(drevo).rc()).rc()),
+            // naravna števila v realna (in nazaj) pretvorimo preko celo64
+            (Tip::Naravno, Tip::Real64) => Ok(Celo64VReal64(NaravnoVCelo64(drevo).rc()).rc()),
+            (Tip::Naravno, Tip::Real) => Ok(Real64VReal(Celo64VReal64(NaravnoVCelo64(drevo).rc()).rc()).rc()),
+            (Tip::Real64, Tip::Naravno) => Ok(CeloVNaravno(Celo64VCelo(Real64VCelo64(drevo).rc()).rc()).rc()),
+            (Tip::Real, Tip::Naravno) => Ok(CeloVNaravno(Celo64VCelo(Real64VCelo64(RealVReal64(drevo).rc()).rc()).rc()).rc()),
             (a, b) if a == b => Ok(drevo),
             _ => Err(Napake::from_zaporedje(&[*tip_ven_izraz], E1,
                     &format!("Tipa {} ni mogoče pretvoriti v {}", tip_noter, tip_ven)))
         }
     }
 
-    fn asm(&self, izraz: &[Žeton<'a>]) -> Result<Rc<Vozlišče>, Napake> {
-        let niz = match izraz {
-            [ Literal(L::Niz(niz, ..)) ] => &niz[1..niz.len()-1],
+    fn v_niz(&mut self, izraz: &[Žeton<'a>], drevo: Rc<Vozlišče>) -> Result<Rc<Vozlišče>, Napake> {
+        match drevo.tip() {
+            Tip::Niz => Ok(drevo),
+            Tip::Seznam(tip, _) if *tip == Tip::Znak => self.seznam_v_niz(izraz, drevo),
+            Tip::RefSeznama(tip) if *tip == Tip::Znak => self.klic_jedra("v_niz", izraz, vec![], vec![drevo]),
+            Tip::Bool | Tip::Celo | Tip::Celo64 | Tip::Real | Tip::Znak => self.klic_jedra("v_niz", izraz, vec![], vec![drevo]),
+            tip => Err(Napake::from_zaporedje(izraz, E1, &format!("Tipa {tip} ni mogoče pretvoriti v niz"))),
+        }
+    }
+
+    // niz z vstavljenimi izrazi združimo iz posameznih delov
+    fn interpoliran_niz(&mut self, literal: &Žeton<'a>, deli: Vec<Del<'a>>) -> Result<Rc<Vozlišče>, Napake> {
+        let mut niz: Option<Rc<Vozlišče>> = None;
+
+        for del in deli {
+            let del = match del {
+                Del::Besedilo(besedilo) => self.seznam_v_niz(&[*literal], Vozlišče::Niz(besedilo).rc())?,
+                Del::Izraz(izraz, oblika) => self.vstavljen_izraz(&izraz, oblika)?,
+            };
+            niz = Some(match niz {
+                Some(niz) => self.klic_jedra("_združi", &[*literal], vec![], vec![niz, del])?,
+                None => del,
+            });
+        }
+
+        Ok(niz.unwrap())
+    }
+
+    // oblika: [<][0][širina][.natančnost][x|e]
+    fn vstavljen_izraz(&mut self, izraz: &[Žeton<'a>], oblika: &str) -> Result<Rc<Vozlišče>, Napake> {
+        let drevo = self.drevo(izraz)?;
+        if oblika.is_empty() {
+            return self.v_niz(izraz, drevo);
+        }
+
+        let Oblika { levo, ničle, širina, natančnost, šestnajstiško, znanstveno } = Oblika::from(oblika)
+            .ok_or(Napake::from_zaporedje(izraz, E1, &format!("Neveljavna oblika: '{oblika}'")))?;
+        let bool = |b: bool| if b { Resnica.rc() } else { Laž.rc() };
+
+        match (drevo.tip(), natančnost) {
+            (Tip::Celo, None) if !znanstveno =>
+                self.klic_jedra("_oblikuj", izraz, vec![], vec![drevo, Celo(širina).rc(), bool(ničle), bool(levo), bool(šestnajstiško)]),
+            (Tip::Real, natančnost) if !šestnajstiško =>
+                self.klic_jedra("_oblikuj", izraz, vec![], vec![drevo, Celo(širina).rc(), Celo(natančnost.unwrap_or(-1)).rc(), bool(ničle), bool(levo), bool(znanstveno)]),
+            (_, None) if !ničle && !šestnajstiško && !znanstveno => {
+                let niz = self.v_niz(izraz, drevo)?;
+                self.klic_jedra("_oblikuj", izraz, vec![], vec![niz, Celo(širina).rc(), bool(levo)])
+            },
+            (tip, _) => Err(Napake::from_zaporedje(izraz, E3, &format!("Oblike '{oblika}' ni mogoče uporabiti za tip '{tip}'"))),
+        }
+    }
+
+    fn seznam_v_niz(&mut self, izraz: &[Žeton<'a>], drevo: Rc<Vozlišče>) -> Result<Rc<Vozlišče>, Napake> {
+        match &*drevo {
+            Spremenljivka { .. } | Indeksiraj { .. } =>
+                self.klic_jedra("v_niz", izraz, vec![], vec![RefSeznama(drevo.clone()).rc()]),
+            _ => {
+                let spr = self.začasna_spremenljivka(drevo.tip());
+                let prirejanje = Prirejanje { spremenljivka: spr.clone(), izraz: drevo }.rc();
+                self.klic_jedra("v_niz", izraz, vec![prirejanje], vec![RefSeznama(spr).rc()])
+            },
+        }
+    }
+
+    // seznam znakov (npr. literal) ob nizu se samodejno pretvori v niz
+    fn poravnaj_niza(&mut self, izraz: &[Žeton<'a>], l: Rc<Vozlišče>, d: Rc<Vozlišče>) -> Result<(Rc<Vozlišče>, Rc<Vozlišče>), Napake> {
+        match (l.tip(), d.tip()) {
+            (Tip::Niz, Tip::Seznam(tip, _)) if *tip == Tip::Znak => Ok((l, self.seznam_v_niz(izraz, d)?)),
+            (Tip::Seznam(tip, _), Tip::Niz) if *tip == Tip::Znak => Ok((self.seznam_v_niz(izraz, l)?, d)),
+            _ => Ok((l, d)),
+        }
+    }
+
+    pub fn asm(&mut self, izraz: &[Žeton<'a>]) -> Result<Rc<Vozlišče>, Napake> {
+        let (niz, argumenti) = match izraz {
+            [ Literal(L::Niz(niz, ..)) ] => (&niz[1..niz.len()-1], [].as_slice()),
+            [ Literal(L::Niz(niz, ..)), Ločilo(",", ..), argumenti @ .. ] => (&niz[1..niz.len()-1], argumenti),
             _ => Err(Napake::from_zaporedje(izraz, E5, "Funkcija 'asm' sprejema samo nize"))?,
         };
 
-        match niz {
-            "GETC" => Ok(Preberi.rc()),
-            _ => Err(Napake::from_zaporedje(izraz, E1,
-                    &format!("Neznan ukaz: {niz}")))
+        match (niz, argumenti) {
+            ("GETC", []) => Ok(Preberi.rc()),
+            ("PUTE", argumenti_izraz) => {
+                let argumenti = self.argumenti_ukaza(izraz, "PUTE", argumenti_izraz, &[Tip::Znak])?;
+                Ok(NatisniNapako(argumenti[0].clone()).rc())
+            },
+            ("SEED", []) => Ok(Seme.rc()),
+            ("TIME", []) => Ok(Čas.rc()),
+            ("SLEP", argumenti_izraz) => {
+                let argumenti = self.argumenti_ukaza(izraz, "SLEP", argumenti_izraz, &[Tip::Celo])?;
+                Ok(Spi(argumenti[0].clone()).rc())
+            },
+            ("ARGC", []) => Ok(ŠteviloArgumentov.rc()),
+            ("ARGV", indeks_izraz) => {
+                let indeks = self.drevo(indeks_izraz)?;
+                match indeks.tip() {
+                    Tip::Celo => Ok(Argument(indeks).rc()),
+                    tip => Err(Napake::from_zaporedje(indeks_izraz, E3, &format!("Neveljaven tip indeksa: '{tip}'"))),
+                }
+            },
+            ("ENVV", ime_izraz) => {
+                let ime = self.drevo(ime_izraz)?;
+                match ime.tip() {
+                    Tip::Niz => Ok(Okolje(ime).rc()),
+                    tip => Err(Napake::from_zaporedje(ime_izraz, E3, &format!("Neveljaven tip imena: '{tip}'"))),
+                }
+            },
+            ("OPEN", argumenti_izraz) => {
+                let argumenti = self.argumenti_ukaza(izraz, "OPEN", argumenti_izraz, &[Tip::Niz, Tip::Celo])?;
+                Ok(Odpri(argumenti[0].clone(), argumenti[1].clone()).rc())
+            },
+            ("CLOS", argumenti_izraz) => {
+                let argumenti = self.argumenti_ukaza(izraz, "CLOS", argumenti_izraz, &[Tip::Celo])?;
+                Ok(Zapri(argumenti[0].clone()).rc())
+            },
+            ("FGTC", argumenti_izraz) => {
+                let argumenti = self.argumenti_ukaza(izraz, "FGTC", argumenti_izraz, &[Tip::Celo])?;
+                Ok(PreberiZnak(argumenti[0].clone()).rc())
+            },
+            ("FPTC", argumenti_izraz) => {
+                let argumenti = self.argumenti_ukaza(izraz, "FPTC", argumenti_izraz, &[Tip::Celo, Tip::Znak])?;
+                Ok(ZapišiZnak(argumenti[0].clone(), argumenti[1].clone()).rc())
+            },
+            ("ALHP", dolžina_izraz) => {
+                let dolžina = self.drevo(dolžina_izraz)?;
+                match dolžina.tip() {
+                    Tip::Celo => Ok(Alociraj(dolžina).rc()),
+                    tip => Err(Napake::from_zaporedje(dolžina_izraz, E3, &format!("Neveljaven tip dolžine: '{tip}'"))),
+                }
+            },
+            (ukaz, argumenti_izraz) => match Matematika::from_ukaz(ukaz) {
+                Some(funkcija) => self.matematična(izraz, funkcija, argumenti_izraz),
+                None => Err(Napake::from_zaporedje(izraz, E1,
+                        &format!("Neznan ukaz: {niz}"))),
+            },
+        }
+    }
+
+    // ukaz, katerega rezultat zavržemo (uporabljen kot stavek)
+    pub fn asm_zavrzi_izhod(&mut self, izraz: &[Žeton<'a>]) -> Result<Rc<Vozlišče>, Napake> {
+        let ukaz = self.asm(izraz)?;
+        let velikost = ukaz.tip().sprememba_stacka();
+
+        Ok(Zaporedje(vec![
+            ukaz,
+            Pop(velikost).rc(),
+        ]).rc())
+    }
+
+    fn matematična(&mut self, izraz: &[Žeton<'a>], funkcija: Matematika, argumenti_izraz: &[Žeton<'a>]) -> Result<Rc<Vozlišče>, Napake> {
+        let argumenti = self.argumenti_ukaza(izraz, funkcija.ukaz(), argumenti_izraz, &funkcija.parametri())?;
+        Ok(Matematična(funkcija, argumenti).rc())
+    }
+
+    // argumenti ukaza, ločeni z vejicami, s preverjenim številom in tipi
+    fn argumenti_ukaza(&mut self, izraz: &[Žeton<'a>], ukaz: &str, argumenti_izraz: &[Žeton<'a>], parametri: &[Tip]) -> Result<Vec<Rc<Vozlišče>>, Napake> {
+        let razdeljeno = if argumenti_izraz.is_empty() { vec![] } else { razdeli(argumenti_izraz, &[","])? };
+
+        if razdeljeno.len() != parametri.len() {
+            return Err(Napake::from_zaporedje(izraz, E5,
+                    &format!("Ukaz '{ukaz}' sprejema {} argumentov, podanih je {}", parametri.len(), razdeljeno.len())));
+        }
+
+        let mut argumenti = Vec::new();
+        for (argument_izraz, tip) in razdeljeno.into_iter().zip(parametri) {
+            let argument = self.drevo(argument_izraz)?;
+            if argument.tip() != *tip {
+                return Err(Napake::from_zaporedje(argument_izraz, E3,
+                        &format!("Neveljaven tip argumenta: '{}', pričakovan '{tip}'", argument.tip())));
+            }
+            argumenti.push(argument);
+        }
+        Ok(argumenti)
+    }
+}
+
+struct Oblika {
+    levo: bool,
+    ničle: bool,
+    širina: i32,
+    natančnost: Option<i32>,
+    šestnajstiško: bool,
+    znanstveno: bool,
+}
+
+impl Oblika {
+    fn from(oblika: &str) -> Option<Oblika> {
+        let (levo, oblika) = match oblika.strip_prefix('<') {
+            Some(oblika) => (true, oblika),
+            None => (false, oblika),
+        };
+        let (šestnajstiško, oblika) = match oblika.strip_suffix('x') {
+            Some(oblika) => (true, oblika),
+            None => (false, oblika),
+        };
+        let (znanstveno, oblika) = match oblika.strip_suffix('e') {
+            Some(oblika) if !šestnajstiško => (true, oblika),
+            _ => (false, oblika),
+        };
+        let (širina, natančnost) = match oblika.split_once('.') {
+            Some((širina, natančnost)) => (širina, Some(natančnost.parse().ok()?)),
+            None => (oblika, None),
+        };
+
+        Some(Oblika {
+            levo,
+            ničle: širina.starts_with('0'),
+            širina: if širina.is_empty() { 0 } else { širina.parse().ok()? },
+            natančnost,
+            šestnajstiško,
+            znanstveno,
+        })
+    }
+}
+
+fn je_rezina(izraz: &[Žeton]) -> bool {
+    match loči_indeks(izraz) {
+        Some((_, indeks)) => matches!(loči_spredaj(indeks, &[".."]), Some(Ok(..))),
+        None => false,
+    }
+}
+
+// razdeli izraz "seznam[indeks]" na seznam in indeks
+fn loči_indeks<'a, 'b>(izraz: &'b [Žeton<'a>]) -> Option<(&'b [Žeton<'a>], &'b [Žeton<'a>])> {
+    let mut oglatih = 0;
+
+    for (i, žeton) in izraz.iter().enumerate().rev() {
+        match žeton.as_str() {
+            "]" => oglatih += 1,
+            "[" => {
+                oglatih -= 1;
+                if oglatih == 0 {
+                    return Some((&izraz[..i], &izraz[i+1..izraz.len()-1]));
+                }
+            },
+            _ => (),
         }
     }
+
+    None
 }
 
 #[cfg(test)]
@@ -298,8 +710,14 @@ mod testi {
         assert_eq!(parser.osnovni([ Literal(L::Celo("3", 1, 1, "[test]"))].as_slice()).unwrap(), Celo(3).rc());
         assert_eq!(parser.osnovni([ Literal(L::Real("3.125", 1, 1, "[test]"))].as_slice()).unwrap(), Real(3.125).rc());
         assert_eq!(parser.osnovni([ Literal(L::Celo("1_000", 1, 1, "[test]"))].as_slice()).unwrap(), Celo(1000).rc());
+        assert_eq!(parser.osnovni([ Literal(L::Celo("2_147_483_648", 1, 1, "[test]"))].as_slice()).unwrap(), Celo64(2_147_483_648).rc());
+        assert_eq!(parser.osnovni("-2147483648".razčleni("[test]").as_slice()).unwrap(), Celo(i32::MIN).rc());
         assert_eq!(parser.osnovni([ Literal(L::Znak("'đ'", 1, 1, "[test]"))].as_slice()).unwrap(), Znak('đ').rc());
         assert_eq!(parser.osnovni([ Literal(L::Niz("\"angleščina\\n\"", 1, 1, "[test]"))].as_slice()).unwrap(), Niz("angleščina\n".to_string()).rc());
+        assert_eq!(parser.osnovni("[1, 2, 3]".razčleni("[test]").as_slice()).unwrap(), Seznam(vec![Celo(1).rc(), Celo(2).rc(), Celo(3).rc()]).rc());
+        assert_eq!(parser.osnovni("[[1.0], [2.0]]".razčleni("[test]").as_slice()).unwrap().tip(), Tip::Seznam(Box::new(Tip::Seznam(Box::new(Tip::Real), 1)), 2));
+        assert!(parser.osnovni("[1, 2.0]".razčleni("[test]").as_slice()).is_err());
+        assert!(parser.osnovni("[]".razčleni("[test]").as_slice()).is_err());
 
         parser.funkcije.insert("fun()".to_string(), Funkcija {
                 tip: Tip::Real,
@@ -332,6 +750,15 @@ mod testi {
             RealVCelo(Real(3.0).rc()).rc());
         assert_eq!(parser.drevo([ Literal(L::Real("3.0", 1, 1, "[test]")), Operator("kot", 1, 3, "[test]"), Tip("real", 1, 7, "[test]") ].as_slice()).unwrap(),
             Real(3.0).rc());
+        assert_eq!(parser.drevo("0.1 kot real64".razčleni("[test]").as_slice()).unwrap(), Real64(0.1).rc());
+        assert_eq!(parser.drevo("-7 kot celo64".razčleni("[test]").as_slice()).unwrap(), Celo64(-7).rc());
+        assert_eq!(parser.drevo("(1 + 2) kot celo64".razčleni("[test]").as_slice()).unwrap(),
+            CeloVCelo64(Add(Tip::Celo, Celo(1).rc(), Celo(2).rc()).rc()).rc());
+        assert_eq!(parser.drevo("0.5 kot real64 kot real".razčleni("[test]").as_slice()).unwrap(), Real64VReal(Real64(0.5).rc()).rc());
+        assert_eq!(parser.drevo("4_000_000_000 kot naravno".razčleni("[test]").as_slice()).unwrap(), Naravno(4_000_000_000).rc());
+        assert_eq!(parser.drevo("-1 kot naravno".razčleni("[test]").as_slice()).unwrap(), CeloVNaravno(Celo(-1).rc()).rc());
+        assert_eq!(parser.drevo("3 kot naravno kot celo64".razčleni("[test]").as_slice()).unwrap(), NaravnoVCelo64(Naravno(3).rc()).rc());
+        assert!(parser.drevo("4_294_967_296 kot naravno".razčleni("[test]").as_slice()).is_err());
     }
 
     #[test]
@@ -358,6 +785,18 @@ mod testi {
         assert_eq!(parser.drevo("2 + -1".razčleni("[test]").as_slice()).unwrap(), Add(Tip::Celo, Celo(2).rc(), Celo(-1).rc()).rc());
     }
 
+    #[test]
+    fn naštevanje() {
+        let program = "naštevanje Barva { Rdeča, Zelena }\nnaštevanje Sadje { Jabolko }\n";
+
+        assert!(format!("{program}naj a = Barva.Rdeča == Barva.Zelena").razčleni("[test]").analiziraj().is_ok());
+        assert!(format!("{program}naj a = Barva.Rdeča kot celo").razčleni("[test]").analiziraj().is_ok());
+        assert!(format!("{program}naj a = Barva.Rdeča == Sadje.Jabolko").razčleni("[test]").analiziraj().is_err());
+        assert!(format!("{program}naj a = Barva.Rdeča < Barva.Zelena").razčleni("[test]").analiziraj().is_err());
+        assert!(format!("{program}naj a = Barva.Rdeča == 0").razčleni("[test]").analiziraj().is_err());
+        assert!(format!("{program}naj a = Barva.Modra").razčleni("[test]").analiziraj().is_err());
+    }
+
     #[test]
     fn primerjalni() {
         let mut parser = Parser::new();
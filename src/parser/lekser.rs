@@ -1,6 +1,8 @@
 use std::{fmt::Debug, hash::Hash};
 use regex::Regex;
 
+use super::{loci::Escape, napaka::{Napake, OznakaNapake::*}};
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Žeton<'a> {
     Ločilo      (&'a str, usize, usize, &'a str),
@@ -108,6 +110,93 @@ impl<'a> Žeton<'a> {
     }
 }
 
+// del niza z vstavljenimi izrazi, npr. "x = {x}, y = {y:.2}"
+#[derive(Debug, Clone, PartialEq)]
+pub enum Del<'a> {
+    Besedilo(String),
+    Izraz(Vec<Žeton<'a>>, &'a str),
+}
+
+impl<'a> Žeton<'a> {
+    // razdeli literal niza na besedilo in izraze (z morebitnim formatom),
+    // None, če niz nima zavitih oklepajev
+    pub fn interpolacija(&self) -> Option<Result<Vec<Del<'a>>, Napake>> {
+        let (niz, vrstica, znak, datoteka) = match self {
+            Žeton::Literal(L::Niz(niz, v, z, f)) if niz.contains(['{', '}']) => (&niz[1..niz.len()-1], *v, *z, *f),
+            _ => return None,
+        };
+
+        let mut deli = Vec::new();
+        let mut besedilo = String::new();
+        let mut znaki = niz.char_indices().peekable();
+
+        while let Some((i, z)) = znaki.next() {
+            match z {
+                // "{{" in "}}" sta navadna oklepaja
+                '{' | '}' if znaki.peek().map(|(_, n)| *n) == Some(z) => {
+                    znaki.next();
+                    besedilo.push(z);
+                },
+                '{' => {
+                    let konec = match niz[i..].find('}') {
+                        Some(konec) => i + konec,
+                        None => return Some(Err(Napake::from_zaporedje(&[*self], E1, "Nezaprt '{' v nizu"))),
+                    };
+                    let (izraz, oblika) = match niz[i+1..konec].split_once(':') {
+                        Some((izraz, oblika)) => (izraz, oblika),
+                        None => (&niz[i+1..konec], ""),
+                    };
+
+                    // +1 za narekovaj in +1 za oklepaj
+                    let odmik = znak + 1 + niz[..i].chars().count();
+                    let tokeni: Vec<Žeton> = Lekser::new(datoteka, izraz).razčleni()
+                        .into_iter()
+                        .map(|t| t.premakni(vrstica, odmik))
+                        .collect();
+
+                    if tokeni.is_empty() {
+                        return Some(Err(Napake::from_zaporedje(&[*self], E1, "Prazen izraz v nizu")));
+                    }
+                    if !besedilo.is_empty() {
+                        deli.push(Del::Besedilo(besedilo.unescape()));
+                        besedilo.clear();
+                    }
+                    deli.push(Del::Izraz(tokeni, oblika));
+
+                    while znaki.next_if(|(j, _)| *j <= konec).is_some() {}
+                },
+                '}' => return Some(Err(Napake::from_zaporedje(&[*self], E1, "Neujemajoč '}' v nizu"))),
+                _ => besedilo.push(z),
+            }
+        }
+
+        if !besedilo.is_empty() {
+            deli.push(Del::Besedilo(besedilo.unescape()));
+        }
+        Some(Ok(deli))
+    }
+
+    fn premakni(self, vrstica: usize, odmik: usize) -> Žeton<'a> {
+        use Žeton::*;
+        use L::*;
+        match self {
+            Operator(val, _, z, f)     => Operator(val, vrstica, z + odmik, f),
+            Ločilo(val, _, z, f)       => Ločilo(val, vrstica, z + odmik, f),
+            Rezerviranka(val, _, z, f) => Rezerviranka(val, vrstica, z + odmik, f),
+            Ime(val, _, z, f)          => Ime(val, vrstica, z + odmik, f),
+            Tip(val, _, z, f)          => Tip(val, vrstica, z + odmik, f),
+            Neznano(val, _, z, f)      => Neznano(val, vrstica, z + odmik, f),
+            Žeton::Literal(literal) => Žeton::Literal(match literal {
+                Bool(val, _, z, f) => Bool(val, vrstica, z + odmik, f),
+                Celo(val, _, z, f) => Celo(val, vrstica, z + odmik, f),
+                Real(val, _, z, f) => Real(val, vrstica, z + odmik, f),
+                Znak(val, _, z, f) => Znak(val, vrstica, z + odmik, f),
+                Niz(val, _, z, f)  => Niz(val, vrstica, z + odmik, f),
+            }),
+        }
+    }
+}
+
 impl ToString for Žeton<'_> {
     fn to_string(&self) -> String {
         use Žeton::*;
@@ -167,14 +256,14 @@ impl<'a> Lekser<'a> {
         const PRESLEDEK: &str = r"([^\S\n]*)";
 
         let regexi: Vec<(Regex, fn(&'a str, usize, usize, &'a str) -> Žeton<'a>)> = vec![
-            (Regex::new(&format!(r"^{PRESLEDEK}(naj|spr|kons|čene|če|dokler|za|funkcija|vrni|prekini){ZADNJA_MEJA}")).unwrap(), Rezerviranka),
-            (Regex::new(&format!(r"^{PRESLEDEK}(brez|bool|celo|real|znak){ZADNJA_MEJA}")).unwrap(), Tip),
+            (Regex::new(&format!(r"^{PRESLEDEK}(naj|spr|kons|čene|če|dokler|za|funkcija|vrni|prekini|naštevanje|izberi|preizkus){ZADNJA_MEJA}")).unwrap(), Rezerviranka),
+            (Regex::new(&format!(r"^{PRESLEDEK}(brez|bool|celo64|celo|real64|real|znak|dolgo|naravno){ZADNJA_MEJA}")).unwrap(), Tip),
             (Regex::new(&format!(r"^{PRESLEDEK}(resnica|laž){ZADNJA_MEJA}")).unwrap(), bool),
-            (Regex::new(&format!(r"^{PRESLEDEK}('(.|\\[\\nrt'])')")).unwrap(), znak),
+            (Regex::new(&format!(r"^{PRESLEDEK}('(.|\\[\\nrt'0])')")).unwrap(), znak),
             (Regex::new(&format!( "^{PRESLEDEK}(\"[^\n\"]*\")")).unwrap(), niz),
             (Regex::new(&format!(r"^{PRESLEDEK}(\d+\.\d+|\d{{1,3}}(_\d{{3}})+\.(\d{{3}}_)+\d{{1,3}}){ZADNJA_MEJA}")).unwrap(), real),
             (Regex::new(&format!(r"^{PRESLEDEK}(\d+|\d{{1,3}}(_\d{{3}})+){ZADNJA_MEJA}")).unwrap(), celo),
-            (Regex::new(&format!(r"^{PRESLEDEK}([.,;:#\n(){{}}\[\]]|->)")).unwrap(), Ločilo),
+            (Regex::new(&format!(r"^{PRESLEDEK}(\.\.|[.,;:#\n(){{}}\[\]]|->)")).unwrap(), Ločilo),
             (Regex::new(&format!(r"^{PRESLEDEK}(?x)(
                         # pretvorba
                             kot |
@@ -206,7 +295,9 @@ impl<'a> Lekser<'a> {
         let mut i: usize = 0;
 
         while i < self.tekst.len() {
-            match self.najdi_token(&regexi, &self.tekst[i..], vrstica, znak) {
+            // žetoni ne segajo čez konec vrstice, zato regexi ne pregledujejo preostanka besedila
+            let konec_vrstice = self.tekst[i..].find('\n').map_or(self.tekst.len(), |konec| i + konec + 1);
+            match self.najdi_token(&regexi, &self.tekst[i..konec_vrstice], vrstica, znak) {
                 Some((token, dolžina)) => {
                     match token {
                         Neznano("", ..) => (),
@@ -267,6 +358,8 @@ mod testi {
         assert_eq!("funkcija".razčleni("[test]"), [Rezerviranka("funkcija", 1, 1, "[test]")]);
         assert_eq!("vrni".razčleni("[test]"), [Rezerviranka("vrni", 1, 1, "[test]")]);
         assert_eq!("prekini".razčleni("[test]"), [Rezerviranka("prekini", 1, 1, "[test]")]);
+        assert_eq!("naštevanje".razčleni("[test]"), [Rezerviranka("naštevanje", 1, 1, "[test]")]);
+        assert_eq!("izberi".razčleni("[test]"), [Rezerviranka("izberi", 1, 1, "[test]")]);
     }
 
     #[test]
@@ -279,6 +372,7 @@ mod testi {
         assert_eq!(r"'\n'".razčleni("[test]"), [Literal(Znak(r"'\n'", 1, 1, "[test]"))]);
         assert_eq!(r"'\\'".razčleni("[test]"), [Literal(Znak(r"'\\'", 1, 1, "[test]"))]);
         assert_eq!(r"'\r'".razčleni("[test]"), [Literal(Znak(r"'\r'", 1, 1, "[test]"))]);
+        assert_eq!(r"'\0'".razčleni("[test]"), [Literal(Znak(r"'\0'", 1, 1, "[test]"))]);
         assert_eq!(r"'\f'".razčleni("[test]"), [Neznano(r"'\f'", 1, 1, "[test]")]);
 
         assert_eq!("\"\"".razčleni("[test]"), [Literal(Niz("\"\"", 1, 1, "[test]"))]);
@@ -309,6 +403,9 @@ mod testi {
     #[test]
     fn operatorji() {
         assert_eq!("a kot real".razčleni("[test]"), [Ime("a", 1, 1, "[test]"), Operator("kot", 1, 3, "[test]"), Tip("real", 1, 7, "[test]")]);
+        assert_eq!("a kot real64".razčleni("[test]"), [Ime("a", 1, 1, "[test]"), Operator("kot", 1, 3, "[test]"), Tip("real64", 1, 7, "[test]")]);
+        assert_eq!("a kot celo64".razčleni("[test]"), [Ime("a", 1, 1, "[test]"), Operator("kot", 1, 3, "[test]"), Tip("celo64", 1, 7, "[test]")]);
+        assert_eq!("a kot naravno".razčleni("[test]"), [Ime("a", 1, 1, "[test]"), Operator("kot", 1, 3, "[test]"), Tip("naravno", 1, 7, "[test]")]);
 
         assert_eq!("a<<=b".razčleni("[test]"), [Ime("a", 1, 1, "[test]"), Operator("<<=", 1, 2, "[test]"), Ime("b", 1, 5, "[test]")]);
         assert_eq!("a<< b".razčleni("[test]"), [Ime("a", 1, 1, "[test]"), Operator("<<",  1, 2, "[test]"), Ime("b", 1, 5, "[test]")]);
@@ -367,4 +464,20 @@ mod testi {
         );
     }
 
+    #[test]
+    fn interpolacija() {
+        let tokeni = "x \"a = {a + 1}, b = {b:05.2}{{}}\\n\"".razčleni("[test]");
+        assert_eq!(tokeni[1].interpolacija(), Some(Ok(vec![
+            Del::Besedilo("a = ".to_string()),
+            Del::Izraz(vec![Ime("a", 1, 9, "[test]"), Operator("+", 1, 11, "[test]"), Literal(Celo("1", 1, 13, "[test]"))], ""),
+            Del::Besedilo(", b = ".to_string()),
+            Del::Izraz(vec![Ime("b", 1, 22, "[test]")], "05.2"),
+            Del::Besedilo("{}\n".to_string()),
+        ])));
+        assert_eq!("\"brez\"".razčleni("[test]")[0].interpolacija(), None);
+        assert!(matches!("\"{a\"".razčleni("[test]")[0].interpolacija(), Some(Err(_))));
+        assert!(matches!("\"a}\"".razčleni("[test]")[0].interpolacija(), Some(Err(_))));
+        assert!(matches!("\"{}\"".razčleni("[test]")[0].interpolacija(), Some(Err(_))));
+    }
+
 }
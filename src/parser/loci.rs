@@ -14,6 +14,13 @@ pub fn loči_spredaj<'a, 'b>(izraz: &'b[Žeton<'a>], nizi: &'b[&'static str]) ->
     let mut oglatih:  isize = 0;
 
     for (i, tok) in izraz.iter().enumerate() {
+        // preveri pred zapiranjem, da se ne ujame zaklepaj gnezdenega bloka
+        if navadnih == 0 && zavitih == 0 && oglatih == 0
+            && nizi.iter().any(|s| *s == tok.as_str()) {
+                ////println!("{:?} najden", tok);
+                return Some(Ok((&izraz[..i], tok, &izraz[i+1..])));
+            }
+
         match tok.as_str() {
             ")" => navadnih -= 1,
             "}" => zavitih  -= 1,
@@ -21,12 +28,6 @@ pub fn loči_spredaj<'a, 'b>(izraz: &'b[Žeton<'a>], nizi: &'b[&'static str]) ->
             _   => ()
         }
 
-        if navadnih <= 0 && zavitih <= 0 && oglatih <= 0
-            && nizi.iter().any(|s| *s == tok.as_str()) {
-                ////println!("{:?} najden", tok);
-                return Some(Ok((&izraz[..i], tok, &izraz[i+1..])));
-            }
-
         if navadnih < 0 || zavitih < 0 || oglatih < 0 {
             return Some(Err(Napake::from_zaporedje(&[*tok], OznakaNapake::E1, "Neujemajoč oklepaj")))
         }
@@ -107,6 +108,7 @@ impl Escape for str {
             .replace(r"\n", "\n")
             .replace(r"\t", "\t")
             .replace(r"\r", "\r")
+            .replace(r"\0", "\0")
             .replace(r#"\"""#, "\"")
             .replace(r"\'", "\'")
     }
@@ -117,6 +119,7 @@ impl Escape for str {
             .replace("\n", r"\n")
             .replace("\t", r"\t")
             .replace("\r", r"\r")
+            .replace("\0", r"\0")
             .replace("\"", r#"\""#)
             .replace("\'", r"\'")
     }
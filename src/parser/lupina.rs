@@ -0,0 +1,123 @@
+use super::*;
+use crate::parser::lekser::Razčleni;
+
+// ime vnosov, ki ne ostanejo v lupini (npr. :tip)
+const VNOS: &str = "[vnos]";
+
+// razčlenjevalnik interaktivne lupine: spremenljivke, konstante in funkcije ostanejo med vnosi
+pub struct Lupina {
+    // razčlenjevalnik si sposodi imena iz vnosov, zato se sprosti pred njimi
+    parser: Parser<'static>,
+    // oštevilčeno ime vnosa ("[vnos 3]") in vnos
+    vnosi: Vec<(Box<str>, Box<str>)>,
+    št_vnosov: usize,
+}
+
+pub enum Vnos {
+    // vrednost izraza se izpiše z ustrezno funkcijo natisni
+    Izraz(Drevo),
+    Zaporedje(Drevo),
+}
+
+impl Lupina {
+    // vrne lupino in drevo standardne knjižnice, ki se mora izvesti pred prvim vnosom
+    pub fn new() -> Result<(Lupina, Drevo), Napake> {
+        let mut parser = Parser::new();
+        parser.v_okvir();
+
+        let main = parser.zaporedje(Parser::standard())?;
+        let lupina = Lupina { parser, vnosi: Vec::new(), št_vnosov: 0 };
+        let drevo = lupina.drevo(main);
+        Ok((lupina, drevo))
+    }
+
+    // vnos z več vrsticami se nadaljuje, dokler niso zaprti vsi "{"
+    pub fn nedokončano(vnos: &str) -> bool {
+        let odprti = vnos
+            .razčleni(VNOS)
+            .iter()
+            .fold(0, |odprti, žeton| match žeton {
+                Ločilo("{", ..) => odprti + 1,
+                Ločilo("}", ..) => odprti - 1,
+                _ => odprti,
+            });
+        odprti > 0
+    }
+
+    pub fn analiziraj(&mut self, vnos: String) -> Result<Vnos, Napake> {
+        // žetoni si sposodijo vnos in njegovo ime, ki morata živeti toliko časa kot razčlenjevalnik
+        let ime = format!("[vnos {}]", self.št_vnosov + 1);
+        self.vnosi.push((ime.into_boxed_str(), vnos.into_boxed_str()));
+        // SAFETY: vsebina Box<str> se ob premiku v vektorju ne premakne, vnos pa se odstrani
+        // samo, če ga razčlenjevalnik ne uporablja, oziroma sprosti po razčlenjevalniku
+        let (ime, vnos): (&'static str, &'static str) = {
+            let (ime, vnos) = self.vnosi.last().unwrap();
+            unsafe { (&*(ime.as_ref() as *const str), &*(vnos.as_ref() as *const str)) }
+        };
+        let izraz = Self::žetoni(vnos, ime);
+        if izraz.is_empty() {
+            self.vnosi.pop();
+            return Ok(Vnos::Zaporedje(self.drevo(Prazno.rc())));
+        }
+        // številka ostane porabljena tudi za neuspešen vnos, da se mesta napak ne ponovijo
+        self.št_vnosov += 1;
+
+        let rezerva = self.parser.clone();
+        let rezultat = match self.parser.clone().drevo(&izraz) {
+            Ok(drevo) if drevo.tip() != Tip::Brez => {
+                let (vrstica, znak) = izraz[0].lokacija();
+                self.parser.natisni(&Ime("natisni", vrstica, znak, ime), &izraz)
+                    .map(|natisni| Vnos::Izraz(self.drevo(natisni)))
+            },
+            Ok(_) => self.parser.zaporedje(&izraz)
+                .map(|zaporedje| Vnos::Zaporedje(self.drevo(zaporedje))),
+            Err(napake_izraza) => match self.parser.zaporedje(&izraz) {
+                Ok(zaporedje) => Ok(Vnos::Zaporedje(self.drevo(zaporedje))),
+                // vnos ni stavek, zato je napaka v izrazu bolj povedna
+                Err(napake) if napake == Napake::from_zaporedje(&izraz, E1, "Neznan izraz") => Err(napake_izraza),
+                Err(napake) => Err(napake),
+            },
+        };
+
+        // neuspešen vnos ne sme pustiti sledi (npr. napol deklariranih spremenljivk)
+        if rezultat.is_err() {
+            self.parser = rezerva;
+            self.vnosi.pop();
+        }
+        rezultat
+    }
+
+    pub fn tip(&self, vnos: &str) -> Result<Tip, Napake> {
+        let izraz = Self::žetoni(vnos, VNOS);
+        Ok(self.parser.clone().drevo(&izraz)?.tip())
+    }
+
+    pub fn drevo_izraza(&self, vnos: &str) -> Result<String, Napake> {
+        let izraz = Self::žetoni(vnos, VNOS);
+        Ok(self.parser.clone().drevo(&izraz)?.drevo(0))
+    }
+
+    fn žetoni<'b>(vnos: &'b str, ime: &'b str) -> Vec<Žeton<'b>> {
+        let mut žetoni = Parser::predprocesiraj([vnos.razčleni(ime).as_slice(), &[Ločilo("\n", 0, 0, ime)]].concat());
+        while let Some(Ločilo("\n", ..)) = žetoni.last() {
+            žetoni.pop();
+        }
+        žetoni
+    }
+
+    fn drevo(&self, main: Rc<Vozlišče>) -> Drevo {
+        // globalne spremenljivke ostanejo na stacku, zato jih program ne sme sprostiti
+        let globalne = self.parser.spremenljivke
+            .values()
+            .map(|s| s.sprememba_stacka())
+            .sum();
+
+        Drevo {
+            main,
+            funkcije: self.parser.funkcije_vec.clone(),
+            št_klicev: self.parser.št_klicev.clone(),
+            prostor: i32::max(globalne, Parser::prostor()),
+            opozorila: Napake::new(),
+        }
+    }
+}
@@ -4,6 +4,10 @@ pub mod tip;
 pub mod napaka;
 
 pub mod loci;
+pub mod lupina;
+pub mod oblikuj;
+pub mod simboli;
+mod opozorila;
 mod operatorji;
 mod predprocesiraj;
 mod okvir;
@@ -11,19 +15,38 @@ mod stavek;
 mod funkcija;
 mod izraz;
 mod argumenti;
+mod nastevanje;
+mod preizkus;
+mod panika;
 
-use std::{collections::HashMap, rc::Rc, iter, io};
+use std::{collections::HashMap, rc::Rc, iter, sync::OnceLock};
 
-use drevo::{Drevo, Vozlišče::{*, self}, VozliščeOption::*};
+use drevo::{Drevo, Matematika, Vozlišče::{*, self}, VozliščeOption::*};
 use tip::Tip;
 use lekser::{Žeton::{*, self}, L};
 use loci::*;
+use simboli::Analiza;
 
 use crate::parser::lekser::Lekser;
 
 use self::napaka::{Napake, OznakaNapake::*, Napaka};
 use self::operatorji::*;
 
+// standardna knjižnica v vrstnem redu vključevanja: ime vira in izvorna koda
+const JEDRO: [(&str, &str); 10] = [
+    ("[jedro]/matematika.slj", include_str!("../../jedro/matematika.slj")),
+    ("[jedro]/niz.slj",        include_str!("../../jedro/niz.slj")),
+    ("[jedro]/natisni.slj",    include_str!("../../jedro/natisni.slj")),
+    // za natisni, pred moduli, ki kličejo panika
+    ("[jedro]/panika.slj",     include_str!("../../jedro/panika.slj")),
+    ("[jedro]/preberi.slj",    include_str!("../../jedro/preberi.slj")),
+    ("[jedro]/nakljucno.slj",  include_str!("../../jedro/nakljucno.slj")),
+    ("[jedro]/seznami.slj",    include_str!("../../jedro/seznami.slj")),
+    ("[jedro]/okolje.slj",     include_str!("../../jedro/okolje.slj")),
+    ("[jedro]/datoteke.slj",   include_str!("../../jedro/datoteke.slj")),
+    ("[jedro]/cas.slj",        include_str!("../../jedro/cas.slj")),
+];
+
 #[derive(Debug, Clone)]
 struct Parser<'a> {
     spremenljivke_stack: Vec<HashMap<&'a str, Rc<Vozlišče>>>,
@@ -34,15 +57,73 @@ struct Parser<'a> {
     funkcije_vec: Vec<Rc<Vozlišče>>,
     št_klicev: HashMap<String, usize>,
     znotraj_funkcije: bool,
+    preizkus: Option<preizkus::Preizkusi>,
+    preizkusi: Vec<String>,
+    brez_trditev: bool,
+    simboli: Option<simboli::Simboli>,
+    opozorila: Option<opozorila::Opozorila>,
 }
 
 pub trait Parse {
     fn analiziraj(self) -> Result<Drevo, Napake>;
+    // trditve (trdi) se ne prevedejo
+    fn analiziraj_brez_trditev(self) -> Result<Drevo, Napake>;
+    // vsak preizkus v svojem drevesu, skupaj z imenom
+    fn analiziraj_preizkuse(self) -> Result<Vec<(String, Drevo)>, Napake>;
+    // napake in imena v programu za urejevalnike (slj-lsp)
+    fn analiziraj_simbole(self) -> Analiza;
+    // opozorila (neuporabljene spremenljivke, nedosegljiva koda ...), prazno, če ima program napake
+    fn opozorila(self) -> Napake;
 }
 
 impl Parse for Vec<Žeton<'_>> {
     fn analiziraj(self) -> Result<Drevo, Napake> {
-        Parser::new().parse(self)
+        let mut parser = Parser::new();
+        parser.opozorila = Some(opozorila::Opozorila::default());
+        parser.parse(self)
+    }
+
+    fn analiziraj_brez_trditev(self) -> Result<Drevo, Napake> {
+        let mut parser = Parser::new();
+        parser.brez_trditev = true;
+        parser.opozorila = Some(opozorila::Opozorila::default());
+        parser.parse(self)
+    }
+
+    fn analiziraj_preizkuse(self) -> Result<Vec<(String, Drevo)>, Napake> {
+        let mut parser = Parser::new();
+        parser.preizkus = Some(preizkus::Preizkusi::default());
+        let drevo = parser.parse(self)?;
+
+        Ok(parser.preizkusi.into_iter()
+            .zip(parser.preizkus.unwrap().vstopi)
+            .map(|(ime, main)| (ime, Drevo {
+                main,
+                funkcije: drevo.funkcije.clone(),
+                št_klicev: drevo.št_klicev.clone(),
+                prostor: drevo.prostor,
+                opozorila: Napake::new(),
+            }))
+            .collect())
+    }
+
+    fn analiziraj_simbole(self) -> Analiza {
+        let mut parser = Parser::new();
+        parser.simboli = Some(simboli::Simboli::default());
+        parser.opozorila = Some(opozorila::Opozorila::default());
+
+        let napake = match parser.parse(self) {
+            Ok(_) => parser.opozorila(),
+            Err(napake) => napake,
+        };
+        parser.analiza(napake)
+    }
+
+    fn opozorila(self) -> Napake {
+        match self.analiziraj() {
+            Ok(drevo) => drevo.opozorila,
+            Err(_) => Napake::new(),
+        }
     }
 }
 
@@ -58,12 +139,18 @@ impl<'a> Parser<'a> {
             funkcije_vec: Vec::new(),
             št_klicev: HashMap::new(),
             znotraj_funkcije: false,
+            preizkus: None,
+            preizkusi: Vec::new(),
+            brez_trditev: false,
+            simboli: None,
+            opozorila: None,
         }
     }
 
     fn parse(&mut self, izraz: Vec<Žeton<'a>>) -> Result<Drevo, Napake> {
+        self.preberi_dovoljenja(&izraz);
         let izraz = [
-            Self::standard().unwrap().as_slice(),
+            Self::standard(),
             &[Ločilo("\n", 0, 0, "[builtin]")],
             &Parser::predprocesiraj(izraz),
         ].concat();
@@ -73,26 +160,24 @@ impl<'a> Parser<'a> {
             funkcije: self.funkcije_vec.clone(),
             št_klicev: self.št_klicev.clone(),
             prostor: Self::prostor(),
+            opozorila: self.opozorila(),
         })
     }
 
-    fn standard() -> Result<Vec<Žeton<'static>>, io::Error> {
-        const MATEMATIKA: &str = include_str!("../../jedro/matematika.slj");
-        const NATISNI: &str = include_str!("../../jedro/natisni.slj");
-        const PREBERI: &str = include_str!("../../jedro/preberi.slj");
-
-        const LEKSER_MAT: Lekser     = Lekser::new("../../jedro/matematika.slj", MATEMATIKA);
-        const LEKSER_NATISNI: Lekser = Lekser::new("../../jedro/natisni.slj", NATISNI);
-        const LEKSER_PREBERI: Lekser = Lekser::new("../../jedro/preberi.slj", PREBERI);
-
-        Ok([
-            Parser::predprocesiraj(LEKSER_MAT.razčleni()).as_slice(),
-            &[Ločilo("\n", 0, 0, "[vgrajeno]")],
-            Parser::predprocesiraj(LEKSER_NATISNI.razčleni()).as_slice(),
-            &[Ločilo("\n", 0, 0, "[vgrajeno]")],
-            &Parser::predprocesiraj(LEKSER_PREBERI.razčleni()),
-            &[Ločilo("\n", 0, 0, "[vgrajeno]")],
-        ].concat())
+    // standardna knjižnica se razčleni enkrat na proces
+    fn standard() -> &'static [Žeton<'static>] {
+        static STANDARD: OnceLock<Vec<Žeton<'static>>> = OnceLock::new();
+
+        STANDARD.get_or_init(|| JEDRO.iter()
+            .flat_map(|(ime, koda)| Parser::predprocesiraj(Lekser::new(ime, koda).razčleni())
+                .into_iter()
+                .chain([Ločilo("\n", 0, 0, "[vgrajeno]")]))
+            .collect())
+    }
+
+    // vir žetona je standardna knjižnica
+    fn je_jedro(datoteka: &str) -> bool {
+        JEDRO.iter().any(|(ime, _)| *ime == datoteka)
     }
 
     fn dodaj_spremenljivko(&mut self, ime: &'a str, tip: Tip, spremenljiva: bool) -> Rc<Vozlišče> {
@@ -114,13 +199,16 @@ impl<'a> Parser<'a> {
         vrednost
     }
 
-    fn poišči_spr(&self, ime: &Žeton) -> Result<Rc<Vozlišče>, Napake> {
-        match self.konstante.get(ime.as_str()) {
+    fn poišči_spr(&mut self, ime: &Žeton) -> Result<Rc<Vozlišče>, Napake> {
+        let spr = match self.konstante.get(ime.as_str()) {
             Some(spr) => Ok(spr.clone()),
             None => match self.spremenljivke.get(ime.as_str()) {
                 Some(spr) => Ok(spr.clone()),
                 None => Err(Napake::from_zaporedje(&[*ime], E2, "Neznana spremenljivka")),
             }
-        }
+        }?;
+        self.zabeleži_sklic(ime, &spr);
+        self.zabeleži_branje(&spr);
+        Ok(spr)
     }
 }
@@ -1,4 +1,6 @@
-use std::{fmt::Debug, collections::HashMap, fs::read_to_string, sync::{Mutex, OnceLock}};
+use std::{fmt::Debug, collections::HashMap, fs::read_to_string, io::{self, Write}, sync::{Mutex, OnceLock}};
+
+use serde_json::{json, Value};
 
 use super::lekser::Žeton;
 
@@ -28,6 +30,25 @@ pub struct Napaka {
     pub konec: (usize, usize),
 }
 
+// oblika izpisa napak (--oblika-napak=...)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OblikaNapak {
+    Besedilo,
+    Json,
+    Sarif,
+}
+
+impl OblikaNapak {
+    pub fn iz_niza(oblika: &str) -> Option<OblikaNapak> {
+        match oblika {
+            "besedilo" => Some(OblikaNapak::Besedilo),
+            "json" => Some(OblikaNapak::Json),
+            "sarif" => Some(OblikaNapak::Sarif),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum OznakaNapake {
     E1,
@@ -39,6 +60,18 @@ pub enum OznakaNapake {
     E7,
     E8,
     E9,
+    // opozorila ne ustavijo prevajanja
+    W1,
+    W2,
+    W3,
+    W4,
+    W5,
+}
+
+impl OznakaNapake {
+    pub fn je_opozorilo(&self) -> bool {
+        matches!(self, OznakaNapake::W1 | OznakaNapake::W2 | OznakaNapake::W3 | OznakaNapake::W4 | OznakaNapake::W5)
+    }
 }
 
 impl Napaka {
@@ -54,18 +87,19 @@ impl Napaka {
         Napaka { oznaka, datoteka, sporočilo, začetek, konec }
     }
 
+    // prazno, če izvorne kode ni na disku (npr. [test] ali [vgrajeno])
     fn vrstice(&self) -> &[String] {
         let datoteke = unsafe { datoteke().get_mut().unwrap() };
 
         if !datoteke.contains_key(&self.datoteka) {
-            let mut datoteka = 
-                read_to_string(&self.datoteka)
-                .unwrap()
-                .lines()
-                .map(String::from)
-                .collect::<Vec<String>>();
-
-            datoteka.push("\n".to_string());
+            let datoteka = match read_to_string(&self.datoteka) {
+                Ok(datoteka) => datoteka
+                    .lines()
+                    .map(String::from)
+                    .chain(["\n".to_string()])
+                    .collect::<Vec<String>>(),
+                Err(_) => Vec::new(),
+            };
             datoteke.insert(self.datoteka.clone(), datoteka);
         }
 
@@ -94,47 +128,136 @@ impl Napake {
     pub fn razširi(&mut self, other: Self) {
         self.napake.extend(other.napake)
     }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Napaka> {
+        self.napake.iter()
+    }
     
+    // samo sporočila in mesta napak, brez izvorne kode (npr. za interaktivno lupino)
+    pub fn izpiši_kratko(&self, izhod: &mut impl Write) -> io::Result<()> {
+        for Napaka { oznaka, sporočilo, datoteka, začetek: (vrstica, znak), .. } in &self.napake {
+            writeln!(izhod, "Napaka {oznaka:?}: {sporočilo} | {datoteka}:{vrstica}:{znak}")?;
+        }
+        Ok(())
+    }
+
+    // napake in opozorila se izpišejo na standardni izhod za napake
     pub fn izpiši(&self) {
         for napaka in &self.napake {
-            let Napaka {
-                oznaka,
-                sporočilo,
-                datoteka,
-                začetek: (prva_vrstica, prvi_znak),
-                konec: (zadnja_vrstica, zadnji_znak)
-            } = napaka;
-
-            let vrstice = &napaka.vrstice()[..*zadnja_vrstica + 1];
-
-            println!("Napaka {oznaka:?}: {sporočilo} | {datoteka}:{prva_vrstica}:{prvi_znak}");
-
-            let zamik = log10(zadnja_vrstica+2);
-
-            if *prva_vrstica > 1 {
-                let št_vrstice = prva_vrstica - 1;
-                let vrstica = &vrstice[prva_vrstica-2];
-                println!("{št_vrstice:zamik$} | {vrstica}");
-            }
-
-            for i in prva_vrstica-1..*zadnja_vrstica {
-                let št_vrstice = i+1;
-                let vrstica = &vrstice[i];
-                println!("{št_vrstice:zamik$} | {vrstica}");
-            }
-
-            let razlika = usize::min(*prvi_znak, *zadnji_znak) - 1;
-            let podčrtaj = "^".repeat(usize::abs_diff(*prvi_znak, *zadnji_znak));
-            const PRAZNO: &str = "";
-            println!("{PRAZNO:zamik$} | {PRAZNO:razlika$}{podčrtaj}");
-
-            let št_vrstice = zadnja_vrstica + 1;
-            let vrstica = vrstice.last().unwrap();
-            println!("{št_vrstice:zamik$} | {vrstica}\n");
+            let _ = napaka.izpiši(&mut io::stderr());
+        }
+
+        let št_napak = self.napake.iter().filter(|n| !n.oznaka.je_opozorilo()).count().to_string();
+        let št_opozoril = self.napake.iter().filter(|n| n.oznaka.je_opozorilo()).count().to_string();
+        if št_napak != "0" {
+            eprintln!("{} {}, ne morem nadaljevati", št_napak, spregaj_napake(&št_napak));
+        }
+        else if št_opozoril != "0" {
+            eprintln!("{} {}", št_opozoril, spregaj_opozorila(&št_opozoril));
+        }
+    }
+
+    // strojno berljive oblike se izpišejo na standardni izhod za napake
+    pub fn izpiši_v_obliki(&self, oblika: OblikaNapak) {
+        match oblika {
+            OblikaNapak::Besedilo => self.izpiši(),
+            OblikaNapak::Json => eprintln!("{}", self.json()),
+            OblikaNapak::Sarif => eprintln!("{}", self.sarif()),
         }
+    }
+
+    // vrstice in znaki se štejejo od 1, konec je za zadnjim znakom
+    pub fn json(&self) -> Value {
+        json!(self.napake.iter()
+            .map(|napaka| json!({
+                "code": format!("{:?}", napaka.oznaka),
+                "severity": if napaka.oznaka.je_opozorilo() { "warning" } else { "error" },
+                "message": napaka.sporočilo,
+                "file": napaka.datoteka,
+                "start": { "line": napaka.začetek.0, "column": napaka.začetek.1 },
+                "end": { "line": napaka.konec.0, "column": napaka.konec.1 },
+            }))
+            .collect::<Vec<Value>>())
+    }
 
-        let št_napak = &self.napake.len().to_string();
-        println!("{} {}, ne morem nadaljevati", št_napak, spregaj_napake(št_napak));
+    // SARIF 2.1.0, npr. za prikaz v GitHub code scanning
+    pub fn sarif(&self) -> Value {
+        let rezultati = self.napake.iter()
+            .map(|napaka| json!({
+                "ruleId": format!("{:?}", napaka.oznaka),
+                "level": if napaka.oznaka.je_opozorilo() { "warning" } else { "error" },
+                "message": { "text": napaka.sporočilo },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": { "uri": napaka.datoteka },
+                        "region": {
+                            "startLine": napaka.začetek.0,
+                            "startColumn": napaka.začetek.1,
+                            "endLine": napaka.konec.0,
+                            "endColumn": napaka.konec.1,
+                        },
+                    },
+                }],
+            }))
+            .collect::<Vec<Value>>();
+
+        json!({
+            "version": "2.1.0",
+            "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+            "runs": [{
+                "tool": { "driver": { "name": "slj", "version": env!("CARGO_PKG_VERSION") } },
+                "results": rezultati,
+            }],
+        })
+    }
+}
+
+impl Napaka {
+    fn izpiši(&self, izhod: &mut impl Write) -> io::Result<()> {
+        let Napaka {
+            oznaka,
+            sporočilo,
+            datoteka,
+            začetek: (prva_vrstica, prvi_znak),
+            konec: (zadnja_vrstica, zadnji_znak)
+        } = self;
+
+        // opozorila so podčrtana z "~"
+        let (vrsta, črta) = match oznaka.je_opozorilo() {
+            true => ("Opozorilo", "~"),
+            false => ("Napaka", "^"),
+        };
+        writeln!(izhod, "{vrsta} {oznaka:?}: {sporočilo} | {datoteka}:{prva_vrstica}:{prvi_znak}")?;
+
+        // brez izvorne kode izpišemo samo mesto
+        let vrstice = self.vrstice();
+        if *prva_vrstica == 0 || vrstice.len() <= *zadnja_vrstica {
+            return writeln!(izhod);
+        }
+        let vrstice = &vrstice[..*zadnja_vrstica + 1];
+
+        let zamik = log10(zadnja_vrstica+2);
+
+        if *prva_vrstica > 1 {
+            let št_vrstice = prva_vrstica - 1;
+            let vrstica = &vrstice[prva_vrstica-2];
+            writeln!(izhod, "{št_vrstice:zamik$} | {vrstica}")?;
+        }
+
+        for i in prva_vrstica-1..*zadnja_vrstica {
+            let št_vrstice = i+1;
+            let vrstica = &vrstice[i];
+            writeln!(izhod, "{št_vrstice:zamik$} | {vrstica}")?;
+        }
+
+        let razlika = usize::min(*prvi_znak, *zadnji_znak) - 1;
+        let podčrtaj = črta.repeat(usize::abs_diff(*prvi_znak, *zadnji_znak));
+        const PRAZNO: &str = "";
+        writeln!(izhod, "{PRAZNO:zamik$} | {PRAZNO:razlika$}{podčrtaj}")?;
+
+        let št_vrstice = zadnja_vrstica + 1;
+        let vrstica = vrstice.last().unwrap();
+        writeln!(izhod, "{št_vrstice:zamik$} | {vrstica}\n")
     }
 }
 
@@ -155,9 +278,23 @@ fn spregaj_napake(št_napak: &str) -> &'static str {
     }
 }
 
+fn spregaj_opozorila(št_opozoril: &str) -> &'static str {
+    let mut rev = št_opozoril.bytes().rev();
+    let zadnji_znak = rev.next().unwrap();
+    let predzanji_znak = rev.next();
+
+    match (predzanji_znak, zadnji_znak) {
+        (None | Some(b'0'), b'1')        => "opozorilo",
+        (None | Some(b'0'), b'2')        => "opozorili",
+        (None | Some(b'0'), b'3' | b'4') => "opozorila",
+        _                                => "opozoril",
+    }
+}
+
 #[cfg(test)]
 mod testi {
     use super::*;
+    use crate::parser::lekser::Razčleni;
 
     #[test]
     fn test_napake_slovnica() {
@@ -185,4 +322,73 @@ mod testi {
         assert_eq!(spregaj_napake("1203"), "napake");
         assert_eq!(spregaj_napake("1205"), "napak");
     }
+
+    #[test]
+    fn test_opozorila_slovnica() {
+        assert_eq!(spregaj_opozorila("1"), "opozorilo");
+        assert_eq!(spregaj_opozorila("2"), "opozorili");
+        assert_eq!(spregaj_opozorila("3"), "opozorila");
+        assert_eq!(spregaj_opozorila("4"), "opozorila");
+        assert_eq!(spregaj_opozorila("5"), "opozoril");
+        assert_eq!(spregaj_opozorila("11"), "opozoril");
+        assert_eq!(spregaj_opozorila("102"), "opozorili");
+    }
+
+    fn napake() -> Napake {
+        let žetoni = "naj x = 1\nnatisni(y)".razčleni("[test]");
+        let mut napake = Napake::from_zaporedje(&žetoni[7..8], OznakaNapake::E2, "Neznana spremenljivka");
+        napake.add_napaka(Napaka::from_zaporedje(&žetoni[1..2], OznakaNapake::W1, "Neuporabljena spremenljivka 'x'"));
+        napake
+    }
+
+    #[test]
+    fn brez_izvorne_kode() {
+        let mut izhod = Vec::new();
+        for napaka in napake().iter() {
+            napaka.izpiši(&mut izhod).unwrap();
+        }
+        assert_eq!(
+            String::from_utf8(izhod).unwrap(),
+            "Napaka E2: Neznana spremenljivka | [test]:2:9\n\nOpozorilo W1: Neuporabljena spremenljivka 'x' | [test]:1:5\n\n"
+        );
+    }
+
+    #[test]
+    fn json() {
+        assert_eq!(napake().json(), json!([
+            {
+                "code": "E2",
+                "severity": "error",
+                "message": "Neznana spremenljivka",
+                "file": "[test]",
+                "start": { "line": 2, "column": 9 },
+                "end": { "line": 2, "column": 10 },
+            },
+            {
+                "code": "W1",
+                "severity": "warning",
+                "message": "Neuporabljena spremenljivka 'x'",
+                "file": "[test]",
+                "start": { "line": 1, "column": 5 },
+                "end": { "line": 1, "column": 6 },
+            },
+        ]));
+    }
+
+    #[test]
+    fn sarif() {
+        let sarif = napake().sarif();
+        assert_eq!(sarif["version"], "2.1.0");
+        assert_eq!(sarif["runs"][0]["tool"]["driver"]["name"], "slj");
+
+        let rezultati = sarif["runs"][0]["results"].as_array().unwrap();
+        assert_eq!(rezultati.len(), 2);
+        assert_eq!(rezultati[0]["ruleId"], "E2");
+        assert_eq!(rezultati[0]["level"], "error");
+        assert_eq!(rezultati[1]["level"], "warning");
+        assert_eq!(rezultati[1]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"], "[test]");
+        assert_eq!(rezultati[1]["locations"][0]["physicalLocation"]["region"], json!({
+            "startLine": 1, "startColumn": 5, "endLine": 1, "endColumn": 6,
+        }));
+    }
 }
@@ -0,0 +1,84 @@
+use super::*;
+
+impl<'a> Parser<'a> {
+    pub fn naštevanje(&mut self, ime: &Žeton<'a>, variante_izraz: &[Žeton<'a>]) -> Result<Rc<Vozlišče>, Napake> {
+        if self.znotraj_funkcije {
+            return Err(Napake::from_zaporedje(&[*ime], E5, "Naštevanje je mogoče deklarirati samo izven funkcije"));
+        }
+        if Tip::naštevanje(ime.as_str(), &self.konstante).is_some() {
+            return Err(Napake::from_zaporedje(&[*ime], E2, "Naštevanje s tem imenom že obstaja"));
+        }
+
+        let mut variante: Vec<String> = Vec::new();
+        let mut napake = Napake::new();
+
+        for varianta in razdeli(variante_izraz, &[",", "\n"])? {
+            match varianta {
+                [] => (),
+                [ varianta @ Ime(..) ] => {
+                    if variante.contains(&varianta.to_string()) {
+                        napake.add_napaka(Napaka::from_zaporedje(&[*varianta], E2, "Varianta s tem imenom že obstaja"));
+                    }
+                    else {
+                        variante.push(varianta.to_string());
+                    }
+                },
+                _ => _ = napake.add_napaka(Napaka::from_zaporedje(varianta, E1, "Neveljavno ime variante")),
+            }
+        }
+
+        if !napake.prazno() {
+            return Err(napake);
+        }
+        if variante.is_empty() {
+            return Err(Napake::from_zaporedje(&[*ime], E5, "Naštevanje mora imeti vsaj eno varianto"));
+        }
+
+        let tip = Tip::Naštevanje(ime.to_string(), variante.clone());
+        for (vrednost, varianta) in variante.iter().enumerate() {
+            self.dodaj_konstanto(format!("{}.{varianta}", ime.as_str()), Varianta(tip.clone(), vrednost as i32).rc());
+        }
+
+        self.natisni_naštevanje(&tip);
+        Ok(Prazno.rc())
+    }
+
+    // funkcija natisni(<naštevanje>), ki izpiše ime variante
+    fn natisni_naštevanje(&mut self, tip: &Tip) {
+        let variante = match tip {
+            Tip::Naštevanje(_, variante) => variante,
+            _ => unreachable!("Izpisujemo samo naštevanja"),
+        };
+
+        let vrednost = Spremenljivka {
+            tip: tip.clone(),
+            ime: "vrednost".to_string(),
+            naslov: 0,
+            z_odmikom: true,
+            spremenljiva: false,
+        }.rc();
+
+        let telo = variante.iter()
+            .enumerate()
+            .rev()
+            .fold(Prazno.rc(), |laž, (i, ime)| PogojniStavek {
+                pogoj: Enako(Tip::Celo,
+                    NaštevanjeVCelo(vrednost.clone()).rc(),
+                    NaštevanjeVCelo(Varianta(tip.clone(), i as i32).rc()).rc()).rc(),
+                resnica: Zaporedje(ime.chars().map(|znak| Natisni(Znak(znak).rc()).rc()).collect()).rc(),
+                laž,
+            }.rc());
+
+        let podpis_funkcije = format!("natisni({tip})");
+        let funkcija = Funkcija {
+            tip: Tip::Brez,
+            ime: podpis_funkcije.clone(),
+            parametri: vec![vrednost],
+            telo: Zaporedje(vec![telo]).rc(),
+            prostor: 0,
+        }.rc();
+
+        self.funkcije.insert(podpis_funkcije, funkcija.clone());
+        self.funkcije_vec.push(funkcija);
+    }
+}
@@ -0,0 +1,247 @@
+use super::lekser::{Razčleni, Žeton::{self, *}, L};
+
+const ZAMIK: &str = "    ";
+
+pub trait Oblikuj {
+    // enotno oblikovana izvorna koda s komentarji; ponovno oblikovanje je ne spremeni
+    fn oblikuj(&self) -> String;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Vloga {
+    // enomestni operator pred operandom (-x, !x, @x)
+    Predpona,
+    // dereferenca za operandom (x@)
+    Pripona,
+    // klicaj multifunkcijskega klica (natisni!(...))
+    Klic,
+    Drugo,
+}
+
+struct Vrstica<'a> {
+    koda: Vec<Žeton<'a>>,
+    komentar: Option<String>,
+}
+
+impl Vrstica<'_> {
+    fn prazna(&self) -> bool {
+        self.koda.is_empty() && self.komentar.is_none()
+    }
+}
+
+impl Oblikuj for str {
+    fn oblikuj(&self) -> String {
+        let mut izhod = String::new();
+        let mut globina: usize = 0;
+        let mut prazna = false;
+        let mut odprto = false;
+
+        for vrstica in vrstice(self) {
+            if vrstica.prazna() {
+                prazna = true;
+                continue;
+            }
+
+            let zaprti = vrstica.koda.iter()
+                .take_while(|žeton| matches!(žeton, Ločilo("}" | ")" | "]", ..)))
+                .count();
+
+            // največ ena prazna vrstica, nikoli na začetku ali tik za "{" in pred "}"
+            if prazna && !izhod.is_empty() && !odprto && zaprti == 0 {
+                izhod.push('\n');
+            }
+            prazna = false;
+
+            izhod += &ZAMIK.repeat(globina.saturating_sub(zaprti));
+            izhod += &poravnaj(&vrstica.koda);
+            if let Some(komentar) = &vrstica.komentar {
+                if !vrstica.koda.is_empty() {
+                    izhod.push(' ');
+                }
+                izhod += komentar;
+            }
+            izhod.push('\n');
+
+            for žeton in &vrstica.koda {
+                match žeton {
+                    Ločilo("{" | "(" | "[", ..) => globina += 1,
+                    Ločilo("}" | ")" | "]", ..) => globina = globina.saturating_sub(1),
+                    _ => (),
+                }
+            }
+            odprto = matches!(vrstica.koda.last(), Some(Ločilo("{" | "(" | "[", ..)));
+        }
+
+        izhod
+    }
+}
+
+// razdeli kodo na vrstice žetonov in komentarjev,
+// "{" in "čene" na začetku vrstice prestavi na konec prejšnje
+fn vrstice(koda: &str) -> Vec<Vrstica<'_>> {
+    let besedilo = koda.lines().collect::<Vec<&str>>();
+    let mut vrstice = vec![Vrstica { koda: Vec::new(), komentar: None }];
+
+    for žeton in koda.razčleni("[oblikuj]") {
+        let vrstica = vrstice.last_mut().unwrap();
+        match žeton {
+            Ločilo("\n", ..) => vrstice.push(Vrstica { koda: Vec::new(), komentar: None }),
+            _ if vrstica.komentar.is_some() => (),
+            // komentar vzamemo iz besedila, saj lekser razčleni tudi njegovo vsebino
+            Ločilo("#", v, z, _) => vrstica.komentar = Some(besedilo[v - 1]
+                .chars()
+                .skip(z - 1)
+                .collect::<String>()
+                .trim_end()
+                .to_string()),
+            _ => vrstica.koda.push(žeton),
+        }
+    }
+
+    let mut združene: Vec<Vrstica> = Vec::new();
+    for vrstica in vrstice {
+        if let Some(Ločilo("{", ..) | Rezerviranka("čene", ..)) = vrstica.koda.first() {
+            let prejšnja = združene.iter().rposition(|v| !v.prazna());
+            if let Some(i) = prejšnja.filter(|i| !združene[*i].koda.is_empty() && združene[*i].komentar.is_none()) {
+                združene.truncate(i + 1);
+                združene[i].koda.extend(vrstica.koda);
+                združene[i].komentar = vrstica.komentar;
+                continue;
+            }
+        }
+        združene.push(vrstica);
+    }
+    združene
+}
+
+fn vloge(koda: &[Žeton]) -> Vec<Vloga> {
+    let mut vloge: Vec<Vloga> = Vec::with_capacity(koda.len());
+
+    for (i, žeton) in koda.iter().enumerate() {
+        let pričakuje_operand = match i.checked_sub(1).map(|j| (koda[j], vloge[j])) {
+            None => true,
+            Some((_, Vloga::Predpona | Vloga::Klic)) => true,
+            Some((_, Vloga::Pripona)) => false,
+            Some((Ločilo(")" | "]" | "}", ..), _)) => false,
+            Some((Operator(..) | Ločilo(..) | Rezerviranka(..), _)) => true,
+            Some(_) => false,
+        };
+
+        vloge.push(match žeton {
+            Operator("!", ..) if i > 0 && matches!(koda[i - 1], Ime(..)) => Vloga::Klic,
+            Operator("!" | "-" | "+" | "@", ..) if pričakuje_operand => Vloga::Predpona,
+            Operator("@", ..) => Vloga::Pripona,
+            _ => Vloga::Drugo,
+        });
+    }
+    vloge
+}
+
+// žetoni vrstice, ločeni z enim presledkom, kjer je ta potreben
+fn poravnaj(koda: &[Žeton]) -> String {
+    let vloge = vloge(koda);
+    let mut vrstica = String::new();
+
+    for (i, žeton) in koda.iter().enumerate() {
+        if i > 0 && presledek((koda[i - 1], vloge[i - 1]), (*žeton, vloge[i])) {
+            vrstica.push(' ');
+        }
+        vrstica += žeton.as_str();
+    }
+    vrstica
+}
+
+fn presledek(prejšnji: (Žeton, Vloga), naslednji: (Žeton, Vloga)) -> bool {
+    !matches!((prejšnji, naslednji),
+        ((_, Vloga::Predpona | Vloga::Klic), _)
+        | (_, (_, Vloga::Pripona | Vloga::Klic))
+        | ((Ločilo("(" | "[" | "." | "..", ..), _), _)
+        | (_, (Ločilo(")" | "]" | "," | ";" | ":" | "." | "..", ..), _))
+        // potenciranje pišemo brez presledkov (2**3)
+        | ((Operator("**", ..), _), _)
+        | (_, (Operator("**", ..), _))
+        | ((Ime(..), _), (Ločilo("(", ..), _))
+        | ((Ime(..) | Ločilo(")" | "]", ..) | Literal(L::Niz(..)), _) | (_, Vloga::Pripona), (Ločilo("[", ..), _))
+        | ((Ločilo("{", ..), _), (Ločilo("}", ..), _))
+    )
+}
+
+#[cfg(test)]
+mod testi {
+    use std::{fs, path::Path};
+
+    use super::*;
+
+    fn brez_lokacij(koda: &str) -> Vec<String> {
+        koda.razčleni("[test]")
+            .iter()
+            .map(Žeton::to_string)
+            .filter(|žeton| žeton != "\n")
+            .collect()
+    }
+
+    #[test]
+    fn presledki() {
+        assert_eq!("naj x=1; naj y=2".oblikuj(), "naj x = 1; naj y = 2\n");
+        assert_eq!("natisni!(x , \"\\n\")".oblikuj(), "natisni!(x, \"\\n\")\n");
+        assert_eq!("x=-a+ !b*(-c)".oblikuj(), "x = -a + !b * (-c)\n");
+        assert_eq!("x = 2 ** (a - 1) + p [ i ] ** 2".oblikuj(), "x = 2**(a - 1) + p[i]**2\n");
+        assert_eq!("f(@a,b@)\nb@ += 1\nc[b@]=@ ( x+1 )".oblikuj(), "f(@a, b@)\nb@ += 1\nc[b@] = @(x + 1)\n");
+        assert_eq!("spr t : [ celo ; 10 ]".oblikuj(), "spr t: [celo; 10]\n");
+        assert_eq!("natisni(@s [ .. n ] , s . dolžina)".oblikuj(), "natisni(@s[..n], s.dolžina)\n");
+        assert_eq!("funkcija f(a:celo)->bool{vrni a kot bool}".oblikuj(), "funkcija f(a: celo) -> bool { vrni a kot bool }\n");
+    }
+
+    #[test]
+    fn zamiki() {
+        assert_eq!(
+            "če x {\nza i = 0, i < 3, i += 1 {\n  natisni(i)\n      }\n}\nčene\n{\nnatisni(0)}".oblikuj(),
+            "če x {\n    za i = 0, i < 3, i += 1 {\n        natisni(i)\n    }\n} čene {\n    natisni(0) }\n"
+        );
+        assert_eq!("f(\na,\nb\n)".oblikuj(), "f(\n    a,\n    b\n)\n");
+    }
+
+    #[test]
+    fn komentarji() {
+        assert_eq!(
+            "# opis\nnaj x = 1    # ena   \n   #zamaknjen\nče x>0 { # pogoj\nx += 1 #\"nezaprt niz\n}".oblikuj(),
+            "# opis\nnaj x = 1 # ena\n#zamaknjen\nče x > 0 { # pogoj\n    x += 1 #\"nezaprt niz\n}\n"
+        );
+        // "{" ne more za komentar
+        assert_eq!("če x # pogoj\n{\n}".oblikuj(), "če x # pogoj\n{\n}\n");
+        assert_eq!("naj s = \"a # b\"".oblikuj(), "naj s = \"a # b\"\n");
+    }
+
+    #[test]
+    fn prazne_vrstice() {
+        assert_eq!("\n\nnaj x = 1\n\n\n\nnaj y = 2\n\n".oblikuj(), "naj x = 1\n\nnaj y = 2\n");
+        assert_eq!("če x {\n\n    y = 1\n   \n}".oblikuj(), "če x {\n    y = 1\n}\n");
+        assert_eq!("".oblikuj(), "");
+        assert_eq!("\n  \n".oblikuj(), "");
+    }
+
+    #[test]
+    fn primeri() {
+        let pot = Path::new(env!("CARGO_MANIFEST_DIR")).join("primeri");
+        for primer in fs::read_dir(pot).unwrap() {
+            let primer = primer.unwrap().path();
+            // namerno napačna koda, oblikovanje bi spremenilo napake, ki jih prikazuje
+            if primer.ends_with("napake.slj") {
+                continue;
+            }
+            let koda = fs::read_to_string(&primer).unwrap();
+            assert_eq!(koda.oblikuj(), koda, "{} ni oblikovan", primer.display());
+        }
+    }
+
+    #[test]
+    fn jedro() {
+        let pot = Path::new(env!("CARGO_MANIFEST_DIR")).join("jedro");
+        for datoteka in fs::read_dir(pot).unwrap() {
+            let koda = fs::read_to_string(datoteka.unwrap().path()).unwrap();
+            let oblikovana = koda.oblikuj();
+            assert_eq!(oblikovana.oblikuj(), oblikovana);
+            assert_eq!(brez_lokacij(&oblikovana), brez_lokacij(&koda));
+        }
+    }
+}
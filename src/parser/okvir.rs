@@ -11,21 +11,27 @@ impl<'a> Parser<'a> {
 
     pub fn okvir(&mut self, izraz: &[Žeton<'a>]) -> Result<Rc<Vozlišče>, Napake> {
         self.v_okvir();
-        let zaporedje = self.zaporedje(izraz)?;
+        self.v_doseg(izraz);
+        let zaporedje = self.zaporedje(izraz);
+        self.iz_dosega();
         self.iz_okvirja();
 
-        Ok(zaporedje)
+        zaporedje
     }
 
     // zaporedje izrazov, ločeno z ";" in "\n"
     pub fn zaporedje(&mut self, izraz: &[Žeton<'a>]) -> Result<Rc<Vozlišče>, Napake> {
         let zaporedje = razdeli(izraz, &[";", "\n"])?;
+        self.preveri_dosegljivost(&zaporedje);
         let mut izrazi: Vec<Rc<Vozlišče>> = Vec::new();
         let mut napake = Napake::new();
 
         for stavek in zaporedje {
             match self.stavek(stavek) {
-                Ok(stavek) => izrazi.push(stavek),
+                Ok(vozlišče) => {
+                    self.zabeleži_za_preizkuse(stavek, &vozlišče);
+                    izrazi.push(vozlišče)
+                },
                 Err(n) => napake.razširi(n),
             }
         }
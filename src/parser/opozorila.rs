@@ -0,0 +1,267 @@
+use super::*;
+use super::{simboli::Mesto, napaka::OznakaNapake};
+
+#[derive(Debug, Clone)]
+struct Deklaracija {
+    ime: String,
+    mesto: Mesto,
+    // drži spremenljivko pri življenju, da njen naslov ostane edinstven
+    _spremenljivka: Rc<Vozlišče>,
+    globalna: bool,
+    // samo "spr x = ..." bi lahko bila "naj"
+    preveri_spremembe: bool,
+    branja: usize,
+    spremenjena: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+pub(super) struct Opozorila {
+    deklaracije: Vec<Deklaracija>,
+    indeksi: HashMap<*const Vozlišče, usize>,
+    funkcije: Vec<(String, Mesto)>,
+    opozorila: Vec<Napaka>,
+    // vrstice s komentarjem "# dovoli [oznake]"; prazen seznam dovoli vsa opozorila
+    dovoljena: HashMap<(String, usize), Vec<String>>,
+}
+
+impl Napaka {
+    fn opozorilo(oznaka: OznakaNapake, sporočilo: &str, mesto: &Mesto) -> Napaka {
+        Napaka {
+            oznaka,
+            sporočilo: sporočilo.to_string(),
+            datoteka: mesto.datoteka.clone(),
+            začetek: mesto.začetek,
+            konec: mesto.konec,
+        }
+    }
+}
+
+// spremenljivka, ki se spremeni ob prirejanju elementu ali referenci
+fn koren(vozlišče: &Rc<Vozlišče>) -> Option<&Rc<Vozlišče>> {
+    match &**vozlišče {
+        Spremenljivka { .. } => Some(vozlišče),
+        Referenca(v) | RefSeznama(v) => koren(v),
+        Indeksiraj { seznam_ref, .. } | Rezina { seznam_ref, .. } => koren(seznam_ref),
+        _ => None,
+    }
+}
+
+// izraz, ki ob izračunu vedno da enako vrednost
+fn brez_učinkov(vozlišče: &Vozlišče) -> bool {
+    match vozlišče {
+        Celo(_) | Real(_) | Znak(_) | Celo64(_) | Real64(_) | Naravno(_) | Spremenljivka { .. } => true,
+        Referenca(v) | RefSeznama(v) | Dereferenciraj(v) | Dolžina(v) | ZnakVCelo(v) => brez_učinkov(v),
+        Indeksiraj { seznam_ref, indeks } => brez_učinkov(seznam_ref) && brez_učinkov(indeks),
+        _ => false,
+    }
+}
+
+fn literal(vozlišče: &Vozlišče) -> bool {
+    match vozlišče {
+        Celo(_) | Real(_) | Celo64(_) | Real64(_) | Naravno(_) => true,
+        ZnakVCelo(znak) => matches!(**znak, Znak(_)),
+        _ => false,
+    }
+}
+
+impl<'a> Parser<'a> {
+    pub(super) fn preberi_dovoljenja(&mut self, izraz: &[Žeton]) {
+        let Some(opozorila) = &mut self.opozorila else { return };
+
+        for (i, žeton) in izraz.iter().enumerate() {
+            if let (Ločilo("#", vrstica, ..), Some(Ime("dovoli", ..))) = (žeton, izraz.get(i + 1)) {
+                let oznake = izraz[i + 2..].iter()
+                    .take_while(|ž| !matches!(ž, Ločilo("\n", ..)))
+                    .filter(|ž| matches!(ž, Ime(..)))
+                    .map(|ž| ž.to_string())
+                    .collect();
+                opozorila.dovoljena.insert((žeton.datoteka(), *vrstica), oznake);
+            }
+        }
+    }
+
+    pub(super) fn zabeleži_deklaracijo(&mut self, ime: &Žeton, spremenljivka: &Rc<Vozlišče>, preveri_spremembe: bool) {
+        let globalna = !self.znotraj_funkcije && self.spremenljivke_stack.len() == 1;
+        let Some(opozorila) = &mut self.opozorila else { return };
+
+        opozorila.indeksi.insert(Rc::as_ptr(spremenljivka), opozorila.deklaracije.len());
+        opozorila.deklaracije.push(Deklaracija {
+            ime: ime.to_string(),
+            mesto: Mesto::from(ime),
+            _spremenljivka: spremenljivka.clone(),
+            globalna,
+            preveri_spremembe,
+            branja: 0,
+            spremenjena: false,
+        });
+    }
+
+    fn deklaracija_spremenljivke(&mut self, vrednost: &Rc<Vozlišče>) -> Option<&mut Deklaracija> {
+        let opozorila = self.opozorila.as_mut()?;
+        let indeks = *opozorila.indeksi.get(&Rc::as_ptr(vrednost))?;
+        opozorila.deklaracije.get_mut(indeks)
+    }
+
+    pub(super) fn zabeleži_branje(&mut self, vrednost: &Rc<Vozlišče>) {
+        if let Some(deklaracija) = self.deklaracija_spremenljivke(vrednost) {
+            deklaracija.branja += 1;
+        }
+    }
+
+    // poišči_spr prirejanje šteje kot branje, kar tu popravimo
+    pub(super) fn zabeleži_prirejanje(&mut self, spremenljivka: &Rc<Vozlišče>) {
+        if let Some(deklaracija) = self.deklaracija_spremenljivke(spremenljivka) {
+            deklaracija.branja = deklaracija.branja.saturating_sub(1);
+            deklaracija.spremenjena = true;
+        }
+    }
+
+    // prirejanje elementu, kombinirano prirejanje ali referenca (prek katere se lahko spremeni)
+    pub(super) fn zabeleži_spremembo(&mut self, vozlišče: &Rc<Vozlišče>) {
+        if let Some(deklaracija) = koren(vozlišče).and_then(|spr| self.deklaracija_spremenljivke(spr)) {
+            deklaracija.spremenjena = true;
+        }
+    }
+
+    pub(super) fn zabeleži_deklaracijo_funkcije(&mut self, ime: &Žeton, podpis: &str) {
+        if let Some(opozorila) = &mut self.opozorila {
+            opozorila.funkcije.push((podpis.to_string(), Mesto::from(ime)));
+        }
+    }
+
+    // stavki za "vrni", "končaj" ali "panika" v istem zaporedju se ne izvedejo
+    pub(super) fn preveri_dosegljivost(&mut self, zaporedje: &[&[Žeton]]) {
+        let Some(opozorila) = &mut self.opozorila else { return };
+
+        let konec = zaporedje.iter().position(|stavek| matches!(stavek,
+            [Rezerviranka("vrni", ..), ..] | [Ime("končaj" | "panika", ..), Ločilo("(", ..), ..]));
+        let Some(konec) = konec else { return };
+        let zadnji = zaporedje[konec][0];
+
+        // zanka za doda korak za telo, ki pa je v izvorni kodi pred njim
+        let nedosegljiv = zaporedje[konec + 1..].iter()
+            .find(|stavek| !stavek.is_empty())
+            .filter(|stavek| stavek[0].datoteka() == zadnji.datoteka() && stavek[0].lokacija() > zadnji.lokacija());
+
+        if let Some(stavek) = nedosegljiv {
+            opozorila.opozorila.push(Napaka::from_zaporedje(stavek, W2, "Nedosegljiva koda"));
+        }
+    }
+
+    pub(super) fn preveri_primerjavo(&mut self, izraz: &[Žeton], primerjava: &Vozlišče) {
+        let Some(opozorila) = &mut self.opozorila else { return };
+        // konstante (kons) so v drevesu že zamenjane z vrednostmi, zato literale preverimo v izvorni kodi
+        let zapisani_literali = !izraz.iter().any(|žeton| matches!(žeton, Ime(..)));
+
+        let vrednost = match primerjava {
+            Enako(_, l, d) | NiEnako(_, l, d) | Večje(_, l, d) | VečjeEnako(_, l, d) | Manjše(_, l, d) | ManjšeEnako(_, l, d)
+                if zapisani_literali && literal(l) && literal(d) => primerjava.eval(izraz).ok(),
+            Enako(_, l, d) | VečjeEnako(_, l, d) | ManjšeEnako(_, l, d) if l == d && !literal(l) && brez_učinkov(l) => Some(Resnica),
+            NiEnako(_, l, d) | Večje(_, l, d) | Manjše(_, l, d) if l == d && !literal(l) && brez_učinkov(l) => Some(Laž),
+            _ => None,
+        };
+
+        match vrednost {
+            Some(Resnica) => opozorila.opozorila.push(Napaka::from_zaporedje(izraz, W5, "Primerjava je vedno resnična")),
+            Some(Laž) => opozorila.opozorila.push(Napaka::from_zaporedje(izraz, W5, "Primerjava ni nikoli resnična")),
+            _ => (),
+        }
+    }
+
+    pub(super) fn opozorila(&self) -> Napake {
+        let Some(opozorila) = &self.opozorila else { return Napake::new() };
+        // preizkusi se pri običajnem razčlenjevanju izpustijo, zato globalna imena morda uporabljajo oni
+        let brez_preizkusov = self.preizkusi.is_empty();
+
+        let spremenljivke = opozorila.deklaracije.iter()
+            .filter(|d| !d.ime.starts_with('_') && (brez_preizkusov || !d.globalna))
+            .filter_map(|d| {
+                if d.branja == 0 {
+                    Some(Napaka::opozorilo(W1, &format!("Neuporabljena spremenljivka '{}'", d.ime), &d.mesto))
+                }
+                else if d.preveri_spremembe && !d.spremenjena {
+                    Some(Napaka::opozorilo(W3, &format!("Spremenljivka '{}' se nikoli ne spremeni, uporabite 'naj'", d.ime), &d.mesto))
+                }
+                else {
+                    None
+                }
+            });
+
+        let funkcije = opozorila.funkcije.iter()
+            .filter(|(podpis, _)| brez_preizkusov && !podpis.starts_with('_') && !self.št_klicev.contains_key(podpis))
+            .map(|(podpis, mesto)| Napaka::opozorilo(W4, &format!("Neuporabljena funkcija '{podpis}'"), mesto));
+
+        let mut vsa = spremenljivke
+            .chain(funkcije)
+            .chain(opozorila.opozorila.iter().cloned())
+            // opozorila v standardni knjižnici uporabnika ne zanimajo
+            .filter(|o| !Parser::je_jedro(&o.datoteka))
+            .filter(|o| match opozorila.dovoljena.get(&(o.datoteka.clone(), o.začetek.0)) {
+                Some(oznake) => !oznake.is_empty() && !oznake.contains(&format!("{:?}", o.oznaka)),
+                None => true,
+            })
+            .collect::<Vec<Napaka>>();
+        vsa.sort_by_key(|o| (o.datoteka.clone(), o.začetek));
+        // nekateri izrazi (npr. argumenti natisni) se razčlenijo večkrat
+        vsa.dedup();
+
+        let mut napake = Napake::new();
+        for opozorilo in vsa {
+            _ = napake.add_napaka(opozorilo);
+        }
+        napake
+    }
+}
+
+#[cfg(test)]
+mod testi {
+    use super::*;
+    use crate::parser::{Parse, lekser::Razčleni};
+
+    fn opozorila(program: &str) -> Vec<(OznakaNapake, usize)> {
+        program.razčleni("[test]")
+            .opozorila()
+            .iter()
+            .map(|o| (o.oznaka.clone(), o.začetek.0))
+            .collect()
+    }
+
+    #[test]
+    fn spremenljivke() {
+        assert_eq!(opozorila("naj a = 1\nspr b = 2\nnatisni(b)"), [(W1, 1), (W3, 2)]);
+        assert_eq!(opozorila("spr a = 1\na = 2"), [(W1, 1)]);
+        assert_eq!(opozorila("spr a = 1\na += 2\nnatisni(a)"), []);
+        assert_eq!(opozorila("spr s: [celo; 2]\ns[0] = 1\nnatisni(s[0])"), []);
+        assert_eq!(opozorila("spr s = [1, 2]\nnatisni(@s)"), []);
+        assert_eq!(opozorila("naj _a = 1"), []);
+        assert_eq!(opozorila("funkcija f(x: celo) {\n    naj y = x\n}\nf(1)"), [(W1, 2)]);
+    }
+
+    #[test]
+    fn nedosegljiva_koda() {
+        assert_eq!(opozorila("funkcija f() -> celo {\n    vrni 1\n    natisni(2)\n}\nnatisni(f())"), [(W2, 3)]);
+        assert_eq!(opozorila("končaj(0)\nnatisni(1)"), [(W2, 2)]);
+        // korak zanke za je v telesu za "vrni", a ni nedosegljiv
+        assert_eq!(opozorila("funkcija f() -> celo {\n    za i = 0, i < 3, i += 1 {\n        vrni i\n    }\n    vrni 0\n}\nnatisni(f())"), []);
+    }
+
+    #[test]
+    fn funkcije() {
+        assert_eq!(opozorila("funkcija f() {}\nfunkcija g() {}\ng()"), [(W4, 1)]);
+        assert_eq!(opozorila("funkcija _f() {}"), []);
+        // funkcijo morda uporablja preizkus
+        assert_eq!(opozorila("funkcija f() {}\npreizkus \"f\" {\n    f()\n}"), []);
+    }
+
+    #[test]
+    fn primerjave() {
+        assert_eq!(opozorila("naj x = 1\nnatisni!(x == x, x < x, 1 < 2, 'a' == 'b', x < 2)"), [(W5, 2), (W5, 2), (W5, 2), (W5, 2)]);
+        // nastavitvene konstante niso literali
+        assert_eq!(opozorila("kons N = 5\nkons M = 5\nče N > 3 {\n    natisni!(N == M, N == N)\n}"), []);
+    }
+
+    #[test]
+    fn dovoli() {
+        assert_eq!(opozorila("naj a = 1 # dovoli\nnaj b = 1 # dovoli W3\nspr c = 1 # dovoli W1, W3"), [(W1, 2)]);
+    }
+}
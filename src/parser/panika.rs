@@ -0,0 +1,48 @@
+use super::*;
+use crate::parser::argumenti::Argumenti;
+
+impl<'a> Parser<'a> {
+    // trdi(pogoj, sporočilo) pokliče _trdi iz jedra z mestom trditve v izvorni kodi
+    pub fn trdi(&mut self, trdi: &Žeton<'a>, argumenti_izraz: &[Žeton<'a>]) -> Result<Rc<Vozlišče>, Napake> {
+        let mut argumenti = self.argumenti(argumenti_izraz)?;
+
+        if argumenti.tipi.first() != Some(&Tip::Bool) || argumenti.tipi.len() > 2 {
+            return Err(Napake::from_zaporedje(argumenti_izraz, E3, "Pričakovana trditev (bool) in neobvezno sporočilo"));
+        }
+        // pri prevajanju brez trditev preverimo le tipe argumentov
+        if self.brez_trditev {
+            return Ok(Prazno.rc());
+        }
+
+        if argumenti.tipi.len() == 1 {
+            self.dodaj_niz(&mut argumenti, "trditev ne drži".to_string());
+        }
+        self.klic_z_mestom("_trdi", trdi, argumenti)
+    }
+
+    // panika(sporočilo) ustavi program, tudi ko so trditve izpuščene
+    pub fn panika(&mut self, panika: &Žeton<'a>, argumenti_izraz: &[Žeton<'a>]) -> Result<Rc<Vozlišče>, Napake> {
+        let argumenti = self.argumenti(argumenti_izraz)?;
+
+        if argumenti.tipi.len() != 1 {
+            return Err(Napake::from_zaporedje(&[*panika], E5, "Panika sprejema natanko eno sporočilo"));
+        }
+        self.klic_z_mestom("_panika", panika, argumenti)
+    }
+
+    fn klic_z_mestom(&mut self, ime: &str, žeton: &Žeton<'a>, mut argumenti: Argumenti) -> Result<Rc<Vozlišče>, Napake> {
+        let (vrstica, znak) = žeton.lokacija();
+        self.dodaj_niz(&mut argumenti, format!("{}:{vrstica}:{znak}", žeton.datoteka()));
+
+        let Argumenti { spremenljivke, argumenti, .. } = argumenti;
+        self.klic_jedra(ime, &[*žeton], spremenljivke, argumenti)
+    }
+
+    // niz, ki ga dodamo kot argument, shranimo v začasno spremenljivko
+    fn dodaj_niz(&mut self, argumenti: &mut Argumenti, niz: String) {
+        let niz = Niz(niz).rc();
+        let spr = self.začasna_spremenljivka(niz.tip());
+        argumenti.spremenljivke.push(Prirejanje { spremenljivka: spr.clone(), izraz: niz }.rc());
+        argumenti.argumenti.push(RefSeznama(spr).rc());
+    }
+}
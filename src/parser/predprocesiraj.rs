@@ -18,7 +18,7 @@ impl<'a> Parser<'a> {
 
         // odstrani razna zaporedja oklepajev, ločil in "\n",
         // da se pravilno prevede
-        while i < predproc.len() - 1 {
+        while i + 1 < predproc.len() {
             i += match predproc[i..] {
                 [ Ločilo("\n", ..), Ločilo("{", ..), ..  ] => { predproc.remove(i+0); 0 },
                 [ Ločilo("\n", ..), Ločilo("}", ..), ..  ] => { predproc.remove(i+0); 0 },
@@ -0,0 +1,45 @@
+use super::*;
+use crate::parser::loci::Escape;
+
+#[derive(Debug, Clone, Default)]
+pub struct Preizkusi {
+    // deklaracije na najvišjem nivoju do trenutnega mesta v programu
+    deklaracije: Vec<Rc<Vozlišče>>,
+    // za vsak preizkus: deklaracije pred njim, njegovo telo in konec programa
+    pub vstopi: Vec<Rc<Vozlišče>>,
+}
+
+impl<'a> Parser<'a> {
+    // preizkus se prevede le pri analizi preizkusov, sicer ga izpustimo
+    pub fn preizkus(&mut self, preizkus: &Žeton<'a>, ime: &Žeton<'a>, telo: &[Žeton<'a>]) -> Result<Rc<Vozlišče>, Napake> {
+        if self.znotraj_funkcije || self.spremenljivke_stack.len() > 1 {
+            return Err(Napake::from_zaporedje(&[*preizkus], E5, "Preizkus je mogoče deklarirati samo na najvišjem nivoju"));
+        }
+
+        let niz = ime.as_str();
+        self.preizkusi.push(niz[1..niz.len()-1].unescape());
+
+        if self.preizkus.is_some() {
+            let telo = self.okvir(telo)?;
+            let preizkusi = self.preizkus.as_mut().unwrap();
+            // program se konča s preizkusom, koda za njim se ne izvede
+            let vstop = preizkusi.deklaracije.iter()
+                .cloned()
+                .chain([telo, Končaj(Celo(0).rc()).rc()])
+                .collect();
+            preizkusi.vstopi.push(Zaporedje(vstop).rc());
+        }
+        Ok(Prazno.rc())
+    }
+
+    // funkcije, konstante, naštevanja in globalne spremenljivke se izvedejo pred vsakim preizkusom,
+    // ostala koda na najvišjem nivoju pa ne
+    pub fn zabeleži_za_preizkuse(&mut self, stavek: &[Žeton], vozlišče: &Rc<Vozlišče>) {
+        let najvišji_nivo = !self.znotraj_funkcije && self.spremenljivke_stack.len() == 1;
+        let deklaracija = matches!(stavek.first(), Some(Rezerviranka("naj" | "spr" | "kons" | "funkcija" | "naštevanje", ..)));
+
+        if let Some(preizkusi) = self.preizkus.as_mut().filter(|_| najvišji_nivo && deklaracija) {
+            preizkusi.deklaracije.push(vozlišče.clone());
+        }
+    }
+}
@@ -0,0 +1,215 @@
+use std::fmt::Display;
+
+use super::*;
+
+// vrstica in znak začetka in konca imena, kot pri napakah
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mesto {
+    pub datoteka: String,
+    pub začetek: (usize, usize),
+    pub konec: (usize, usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Vrsta {
+    Spremenljivka,
+    Konstanta,
+    Funkcija,
+}
+
+// ime, ki ga deklarira program; vidno je do konca dosega
+#[derive(Debug, Clone, PartialEq)]
+pub struct Definicija {
+    pub ime: String,
+    pub vrsta: Vrsta,
+    pub opis: String,
+    pub mesto: Mesto,
+    pub doseg: (usize, usize),
+}
+
+// uporaba imena v programu
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sklic {
+    pub opis: String,
+    pub mesto: Mesto,
+    pub definicija: Option<Mesto>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Podpis {
+    pub ime: String,
+    pub parametri: Vec<(String, Tip)>,
+    pub tip: Tip,
+}
+
+// rezultat analize za urejevalnike: napake, imena v programu in vse funkcije (tudi iz jedra)
+#[derive(Debug)]
+pub struct Analiza {
+    pub napake: Napake,
+    pub definicije: Vec<Definicija>,
+    pub sklici: Vec<Sklic>,
+    pub funkcije: Vec<Podpis>,
+}
+
+#[derive(Debug, Clone)]
+enum Ključ {
+    Vrednost(Rc<Vozlišče>),
+    Funkcija(String),
+}
+
+#[derive(Debug, Clone, Default)]
+pub(super) struct Simboli {
+    definicije: Vec<(Ključ, Definicija)>,
+    sklici: Vec<Sklic>,
+    dosegi: Vec<(usize, usize)>,
+}
+
+const DO_KONCA: (usize, usize) = (usize::MAX, usize::MAX);
+
+impl Mesto {
+    pub(super) fn from(žeton: &Žeton) -> Mesto {
+        let (vrstica, znak) = žeton.lokacija();
+        Mesto {
+            datoteka: žeton.datoteka(),
+            začetek: (vrstica, znak),
+            konec: (vrstica, znak + žeton.as_str().chars().count()),
+        }
+    }
+
+    pub fn vsebuje(&self, mesto: (usize, usize)) -> bool {
+        self.začetek <= mesto && mesto <= self.konec
+    }
+}
+
+impl Podpis {
+    fn from(funkcija: &Vozlišče) -> Podpis {
+        match funkcija {
+            Funkcija { tip, ime, parametri, .. } => Podpis {
+                // ime funkcije v tabeli vsebuje tudi tipe parametrov
+                ime: ime.split('(').next().unwrap().to_string(),
+                parametri: parametri.iter()
+                    .map(|p| match &**p {
+                        Spremenljivka { ime, tip, .. } => (ime.clone(), tip.clone()),
+                        _ => unreachable!("Parametri so vedno spremenljivke"),
+                    })
+                    .collect(),
+                tip: tip.clone(),
+            },
+            _ => unreachable!("Podpis ima samo funkcija"),
+        }
+    }
+}
+
+impl Display for Podpis {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let parametri = self.parametri.iter()
+            .map(|(ime, tip)| format!("{ime}: {tip}"))
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        match self.tip {
+            Tip::Brez => write!(f, "funkcija {}({parametri})", self.ime),
+            _ => write!(f, "funkcija {}({parametri}) -> {}", self.ime, self.tip),
+        }
+    }
+}
+
+fn opis(ime: &str, vrednost: &Vozlišče) -> String {
+    match vrednost {
+        Spremenljivka { tip, .. } => format!("{ime}: {tip}"),
+        _ => format!("kons {ime}: {} = {vrednost}", vrednost.tip()),
+    }
+}
+
+impl<'a> Parser<'a> {
+    // definicije v okvirju so vidne do zadnjega žetona v njem
+    pub(super) fn v_doseg(&mut self, izraz: &[Žeton]) {
+        if let Some(simboli) = &mut self.simboli {
+            let doseg = match izraz.last() {
+                Some(žeton) => Mesto::from(žeton).konec,
+                None => *simboli.dosegi.last().unwrap_or(&DO_KONCA),
+            };
+            simboli.dosegi.push(doseg);
+        }
+    }
+
+    pub(super) fn iz_dosega(&mut self) {
+        if let Some(simboli) = &mut self.simboli {
+            simboli.dosegi.pop();
+        }
+    }
+
+    pub(super) fn zabeleži_definicijo(&mut self, ime: &Žeton, vrednost: &Rc<Vozlišče>) {
+        if let Some(simboli) = &mut self.simboli {
+            let vrsta = match &**vrednost {
+                Spremenljivka { .. } => Vrsta::Spremenljivka,
+                _ => Vrsta::Konstanta,
+            };
+            simboli.definicije.push((Ključ::Vrednost(vrednost.clone()), Definicija {
+                ime: ime.as_str().to_string(),
+                vrsta,
+                opis: opis(ime.as_str(), vrednost),
+                mesto: Mesto::from(ime),
+                doseg: *simboli.dosegi.last().unwrap_or(&DO_KONCA),
+            }));
+        }
+    }
+
+    pub(super) fn zabeleži_funkcijo(&mut self, ime: &Žeton, funkcija: &Vozlišče) {
+        if let (Some(simboli), Funkcija { ime: podpis, .. }) = (&mut self.simboli, funkcija) {
+            simboli.definicije.push((Ključ::Funkcija(podpis.clone()), Definicija {
+                ime: ime.as_str().to_string(),
+                vrsta: Vrsta::Funkcija,
+                opis: Podpis::from(funkcija).to_string(),
+                mesto: Mesto::from(ime),
+                doseg: DO_KONCA,
+            }));
+        }
+    }
+
+    // sklic na spremenljivko ali konstanto, ki jo je vrnil poišči_spr
+    pub(super) fn zabeleži_sklic(&mut self, ime: &Žeton, vrednost: &Rc<Vozlišče>) {
+        if let Some(simboli) = &mut self.simboli {
+            let definicija = simboli.definicije.iter()
+                .rev()
+                .find(|(ključ, _)| matches!(ključ, Ključ::Vrednost(v) if Rc::ptr_eq(v, vrednost)))
+                .map(|(_, definicija)| definicija);
+
+            simboli.sklici.push(Sklic {
+                opis: definicija.map_or_else(|| opis(ime.as_str(), vrednost), |d| d.opis.clone()),
+                mesto: Mesto::from(ime),
+                definicija: definicija.map(|d| d.mesto.clone()),
+            });
+        }
+    }
+
+    pub(super) fn zabeleži_klic(&mut self, ime: &Žeton, funkcija: &Vozlišče) {
+        if let (Some(simboli), Funkcija { ime: podpis, .. }) = (&mut self.simboli, funkcija) {
+            let definicija = simboli.definicije.iter()
+                .find(|(ključ, _)| matches!(ključ, Ključ::Funkcija(f) if f == podpis))
+                .map(|(_, definicija)| definicija.mesto.clone());
+
+            simboli.sklici.push(Sklic {
+                opis: Podpis::from(funkcija).to_string(),
+                mesto: Mesto::from(ime),
+                definicija,
+            });
+        }
+    }
+
+    pub(super) fn analiza(self, napake: Napake) -> Analiza {
+        let Simboli { definicije, sklici, .. } = self.simboli.unwrap_or_default();
+
+        let mut funkcije = self.funkcije.values()
+            .map(|funkcija| Podpis::from(funkcija))
+            .collect::<Vec<Podpis>>();
+        funkcije.sort_by_key(|podpis| (podpis.ime.clone(), podpis.parametri.len(), podpis.to_string()));
+
+        Analiza {
+            napake,
+            definicije: definicije.into_iter().map(|(_, definicija)| definicija).collect(),
+            sklici,
+            funkcije,
+        }
+    }
+}
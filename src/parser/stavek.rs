@@ -30,16 +30,11 @@ impl<'a> Parser<'a> {
                 }
             },
             // prirejanje elementu seznama
-            [ ime @ Ime(..), Ločilo("[", ..), ostanek @ .. ] => {
-                let (indeks, _, ostanek) = loči_spredaj(ostanek, &["]"])
-                    .ok_or(Napake::from_zaporedje(ostanek, E5, "Pričakovan ']'"))??;
-                let (prazno, _, izraz) = loči_spredaj(ostanek, &["="])
-                    .ok_or(Napake::from_zaporedje(ostanek, E5, "Pričakovan '='"))??;
-                
-                match prazno {
-                    [] => self.prirejanje_seznamu(ime, indeks, izraz),
-                    _ => Err(Napake::from_zaporedje(prazno, E5, "Pričakovan '='")),
-                }
+            [ Ime(..), Ločilo("[", ..), .. ] => {
+                let (element, _, izraz) = loči_spredaj(izraz, &["="])
+                    .ok_or(Napake::from_zaporedje(izraz, E5, "Pričakovan '='"))??;
+
+                self.prirejanje_seznamu(element, izraz)
             },
             // okvir
             [ Ločilo("{", ..), vmes @ .., Ločilo("}", ..) ] => self.okvir(vmes),
@@ -47,6 +42,14 @@ impl<'a> Parser<'a> {
             [ ime @ Ime("natisni", ..), Ločilo("(", ..), argumenti @ .., Ločilo(")", ..) ] => self.natisni(ime, argumenti),
             // funkcija natisni (zaenkrat še posebna funkcija)
             [ Ime("splakni", ..), Ločilo("(", ..), Ločilo(")", ..) ] => Ok(Splakni.rc()),
+            // funkcija končaj (zaenkrat še posebna funkcija)
+            [ Ime("končaj", ..), Ločilo("(", ..), argumenti @ .., Ločilo(")", ..) ] => self.končaj(argumenti),
+            // ukaz navideznega stroja, katerega rezultat zavržemo
+            [ Ime("asm", ..), Ločilo("(", ..), argumenti @ .., Ločilo(")", ..) ] => self.asm_zavrzi_izhod(argumenti),
+            // trditev (zaenkrat še posebna funkcija)
+            [ trdi @ Ime("trdi", ..), Ločilo("(", ..), argumenti @ .., Ločilo(")", ..) ] => self.trdi(trdi, argumenti),
+            // panika (zaenkrat še posebna funkcija)
+            [ panika @ Ime("panika", ..), Ločilo("(", ..), argumenti @ .., Ločilo(")", ..) ] => self.panika(panika, argumenti),
             // funkcijski klic
             [ ime @ Ime(..), Ločilo("(", ..), argumenti @ .., Ločilo(")", ..) ] => self.funkcijski_klic_zavrzi_izhod(ime, argumenti),
             // pogojni stavek
@@ -55,8 +58,14 @@ impl<'a> Parser<'a> {
             [ Rezerviranka("dokler", ..), ostanek @ .. ] => self.zanka_dokler(ostanek),
             // zanka za (for loop)
             [ Rezerviranka("za", ..), ostanek @ .. ] => self.zanka_za(ostanek),
+            // izbira (switch)
+            [ Rezerviranka("izberi", ..), ostanek @ .. ] => self.izbira(ostanek),
             // deklaracija funkcije
             [ Rezerviranka("funkcija", ..), ime @ Ime(..), ostanek @ .. ] => self.funkcija(ime, ostanek),
+            // deklaracija naštevanja (enum)
+            [ Rezerviranka("naštevanje", ..), ime @ Ime(..), Ločilo("{", ..), variante @ .., Ločilo("}", ..) ] => self.naštevanje(ime, variante),
+            // preizkus (test)
+            [ preizkus @ Rezerviranka("preizkus", ..), ime @ Literal(L::Niz(..)), Ločilo("{", ..), telo @ .., Ločilo("}", ..) ] => self.preizkus(preizkus, ime, telo),
             // vrni (return)
             [ vrni @ Rezerviranka("vrni", ..), ostanek @ .. ] => self.vrni(vrni, ostanek),
             // prazen stavek
@@ -72,9 +81,23 @@ impl<'a> Parser<'a> {
             Some(_) => Err(Napake::from_zaporedje(&[*ime], E2, "Spremenljivka že obstaja")),
             None => Ok(self.dodaj_spremenljivko(ime.as_str(), tip.clone(), true)),
         }?;
+        self.zabeleži_definicijo(ime, &spremenljivka);
+        self.zabeleži_deklaracijo(ime, &spremenljivka, false);
         
-        match tip {
-            Tip::Seznam(_, dolžina) => Ok(Prirejanje { spremenljivka, izraz: Celo(dolžina).rc() }.rc()),
+        match (&*spremenljivka, &tip) {
+            (Spremenljivka { naslov, z_odmikom, .. }, Tip::Seznam(..)) => Ok(Zaporedje(tip.dolžine()
+                .into_iter()
+                .map(|(odmik, dolžina)| Prirejanje {
+                    spremenljivka: Spremenljivka {
+                        tip: Tip::Celo,
+                        ime: format!("{}.dolžina", ime.as_str()),
+                        naslov: naslov + odmik,
+                        z_odmikom: *z_odmikom,
+                        spremenljiva: true,
+                    }.rc(),
+                    izraz: Celo(dolžina).rc(),
+                }.rc())
+                .collect()).rc()),
             _ => Ok(Prazno.rc()),
         }
     }
@@ -89,6 +112,8 @@ impl<'a> Parser<'a> {
             Some(_) => Err(Napake::from_zaporedje(&[*ime], E2, "Spremenljivka že obstaja")),
             None => Ok(self.dodaj_spremenljivko(ime.as_str(), tip_spr.clone(), spremenljiva))
         }?;
+        self.zabeleži_definicijo(ime, &spremenljivka);
+        self.zabeleži_deklaracijo(ime, &spremenljivka, spremenljiva);
 
         if tip_spr == izraz.tip() {
             Ok(Prirejanje { spremenljivka, izraz }.rc())
@@ -105,10 +130,11 @@ impl<'a> Parser<'a> {
             Some(tip) => Tip::from(tip, &self.konstante)?,
             None => drevo.tip(),
         };
-        match self.konstante.get(ime.as_str()) {
+        let konstanta = match self.konstante.get(ime.as_str()) {
             Some(_) => Err(Napake::from_zaporedje(&[*ime], E2, "Konstanta že obstaja")),
             None => Ok(self.dodaj_konstanto(ime.to_string(), drevo.eval(izraz)?.rc()))
         }?;
+        self.zabeleži_definicijo(ime, &konstanta);
 
         if tip_kons == drevo.tip() {
             Ok(Prazno.rc())
@@ -122,6 +148,7 @@ impl<'a> Parser<'a> {
     fn prirejanje(&mut self, ime: &Žeton<'a>, izraz: &[Žeton<'a>]) -> Result<Rc<Vozlišče>, Napake> {
         let izraz = self.drevo(izraz)?;
         let spremenljivka = self.poišči_spr(ime)?;
+        self.zabeleži_prirejanje(&spremenljivka);
 
         if let Spremenljivka { spremenljiva, .. } = &*spremenljivka {
             if !spremenljiva {
@@ -158,33 +185,28 @@ impl<'a> Parser<'a> {
 
     }
 
-    fn prirejanje_seznamu(&mut self, ime: &Žeton<'a>, indeks: &[Žeton<'a>], izraz: &[Žeton<'a>]) -> Result<Rc<Vozlišče>, Napake> {
+    fn prirejanje_seznamu(&mut self, element_izraz: &[Žeton<'a>], izraz: &[Žeton<'a>]) -> Result<Rc<Vozlišče>, Napake> {
         let izraz = self.drevo(izraz)?;
-        let indeks = Some(self.drevo(indeks)?);
-        let tip_indeksa = indeks.clone().unwrap().tip();
-        let spr = self.poišči_spr(ime)?;
+        let element = self.indeksiraj(element_izraz)?;
+        self.zabeleži_spremembo(&element);
 
-        if let Tip::Seznam(tip, _) | Tip::RefSeznama(tip) = spr.tip() {
-            if *tip != izraz.tip() {
-                return Err(Napake::from_zaporedje(&[*ime], E3,
-                        &format!("Nemogoča operacija: {} = {}", *tip, izraz.tip())));
-            }
-        }
-        if tip_indeksa != Tip::Celo {
-            return Err(Napake::from_zaporedje(&[*ime], E3,
-                    &format!("Neveljaven tip indeksa: '{}'", tip_indeksa)));
+        if element.tip() != izraz.tip() {
+            return Err(Napake::from_zaporedje(element_izraz, E3,
+                    &format!("Nemogoča operacija: {} = {}", element.tip(), izraz.tip())));
         }
 
-        match spr.tip() {
-            Tip::RefSeznama(..) => Ok(PrirejanjeRef { referenca: spr, indeks, izraz }.rc()),
-            Tip::Seznam(..) => Ok(PrirejanjeRef { referenca: RefSeznama(spr).rc(), indeks, izraz }.rc()),
-            tip @ _ => Err(Napake::from_zaporedje(&[*ime], E2, 
-                    &format!("V spremenljivko tipa '{}' ni mogoče indeksirati.", tip))),
+        match &*element {
+            Indeksiraj { seznam_ref, .. } if seznam_ref.tip() == Tip::Niz =>
+                Err(Napake::from_zaporedje(element_izraz, E3, "Niza ni mogoče spreminjati, uporabite referenco nanj")),
+            Indeksiraj { seznam_ref, indeks } =>
+                Ok(PrirejanjeRef { referenca: seznam_ref.clone(), indeks: Some(indeks.clone()), izraz }.rc()),
+            _ => unreachable!("Indeksiranje vedno vrne Indeksiraj"),
         }
     }
 
     fn kombinirano_prirejanje(&mut self, ime: &Žeton, operator: &Žeton, izraz: &[Žeton<'a>]) -> Result<Rc<Vozlišče>, Napake> {
         let spremenljivka = self.poišči_spr(ime)?;
+        self.zabeleži_spremembo(&spremenljivka);
 
         if let Spremenljivka { spremenljiva, .. } = &*spremenljivka {
             if !spremenljiva {
@@ -216,6 +238,9 @@ impl<'a> Parser<'a> {
             Aritmetični(op) => match (spremenljivka.tip(), drevo.tip()) {
                 (Tip::Celo, Tip::Celo) => Ok(op(Tip::Celo, spremenljivka, drevo).rc()),
                 (Tip::Real, Tip::Real) => Ok(op(Tip::Real, spremenljivka, drevo).rc()),
+                (Tip::Celo64, Tip::Celo64) => Ok(op(Tip::Celo64, spremenljivka, drevo).rc()),
+                (Tip::Real64, Tip::Real64) => Ok(op(Tip::Real64, spremenljivka, drevo).rc()),
+                (Tip::Naravno, Tip::Naravno) => Ok(op(Tip::Naravno, spremenljivka, drevo).rc()),
                 _ => Err(Napake::from_zaporedje(&[*operator], E3,
                         &format!("Nemogoča operacija: {} {} {}", spremenljivka.tip(), operator.as_str(), drevo.tip()))),
             },
@@ -226,6 +251,8 @@ impl<'a> Parser<'a> {
             }
             Bitni(op) => match (spremenljivka.tip(), drevo.tip()) {
                 (Tip::Celo, Tip::Celo) => Ok(op(spremenljivka, drevo).rc()),
+                (Tip::Naravno, Tip::Naravno) => Ok(op(spremenljivka, drevo).rc()),
+                (Tip::Naravno, Tip::Celo) if matches!(operator.as_str(), "<<=" | ">>=") => Ok(op(spremenljivka, drevo).rc()),
                 _ => Err(Napake::from_zaporedje(&[*operator], E3,
                         &format!("Nemogoča operacija: {} {} {}", spremenljivka.tip(), operator.as_str(), drevo.tip()))),
             }
@@ -233,7 +260,7 @@ impl<'a> Parser<'a> {
         }
     }
 
-    fn natisni(&mut self, ime: &Žeton, argumenti_izraz: &[Žeton<'a>]) -> Result<Rc<Vozlišče>, Napake> {
+    pub fn natisni(&mut self, ime: &Žeton, argumenti_izraz: &[Žeton<'a>]) -> Result<Rc<Vozlišče>, Napake> {
         let Argumenti { tipi, argumenti, .. } = self.argumenti(argumenti_izraz)?;
 
         match tipi.as_slice() {
@@ -242,6 +269,14 @@ impl<'a> Parser<'a> {
         }
     }
 
+    fn končaj(&mut self, izraz: &[Žeton<'a>]) -> Result<Rc<Vozlišče>, Napake> {
+        let koda = self.drevo(izraz)?;
+        match koda.tip() {
+            Tip::Celo => Ok(Končaj(koda).rc()),
+            tip => Err(Napake::from_zaporedje(izraz, E3, &format!("Izhodna koda mora biti tipa 'celo', ne '{tip}'"))),
+        }
+    }
+
     fn vrni(&mut self, vrni: &Žeton, izraz: &[Žeton<'a>]) -> Result<Rc<Vozlišče>, Napake> {
         let drevo = self.drevo(izraz)?;
         let spremenljivka = self.spremenljivke.get("0_vrni")
@@ -282,6 +317,76 @@ impl<'a> Parser<'a> {
         }.rc())
     }
 
+    fn izbira(&mut self, izraz: &[Žeton<'a>]) -> Result<Rc<Vozlišče>, Napake> {
+        let (izbira_izraz, _, izraz) = loči_spredaj(izraz, &["{"])
+            .ok_or(Napake::from_zaporedje(izraz, E5, "Pričakovan '{'"))??;
+
+        let (mut veje_izraz, _, prazno) = loči_zadaj(izraz, &["}"])
+            .ok_or(Napake::from_zaporedje(izraz, E5, "Pričakovan '}'"))??;
+
+        if !prazno.is_empty() {
+            return Err(Napake::from_zaporedje(prazno, E5, "Izraz 'izberi' se mora zaključiti z '}'"));
+        }
+
+        // izraz izračunamo samo enkrat
+        let izbira = self.drevo(izbira_izraz)?;
+        let (prirejanje, izbira) = match &*izbira {
+            Spremenljivka { .. } => (Prazno.rc(), izbira),
+            _ => {
+                let spremenljivka = self.začasna_spremenljivka(izbira.tip());
+                (Prirejanje { spremenljivka: spremenljivka.clone(), izraz: izbira }.rc(), spremenljivka)
+            },
+        };
+
+        let mut veje = Vec::new();
+        let mut čene = Prazno.rc();
+
+        while !veje_izraz.is_empty() {
+            let ostanek = match veje_izraz {
+                [ Ločilo("\n", ..), ostanek @ .. ] => ostanek,
+                _ => veje_izraz,
+            };
+
+            let (vzorci, oklepaj, ostanek) = loči_spredaj(ostanek, &["{"])
+                .ok_or(Napake::from_zaporedje(ostanek, E5, "Pričakovan '{'"))??;
+            let (telo, _, ostanek) = loči_spredaj(ostanek, &["}"])
+                .ok_or(Napake::from_zaporedje(ostanek, E5, "Pričakovan '}'"))??;
+
+            match vzorci {
+                [ Rezerviranka("čene", ..) ] => match ostanek {
+                    [] | [ Ločilo("\n", ..) ] => čene = self.okvir(telo)?,
+                    _ => return Err(Napake::from_zaporedje(ostanek, E5, "Veja 'čene' mora biti zadnja")),
+                },
+                [] => return Err(Napake::from_zaporedje(&[*oklepaj], E5, "Pred '{' pričakovan vzorec")),
+                _ => {
+                    let mut pogoj: Option<Rc<Vozlišče>> = None;
+
+                    for vzorec_izraz in razdeli(vzorci, &[","])? {
+                        let vzorec = self.drevo(vzorec_izraz)?;
+                        let enako = self.primerjaj(&Operator("==", 0, 0, "[builtin]"), izbira.clone(), vzorec.clone())
+                            .or(Err(Napake::from_zaporedje(vzorec_izraz, E3,
+                                    &format!("Vzorca tipa '{}' ni mogoče primerjati z izrazom tipa '{}'", vzorec.tip(), izbira.tip()))))?;
+
+                        pogoj = Some(match pogoj {
+                            Some(pogoj) => Disjunkcija(pogoj, enako).rc(),
+                            None => enako,
+                        });
+                    }
+
+                    veje.push((pogoj.unwrap(), self.okvir(telo)?));
+                },
+            }
+
+            veje_izraz = ostanek;
+        }
+
+        let izbira = veje.into_iter()
+            .rev()
+            .fold(čene, |laž, (pogoj, resnica)| PogojniStavek { pogoj, resnica, laž }.rc());
+
+        Ok(Zaporedje(vec![prirejanje, izbira]).rc())
+    }
+
     fn zanka_dokler(&mut self, izraz: &[Žeton<'a>]) -> Result<Rc<Vozlišče>, Napake> {
         let (pogoj_izraz, _, izraz) = loči_spredaj(izraz, &["{"])
             .ok_or(Napake::from_zaporedje(izraz, E5, "Pričakovan '{'"))??;
@@ -18,10 +18,15 @@ pub enum Tip {
     Celo,
     Real,
     Znak,
+    Celo64,
+    Real64,
+    Naravno,
+    Niz,
     Seznam(Box<Tip>, i32),
     Strukt(BTreeMap<String, Box<Tip>>),
     Referenca(Box<Tip>),
     RefSeznama(Box<Tip>),
+    Naštevanje(String, Vec<String>),
 }
 
 impl Tip {
@@ -33,6 +38,11 @@ impl Tip {
             [ Žeton::Tip("celo", ..) ] => Ok(Tip::Celo),
             [ Žeton::Tip("real", ..) ] => Ok(Tip::Real),
             [ Žeton::Tip("znak", ..) ] => Ok(Tip::Znak),
+            [ Žeton::Tip("celo64" | "dolgo", ..) ] => Ok(Tip::Celo64),
+            [ Žeton::Tip("real64", ..) ] => Ok(Tip::Real64),
+            [ Žeton::Tip("naravno", ..) ] => Ok(Tip::Naravno),
+            // "niz" ni rezervirana beseda, da ga lahko še vedno uporabljamo kot ime
+            [ Žeton::Ime("niz", ..) ] => Ok(Tip::Niz),
             [ Ločilo("[", ..), tip @ .., Ločilo(";", ..), žeton @ Žeton::Literal(L::Celo(len, ..)) , Ločilo("]", ..) ] => 
                 Ok(Tip::Seznam(Box::new(Tip::from(tip, konstante)?), 
                         match len.replace("_", "").parse() {
@@ -54,11 +64,21 @@ impl Tip {
             [ Ločilo("{", ..), vmes @ .., Ločilo("}", ..) ] => Ok(Tip::Strukt(zgradi_tip_strukta(vmes)?)),
             [ Operator("@", ..), Ločilo("[", ..), ostanek @ .. , Ločilo("]", ..)] => Ok(RefSeznama(Box::new(Tip::from(ostanek, konstante)?))),
             [ Operator("@", ..), ostanek @ .. ] => Ok(Referenca(Box::new(Tip::from(ostanek, konstante)?))),
+            [ žeton @ Žeton::Ime(ime, ..) ] => Tip::naštevanje(ime, konstante)
+                .ok_or(Napake::from_zaporedje(&[*žeton], E1, &format!("Neznan tip: '{ime}'"))),
             _ => Err(Napake::from_zaporedje(izraz, E1, 
                     &format!("Neznan tip: '{}'", izraz.iter().map(|t| t.as_str()).collect::<Vec<&str>>().join("")))),
         }
     }
 
+    pub fn naštevanje(ime: &str, konstante: &HashMap<String, Rc<Vozlišče>>) -> Option<Self> {
+        konstante.values()
+            .find_map(|k| match &**k {
+                Vozlišče::Varianta(tip @ Naštevanje(ime_tipa, _), _) if ime_tipa == ime => Some(tip.clone()),
+                _ => None,
+            })
+    }
+
     pub fn dolžina(&self) -> i32 {
         match self {
             Seznam(_, len) => *len,
@@ -70,9 +90,42 @@ impl Tip {
         match self {
             Brez => 0,
             Bool | Celo | Real | Znak => 1,
+            Celo64 | Real64 | Naravno => 1,
+            Naštevanje(..) => 1,
             Seznam(tip, len) => (tip.sprememba_stacka() * len) + 1,
             Strukt(polja) => polja.values().map(|p| p.sprememba_stacka()).sum(),
-            Referenca(_) | RefSeznama(_) => 1,
+            Referenca(_) => 1,
+            // [naslov, dolžina]
+            RefSeznama(_) | Niz => 2,
+        }
+    }
+
+    // tipi posameznih mest na stacku (pri seznamu je prvo mesto dolžina)
+    pub fn osnovni_tipi(&self) -> Vec<Self> {
+        match self {
+            Brez => vec![],
+            Seznam(tip, len) => [
+                vec![Celo],
+                (0..*len).flat_map(|_| tip.osnovni_tipi()).collect(),
+            ].concat(),
+            Strukt(polja) => polja.values().flat_map(|p| p.osnovni_tipi()).collect(),
+            RefSeznama(_) | Niz => vec![Celo, Celo],
+            _ => vec![self.clone()],
+        }
+    }
+
+    // odmiki in vrednosti vseh dolžin v seznamu (tudi v podseznamih)
+    pub fn dolžine(&self) -> Vec<(i32, i32)> {
+        match self {
+            Seznam(tip, len) => [
+                vec![(0, *len)],
+                (0..*len)
+                    .flat_map(|i| tip.dolžine()
+                        .into_iter()
+                        .map(move |(odmik, dolžina)| (1 + i * tip.sprememba_stacka() + odmik, dolžina)))
+                    .collect(),
+            ].concat(),
+            _ => vec![],
         }
     }
 
@@ -81,6 +134,7 @@ impl Tip {
             Tip::Seznam(tip, _) => (**tip).clone(),
             Tip::Referenca(tip) => (**tip).clone(),
             Tip::RefSeznama(tip) => (**tip).clone(),
+            Tip::Niz => Tip::Znak,
             _ => unreachable!("Samo seznami vsebujejo tipe"),
         }
     }
@@ -95,6 +149,10 @@ impl Display for Tip {
             Celo => "celo".to_string(),
             Real => "real".to_string(),
             Znak => "znak".to_string(),
+            Celo64 => "celo64".to_string(),
+            Real64 => "real64".to_string(),
+            Naravno => "naravno".to_string(),
+            Niz => "niz".to_string(),
             Seznam(tip, len) => format!("[{tip}; {len}]"),
             Strukt(polja) => {
                 let mut str = "{\n".to_string();
@@ -106,6 +164,7 @@ impl Display for Tip {
             },
             Referenca(tip) => format!("@{tip}"),
             RefSeznama(tip) => format!("@[{tip}]"),
+            Naštevanje(ime, _) => ime.clone(),
         })
     }
 }
@@ -170,6 +229,11 @@ mod testi {
         assert_eq!(Tip::from("celo".razčleni("[test]").as_slice(), &HashMap::new()).unwrap().to_string(), "celo");
         assert_eq!(Tip::from("real".razčleni("[test]").as_slice(), &HashMap::new()).unwrap().to_string(), "real");
         assert_eq!(Tip::from("znak".razčleni("[test]").as_slice(), &HashMap::new()).unwrap().to_string(), "znak");
+        assert_eq!(Tip::from("celo64".razčleni("[test]").as_slice(), &HashMap::new()).unwrap().to_string(), "celo64");
+        assert_eq!(Tip::from("dolgo".razčleni("[test]").as_slice(), &HashMap::new()).unwrap().to_string(), "celo64");
+        assert_eq!(Tip::from("real64".razčleni("[test]").as_slice(), &HashMap::new()).unwrap().to_string(), "real64");
+        assert_eq!(Tip::from("naravno".razčleni("[test]").as_slice(), &HashMap::new()).unwrap().to_string(), "naravno");
+        assert_eq!(Tip::from("niz".razčleni("[test]").as_slice(), &HashMap::new()).unwrap().to_string(), "niz");
 
         assert_eq!(Tip::from("[celo; 6]".razčleni("[test]").as_slice(), &HashMap::new()).unwrap().to_string(), "[celo; 6]");
         assert_eq!(Tip::from("[[celo; 3]; 6]".razčleni("[test]").as_slice(), &HashMap::new()).unwrap().to_string(), "[[celo; 3]; 6]");
@@ -180,4 +244,11 @@ mod testi {
         assert_eq!(Tip::from("@celo".razčleni("[test]").as_slice(), &HashMap::new()).unwrap().to_string(), "@celo");
         assert_eq!(Tip::from("@[real]".razčleni("[test]").as_slice(), &HashMap::new()).unwrap().to_string(), "@[real]");
     }
+
+    #[test]
+    fn dolžine() {
+        assert_eq!(Tip::Celo.dolžine(), []);
+        assert_eq!(Tip::Seznam(Box::new(Tip::Real), 5).dolžine(), [(0, 5)]);
+        assert_eq!(Tip::Seznam(Box::new(Tip::Seznam(Box::new(Tip::Celo), 3)), 2).dolžine(), [(0, 2), (1, 3), (5, 3)]);
+    }
 }
@@ -17,7 +17,15 @@ impl From<String> for Program {
             ukazi.push(match besede[1] {
                 "PUSH" => {
                     if besede[2].chars().nth(0).unwrap() == '#' {
-                        if besede[2].contains('.') {
+                        if besede[2].ends_with('D') {
+                            push_tipi.push(Tip::Real64);
+                            PUSH(Podatek { d:  besede[2][1..besede[2].len()-1].parse().unwrap() })
+                        }
+                        else if besede[2].ends_with('L') {
+                            push_tipi.push(Tip::Celo64);
+                            PUSH(Podatek { l:  besede[2][1..besede[2].len()-1].parse().unwrap() })
+                        }
+                        else if besede[2].contains('.') {
                             push_tipi.push(Tip::Real);
                             PUSH(Podatek { f:  besede[2][1..].parse().unwrap() })
                         }
@@ -40,6 +48,8 @@ impl From<String> for Program {
                 "STOR" => STOR(besede[2][1..].parse().unwrap()),
                 "STOF" => STOF(besede[2][1..].parse().unwrap()),
                 "STDY" => STDY(besede[2][1..].parse().unwrap()),
+                "COPY" => COPY(besede[2][1..].parse().unwrap()),
+                "SLCE" => SLCE(besede[2][1..].parse().unwrap()),
                 "TOP"  => TOP(besede[2][0..].parse().unwrap()),
                 "JMPD" => JMPD,
                 "POS"  => POS,
@@ -47,7 +57,20 @@ impl From<String> for Program {
                 "LOFF" => LOFF,
                 "SOFF" => SOFF,
                 "PUTC" => PUTC,
+                "PUTE" => PUTE,
                 "GETC" => GETC,
+                "SEED" => SEED,
+                "ARGC" => ARGC,
+                "ARGV" => ARGV,
+                "ENVV" => ENVV,
+                "OPEN" => OPEN,
+                "CLOS" => CLOS,
+                "FGTC" => FGTC,
+                "FPTC" => FPTC,
+                "TIME" => TIME,
+                "SLEP" => SLEP,
+                "EXIT" => EXIT,
+                "ALHP" => ALHP,
                 "ADDF" => ADDF,
                 "SUBF" => SUBF,
                 "MULF" => MULF,
@@ -63,13 +86,52 @@ impl From<String> for Program {
                 "BOR"  => BOR,
                 "BXOR" => BXOR,
                 "BAND" => BAND,
+                "BSLL" => BSLL,
+                "BSLR" => BSLR,
                 "FTOI" => FTOI,
                 "ITOF" => ITOF,
+                "ADDL" => ADDL,
+                "SUBL" => SUBL,
+                "MULL" => MULL,
+                "DIVL" => DIVL,
+                "MODL" => MODL,
+                "POWL" => POWL,
+                "ADDD" => ADDD,
+                "SUBD" => SUBD,
+                "MULD" => MULD,
+                "DIVD" => DIVD,
+                "MODD" => MODD,
+                "POWD" => POWD,
+                "POSL" => POSL,
+                "ZERL" => ZERL,
+                "ITOL" => ITOL,
+                "LTOI" => LTOI,
+                "FTOD" => FTOD,
+                "DTOF" => DTOF,
+                "LTOD" => LTOD,
+                "DTOL" => DTOL,
+                "DIVU" => DIVU,
+                "MODU" => MODU,
+                "GTU"  => GTU,
+                "BSRU" => BSRU,
+                "UTOL" => UTOL,
+                "SQRT" => SQRT,
+                "SINF" => SINF,
+                "COSF" => COSF,
+                "TANF" => TANF,
+                "ATNF" => ATNF,
+                "EXPF" => EXPF,
+                "LOGF" => LOGF,
+                "FLRF" => FLRF,
+                "CEIF" => CEIF,
+                "RNDF" => RNDF,
+                "ABSF" => ABSF,
+                "ABSI" => ABSI,
                 _      => NOOP,
             });
         }
 
-        Program { push_tipi, ukazi }
+        Program { push_tipi, ukazi, argumenti: vec![] }
     }
 }
 
@@ -5,12 +5,14 @@ mod v_assembler;
 mod v_fasm_x86;
 mod zazeni;
 
+pub use zazeni::{Stroj, Izid};
+
 use std::collections::HashMap;
 use std::{mem::size_of, fmt::Debug};
 use std::{fmt, io};
 
 use crate::parser::{drevo::Drevo, tip::Tip};
-use crate::parser::drevo::Vozlišče::{*, self};
+use crate::parser::drevo::{Matematika, Vozlišče::{*, self}};
 use self::{UkazPodatek::*, UkazPodatekRelative::*};
 
 pub trait ToProgram {
@@ -36,6 +38,8 @@ pub union Podatek {
     i: i32,
     f: f32,
     c: char,
+    l: i64,
+    d: f64,
 }
 
 impl PartialEq for Podatek {
@@ -59,14 +63,29 @@ enum UkazPodatek
     STOR(i32), // store normal
     STOF(i32), // store w/ offset
     STDY(i32), // store dynamic
+    COPY(i32), // copy n values
+    SLCE(i32), // slice (bounds checked)
+    ALHP,      // allocate on heap
     TOP(i32),
     POS,
     ZERO,
     SOFF,
     LOFF,
     PUTC,
+    PUTE,      // print char to stderr
     GETC,
+    SEED,      // seed for random numbers
+    ARGC,      // number of arguments
+    ARGV,      // argument as string
+    ENVV,      // environment variable as string
+    OPEN,      // open file
+    CLOS,      // close file
+    FGTC,      // read char from file
+    FPTC,      // write char to file
+    TIME,      // monotonic time in nanoseconds
+    SLEP,      // sleep for milliseconds
     FLUSH,
+    EXIT,      // end with exit code
     ADDF,
     SUBF,
     MULF,
@@ -86,6 +105,43 @@ enum UkazPodatek
     BSLR,
     FTOI,
     ITOF,
+    ADDL,
+    SUBL,
+    MULL,
+    DIVL,
+    MODL,
+    POWL,
+    ADDD,
+    SUBD,
+    MULD,
+    DIVD,
+    MODD,
+    POWD,
+    POSL,
+    ZERL,
+    ITOL,
+    LTOI,
+    FTOD,
+    DTOF,
+    LTOD,
+    DTOL,
+    DIVU,
+    MODU,
+    GTU,
+    BSRU,
+    UTOL,
+    SQRT,
+    SINF,
+    COSF,
+    TANF,
+    ATNF, // atan2
+    EXPF,
+    LOGF, // naravni logaritem
+    FLRF, // floor
+    CEIF, // ceil
+    RNDF, // round
+    ABSF,
+    ABSI,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -99,6 +155,7 @@ enum ArO {
     And,
     Shl,
     Shr,
+    Sar,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -107,7 +164,7 @@ enum R {
     Rax, Eax, Al,
     Rbx, Ebx, Bl,
     Rcx, Ecx, Cl,
-    Rdx,
+    Rdx, Edx,
     Rdi,
     Rsi,
     R8,
@@ -121,6 +178,8 @@ enum UkazPodatekRelative {
     PUSHI(i32),
     PUSHF(f32),
     PUSHC(char),
+    PUSHL(i64),
+    PUSHD(f64),
     JUMPRel(String),
     JMPCRel(String),
     CALL(String),
@@ -139,6 +198,14 @@ enum UkazPodatekRelative {
 pub struct Program {
     push_tipi: Vec<Tip>,
     ukazi: Vec<UkazPodatek>,
+    argumenti: Vec<String>,
+}
+
+impl Program {
+    // argumenti, ki jih program vidi preko argumenti() in argument(n)
+    pub fn z_argumenti(self, argumenti: Vec<String>) -> Program {
+        Program { argumenti, ..self }
+    }
 }
 
 
@@ -158,6 +225,7 @@ impl ToProgram for Drevo {
         Program { 
             push_tipi,
             ukazi,
+            argumenti: vec![],
         }
     }
 }
@@ -174,7 +242,7 @@ impl ToFasmX86 for Drevo {
 
 const RESNICA: Podatek = Podatek { i: 1 };
 const LAŽ    : Podatek = Podatek { i: 0 };
-const NIČ    : Podatek = Podatek { i: 0 };
+const NIČ    : Podatek = Podatek { l: 0 };
 
 
 impl Program {
@@ -196,6 +264,8 @@ mod test {
                 Tip::Celo,
                 Tip::Znak,
                 Tip::Znak,
+                Tip::Celo64,
+                Tip::Real64,
             ],
             ukazi: [
                 NOOP,
@@ -207,6 +277,8 @@ mod test {
                 PUSH(Podatek { i: 42 }),
                 PUSH(Podatek { c: 'c' }),
                 PUSH(Podatek { c: '\n' }),
+                PUSH(Podatek { l: 1 << 40 }),
+                PUSH(Podatek { d: 2.718281828459045 }),
                 ALOC(-12),
                 POS,
                 ZERO,
@@ -214,12 +286,27 @@ mod test {
                 LDOF(42),
                 STOR(256),
                 STOF(200),
+                COPY(5),
+                SLCE(3),
+                ALHP,
                 TOP(13),
                 TOP(-13),
                 SOFF,
                 LOFF,
                 PUTC,
+                PUTE,
                 GETC,
+                SEED,
+                ARGC,
+                ARGV,
+                ENVV,
+                OPEN,
+                CLOS,
+                FGTC,
+                FPTC,
+                TIME,
+                SLEP,
+                EXIT,
                 ADDF,
                 SUBF,
                 MULF,
@@ -237,7 +324,47 @@ mod test {
                 BOR,
                 BXOR,
                 BAND,
+                ADDL,
+                SUBL,
+                MULL,
+                DIVL,
+                MODL,
+                POWL,
+                ADDD,
+                SUBD,
+                MULD,
+                DIVD,
+                MODD,
+                POWD,
+                POSL,
+                ZERL,
+                ITOL,
+                LTOI,
+                FTOD,
+                DTOF,
+                LTOD,
+                DTOL,
+                BSLL,
+                BSLR,
+                DIVU,
+                MODU,
+                GTU,
+                BSRU,
+                UTOL,
+                SQRT,
+                SINF,
+                COSF,
+                TANF,
+                ATNF,
+                EXPF,
+                LOGF,
+                FLRF,
+                CEIF,
+                RNDF,
+                ABSF,
+                ABSI,
             ].to_vec(),
+            argumenti: vec![],
         };
 
         assert_eq!(program, Program::from(program.v_assembler()));
@@ -85,6 +85,8 @@ impl Postprocesiraj for Vec<UkazPodatekRelative> {
                     PUSHI(celo) => { push_tipi.push(Tip::Celo); PUSH(Podatek { i: *celo }) },
                     PUSHF(real) => { push_tipi.push(Tip::Real); PUSH(Podatek { f: *real }) },
                     PUSHC(znak) => { push_tipi.push(Tip::Znak); PUSH(Podatek { c: *znak }) },
+                    PUSHL(celo) => { push_tipi.push(Tip::Celo64); PUSH(Podatek { l: *celo }) },
+                    PUSHD(real) => { push_tipi.push(Tip::Real64); PUSH(Podatek { d: *real }) },
                     JUMPRel(oznaka) => JUMP(oznake_vrstic[oznaka]),
                     JMPCRel(oznaka) => JMPC(oznake_vrstic[oznaka]),
                     CALL(oznaka) => JUMP(oznake_vrstic[oznaka]),
@@ -36,45 +36,81 @@ impl Vozlišče {
             NaložiOdmik => vec![Osnovni(LOFF)],
 
             Znak(znak) => vec![PUSHC(*znak)],
+            // na stacku so vrednosti v enakem vrstnem redu kot v pomnilniku (dolžina je prva)
             Niz(niz) => [
+                vec![PUSHI(niz.chars().count() as i32)],
                 niz
-                    .chars().rev()
+                    .chars()
                     .map(|znak| PUSHC(znak))
                     .collect::<Vec<UkazPodatekRelative>>(),
-                vec![PUSHI(niz.chars().count() as i32)],
+            ].concat(),
+            Seznam(elementi) => [
+                vec![PUSHI(elementi.len() as i32)],
+                elementi
+                    .iter()
+                    .flat_map(|element| element.prevedi(št_klicev))
+                    .collect::<Vec<UkazPodatekRelative>>(),
             ].concat(),
             Celo(število) => vec![PUSHI(*število)],
             Real(število) => vec![PUSHF(*število)],
+            Celo64(število) => vec![PUSHL(*število)],
+            Real64(število) => vec![PUSHD(*število)],
+            Naravno(število) => vec![PUSHI(*število as i32)],
+            Varianta(_, vrednost) => vec![PUSHI(*vrednost)],
 
             Resnica => vec![PUSHI(1)],
             Laž     => vec![PUSHI(0)],
 
-            Spremenljivka{ naslov, z_odmikom, .. } => vec![Osnovni(if *z_odmikom { LDOF(*naslov) } else { LOAD(*naslov) })],
-            Referenca(vozlišče) | RefSeznama(vozlišče) => match &**vozlišče {
+            Spremenljivka{ naslov, z_odmikom, tip, .. } => (0..tip.sprememba_stacka())
+                .map(|odmik| Osnovni(if *z_odmikom { LDOF(*naslov + odmik) } else { LOAD(*naslov + odmik) }))
+                .collect(),
+            RefSeznama(_) => [
+                naslov_seznama(self, št_klicev).as_slice(),
+                dolžina_seznama(self, št_klicev).as_slice(),
+            ].concat(),
+            Rezina { seznam_ref, začetek, konec } => [
+                seznam_ref.prevedi(št_klicev).as_slice(),
+                začetek.prevedi(št_klicev).as_slice(),
+                konec.prevedi(št_klicev).as_slice(),
+                [Osnovni(SLCE(seznam_ref.tip().vsebuje_tip().sprememba_stacka()))].as_slice(),
+            ].concat(),
+            Referenca(vozlišče) => match &**vozlišče {
                 Spremenljivka { naslov, z_odmikom, .. } =>
                     match vozlišče.tip() {
                         Tip::Seznam(..) => vec![PUSHREF(*naslov + 1, *z_odmikom)],
                         _               => vec![PUSHREF(*naslov, *z_odmikom)],
                     }
+                Indeksiraj { seznam_ref, indeks } => [
+                    naslov_elementa(seznam_ref, indeks, št_klicev).as_slice(),
+                    match vozlišče.tip() {
+                        Tip::Seznam(..) => [PUSHI(1), Osnovni(ADDI)].as_slice(),
+                        _               => [].as_slice(),
+                    },
+                ].concat(),
                     
-                _ => unreachable!("Referenciramo lahko samo spremenljivko.")
+                _ => unreachable!("Referenciramo lahko samo spremenljivko ali element seznama.")
             },
 
             Dereferenciraj(vozlišče) => [
                 vozlišče.prevedi(št_klicev).as_slice(),
                 [Osnovni(LDDY(0))].as_slice(),
             ].concat(),
-            Indeksiraj { seznam_ref, indeks } => [
-                    seznam_ref.prevedi(št_klicev).as_slice(),
-                    indeks.prevedi(št_klicev).as_slice(),
+            Indeksiraj { seznam_ref, indeks } => match self.sprememba_stacka() {
+                1 => [
+                    naslov_seznama(seznam_ref, št_klicev).as_slice(),
+                    pomnoži_indeks(seznam_ref, indeks, št_klicev).as_slice(),
                     &[LDINDEXED],
                 ].concat(),
+                // večji element (npr. vrstico matrike) prekopiramo na vrh stacka
+                velikost => [
+                    [Osnovni(ALOC(velikost)), Osnovni(LOFF)].as_slice(),
+                    naslov_elementa(seznam_ref, indeks, št_klicev).as_slice(),
+                    [Osnovni(TOP(-velikost - 2)), Osnovni(LOFF), Osnovni(COPY(velikost)), Osnovni(SOFF)].as_slice(),
+                ].concat(),
+            },
             Dolžina(vozlišče) => match vozlišče.tip() {
                 Tip::Seznam(_, dolžina) => Celo(dolžina).rc().prevedi(št_klicev),
-                Tip::RefSeznama(..) => [
-                    vozlišče.prevedi(št_klicev).as_slice(),
-                    [Osnovni(LDDY(-1))].as_slice(),
-                ].concat(),
+                Tip::RefSeznama(..) | Tip::Niz => dolžina_seznama(vozlišče, št_klicev),
                 _ => unreachable!("Jemanje dolžine nečesa, kar ni seznam"),
             },
 
@@ -88,6 +124,21 @@ impl Vozlišče {
                 d.prevedi(št_klicev).as_slice(),
                 [Osnovni(ADDF)].as_slice(),
             ].concat(),
+            Add(Tip::Celo64, l, d) => [
+                l.prevedi(št_klicev).as_slice(),
+                d.prevedi(št_klicev).as_slice(),
+                [Osnovni(ADDL)].as_slice(),
+            ].concat(),
+            Add(Tip::Real64, l, d) => [
+                l.prevedi(št_klicev).as_slice(),
+                d.prevedi(št_klicev).as_slice(),
+                [Osnovni(ADDD)].as_slice(),
+            ].concat(),
+            Add(Tip::Naravno, l, d) => [
+                l.prevedi(št_klicev).as_slice(),
+                d.prevedi(št_klicev).as_slice(),
+                [Osnovni(ADDI)].as_slice(),
+            ].concat(),
             Add(..) => unreachable!(),
             Sub(Tip::Celo, l, d) => [
                 l.prevedi(št_klicev).as_slice(),
@@ -99,6 +150,21 @@ impl Vozlišče {
                 d.prevedi(št_klicev).as_slice(),
                 [Osnovni(SUBF)].as_slice(),
             ].concat(),
+            Sub(Tip::Celo64, l, d) => [
+                l.prevedi(št_klicev).as_slice(),
+                d.prevedi(št_klicev).as_slice(),
+                [Osnovni(SUBL)].as_slice(),
+            ].concat(),
+            Sub(Tip::Real64, l, d) => [
+                l.prevedi(št_klicev).as_slice(),
+                d.prevedi(št_klicev).as_slice(),
+                [Osnovni(SUBD)].as_slice(),
+            ].concat(),
+            Sub(Tip::Naravno, l, d) => [
+                l.prevedi(št_klicev).as_slice(),
+                d.prevedi(št_klicev).as_slice(),
+                [Osnovni(SUBI)].as_slice(),
+            ].concat(),
             Sub(..) => unreachable!(),
             Mul(Tip::Celo, l, d) => [
                 l.prevedi(št_klicev).as_slice(),
@@ -110,6 +176,21 @@ impl Vozlišče {
                 d.prevedi(št_klicev).as_slice(),
                 [Osnovni(MULF)].as_slice(),
             ].concat(),
+            Mul(Tip::Celo64, l, d) => [
+                l.prevedi(št_klicev).as_slice(),
+                d.prevedi(št_klicev).as_slice(),
+                [Osnovni(MULL)].as_slice(),
+            ].concat(),
+            Mul(Tip::Real64, l, d) => [
+                l.prevedi(št_klicev).as_slice(),
+                d.prevedi(št_klicev).as_slice(),
+                [Osnovni(MULD)].as_slice(),
+            ].concat(),
+            Mul(Tip::Naravno, l, d) => [
+                l.prevedi(št_klicev).as_slice(),
+                d.prevedi(št_klicev).as_slice(),
+                [Osnovni(MULI)].as_slice(),
+            ].concat(),
             Mul(..) => unreachable!(),
             Div(Tip::Celo, l, d) => [
                 l.prevedi(št_klicev).as_slice(),
@@ -121,6 +202,21 @@ impl Vozlišče {
                 d.prevedi(št_klicev).as_slice(),
                 [Osnovni(DIVF)].as_slice(),
             ].concat(),
+            Div(Tip::Celo64, l, d) => [
+                l.prevedi(št_klicev).as_slice(),
+                d.prevedi(št_klicev).as_slice(),
+                [Osnovni(DIVL)].as_slice(),
+            ].concat(),
+            Div(Tip::Real64, l, d) => [
+                l.prevedi(št_klicev).as_slice(),
+                d.prevedi(št_klicev).as_slice(),
+                [Osnovni(DIVD)].as_slice(),
+            ].concat(),
+            Div(Tip::Naravno, l, d) => [
+                l.prevedi(št_klicev).as_slice(),
+                d.prevedi(št_klicev).as_slice(),
+                [Osnovni(DIVU)].as_slice(),
+            ].concat(),
             Div(..) => unreachable!(),
             Mod(Tip::Celo, l, d) => [
                 l.prevedi(št_klicev).as_slice(),
@@ -132,6 +228,21 @@ impl Vozlišče {
                 d.prevedi(št_klicev).as_slice(),
                 [Osnovni(MODF)].as_slice(),
             ].concat(),
+            Mod(Tip::Celo64, l, d) => [
+                l.prevedi(št_klicev).as_slice(),
+                d.prevedi(št_klicev).as_slice(),
+                [Osnovni(MODL)].as_slice(),
+            ].concat(),
+            Mod(Tip::Real64, l, d) => [
+                l.prevedi(št_klicev).as_slice(),
+                d.prevedi(št_klicev).as_slice(),
+                [Osnovni(MODD)].as_slice(),
+            ].concat(),
+            Mod(Tip::Naravno, l, d) => [
+                l.prevedi(št_klicev).as_slice(),
+                d.prevedi(št_klicev).as_slice(),
+                [Osnovni(MODU)].as_slice(),
+            ].concat(),
             Mod(..) => unreachable!(),
             Pow(Tip::Celo, l, d) => [
                 l.prevedi(št_klicev).as_slice(),
@@ -143,6 +254,21 @@ impl Vozlišče {
                 d.prevedi(št_klicev).as_slice(),
                 [Osnovni(POWF)].as_slice(),
             ].concat(),
+            Pow(Tip::Celo64, l, d) => [
+                l.prevedi(št_klicev).as_slice(),
+                d.prevedi(št_klicev).as_slice(),
+                [Osnovni(POWL)].as_slice(),
+            ].concat(),
+            Pow(Tip::Real64, l, d) => [
+                l.prevedi(št_klicev).as_slice(),
+                d.prevedi(št_klicev).as_slice(),
+                [Osnovni(POWD)].as_slice(),
+            ].concat(),
+            Pow(Tip::Naravno, l, d) => [
+                l.prevedi(št_klicev).as_slice(),
+                d.prevedi(št_klicev).as_slice(),
+                [Osnovni(POWI)].as_slice(),
+            ].concat(),
             Pow(..) => unreachable!(),
 
             CeloVReal(vozlišče) => [
@@ -155,6 +281,36 @@ impl Vozlišče {
             ].concat(),
             CeloVZnak(vozlišče) => vozlišče.prevedi(št_klicev),
             ZnakVCelo(vozlišče) => vozlišče.prevedi(št_klicev),
+            NaštevanjeVCelo(vozlišče) => vozlišče.prevedi(št_klicev),
+            CeloVCelo64(vozlišče) => [
+                vozlišče.prevedi(št_klicev).as_slice(),
+                [Osnovni(ITOL)].as_slice(),
+            ].concat(),
+            Celo64VCelo(vozlišče) => [
+                vozlišče.prevedi(št_klicev).as_slice(),
+                [Osnovni(LTOI)].as_slice(),
+            ].concat(),
+            RealVReal64(vozlišče) => [
+                vozlišče.prevedi(št_klicev).as_slice(),
+                [Osnovni(FTOD)].as_slice(),
+            ].concat(),
+            Real64VReal(vozlišče) => [
+                vozlišče.prevedi(št_klicev).as_slice(),
+                [Osnovni(DTOF)].as_slice(),
+            ].concat(),
+            Celo64VReal64(vozlišče) => [
+                vozlišče.prevedi(št_klicev).as_slice(),
+                [Osnovni(LTOD)].as_slice(),
+            ].concat(),
+            Real64VCelo64(vozlišče) => [
+                vozlišče.prevedi(št_klicev).as_slice(),
+                [Osnovni(DTOL)].as_slice(),
+            ].concat(),
+            CeloVNaravno(vozlišče) | NaravnoVCelo(vozlišče) => vozlišče.prevedi(št_klicev),
+            NaravnoVCelo64(vozlišče) => [
+                vozlišče.prevedi(št_klicev).as_slice(),
+                [Osnovni(UTOL)].as_slice(),
+            ].concat(),
 
             Zanikaj(vozlišče) => [
                 [PUSHI(1)].as_slice(),
@@ -196,18 +352,66 @@ impl Vozlišče {
             BitniPremikDesno(l, d) => [
                 l.prevedi(št_klicev).as_slice(),
                 d.prevedi(št_klicev).as_slice(),
-                [Osnovni(BSLR)].as_slice(),
+                [Osnovni(match l.tip() {
+                    Tip::Naravno => BSRU,
+                    _ => BSLR,
+                })].as_slice(),
             ].concat(),
 
+            // primerjamo mesto za mestom, rezultat zmnožimo
+            Enako(tip @ Tip::Seznam(..), l, d) => {
+                let osnovni_tipi = tip.osnovni_tipi();
+                let velikost = osnovni_tipi.len() as i32;
+
+                let primerjaj = osnovni_tipi.iter()
+                    .enumerate()
+                    .flat_map(|(i, tip)| {
+                        let i = i as i32;
+                        let (odštej, nič) = match tip {
+                            Tip::Real => (SUBF, ZERO),
+                            Tip::Celo64 => (SUBL, ZERL),
+                            Tip::Real64 => (SUBD, ZERL),
+                            _ => (SUBI, ZERO),
+                        };
+                        [
+                            Osnovni(LDOF(i)),
+                            Osnovni(LDOF(velikost + i)),
+                            Osnovni(odštej),
+                            Osnovni(nič),
+                            Osnovni(MULI),
+                        ]
+                    })
+                    .collect::<Vec<UkazPodatekRelative>>();
+
+                [
+                    [Osnovni(ALOC(1)), Osnovni(LOFF)].as_slice(),
+                    l.prevedi(št_klicev).as_slice(),
+                    d.prevedi(št_klicev).as_slice(),
+                    [Osnovni(TOP(-2 * velikost)), PUSHI(1)].as_slice(),
+                    primerjaj.as_slice(),
+                    [Osnovni(STOF(-2)), Osnovni(ALOC(-2 * velikost)), Osnovni(SOFF)].as_slice(),
+                ].concat()
+            },
             Enako(tip, l, d) => [
                 Sub(tip.clone(), l.clone(), d.clone()).prevedi(št_klicev).as_slice(),
-                [Osnovni(ZERO)].as_slice(),
+                [Osnovni(match tip {
+                    Tip::Celo64 | Tip::Real64 => ZERL,
+                    _ => ZERO,
+                })].as_slice(),
             ].concat(),
             NiEnako(tip, l, d) => Zanikaj(Enako(tip.clone(), l.clone(), d.clone()).rc()).prevedi(št_klicev),
 
+            Večje(Tip::Naravno, l, d) => [
+                l.prevedi(št_klicev).as_slice(),
+                d.prevedi(št_klicev).as_slice(),
+                [Osnovni(GTU)].as_slice(),
+            ].concat(),
             Večje(tip, l, d) => [
                 Sub(tip.clone(), l.clone(), d.clone()).prevedi(št_klicev).as_slice(),
-                [Osnovni(POS)].as_slice(),
+                [Osnovni(match tip {
+                    Tip::Celo64 | Tip::Real64 => POSL,
+                    _ => POS,
+                })].as_slice(),
             ].concat(),
             Manjše(tip, l, d)      => Večje(tip.clone(), d.clone(), l.clone()).prevedi(št_klicev),
             VečjeEnako(tip, l, d)  => Zanikaj(Manjše(tip.clone(), l.clone(), d.clone()).rc()).prevedi(št_klicev),
@@ -253,7 +457,18 @@ impl Vozlišče {
                     _ => unreachable!("Vedno prirejamo spremenljivki.")
                 };
 
-                let shrani = (naslov..naslov+velikost)
+                // seznam kopiramo neposredno iz ene spremenljivke v drugo
+                if let Spremenljivka { naslov: izvor, z_odmikom: izvor_z_odmikom, .. } = &**izraz {
+                    if velikost > 1 {
+                        return vec![
+                            PUSHREF(*izvor, *izvor_z_odmikom),
+                            PUSHREF(naslov, z_odmikom),
+                            Osnovni(COPY(velikost)),
+                        ];
+                    }
+                }
+
+                let shrani = (naslov..naslov+velikost).rev()
                     .map(|naslov| Osnovni(if z_odmikom { STOF(naslov) } else { STOR(naslov) }))
                     .collect::<Vec<UkazPodatekRelative>>();
 
@@ -265,9 +480,18 @@ impl Vozlišče {
 
             PrirejanjeRef { referenca, indeks, izraz } => {
                 let shrani = match indeks {
+                    // večji element prekopiramo z vrha stacka, odmik med tem obnovimo
+                    Some(indeks) if izraz.sprememba_stacka() > 1 => {
+                        let velikost = izraz.sprememba_stacka();
+                        [
+                            [Osnovni(LOFF), Osnovni(TOP(-velikost - 1)), Osnovni(LOFF), Osnovni(LDOF(velikost)), Osnovni(SOFF)].as_slice(),
+                            naslov_elementa(referenca, indeks, št_klicev).as_slice(),
+                            [Osnovni(COPY(velikost)), Osnovni(ALOC(-velikost - 1))].as_slice(),
+                        ].concat()
+                    },
                     Some(indeks) => [
-                        referenca.prevedi(št_klicev).as_slice(),
-                        indeks.prevedi(št_klicev).as_slice(),
+                        naslov_seznama(referenca, št_klicev).as_slice(),
+                        pomnoži_indeks(referenca, indeks, št_klicev).as_slice(),
                         [STINDEXED].as_slice(),
                     ].concat(),
                     None => [
@@ -358,8 +582,70 @@ impl Vozlišče {
                     [Osnovni(PUTC)].as_slice(),
                 ].concat()
             },
+            NatisniNapako(znak) => [
+                znak.prevedi(št_klicev).as_slice(),
+                [Osnovni(PUTE)].as_slice(),
+            ].concat(),
             Preberi => vec![Osnovni(GETC)],
+            Čas => vec![Osnovni(TIME)],
+            Spi(ms) => [
+                ms.prevedi(št_klicev).as_slice(),
+                [Osnovni(SLEP)].as_slice(),
+            ].concat(),
+            Seme => vec![Osnovni(SEED)],
+            ŠteviloArgumentov => vec![Osnovni(ARGC)],
+            Argument(indeks) => [
+                indeks.prevedi(št_klicev).as_slice(),
+                [Osnovni(ARGV)].as_slice(),
+            ].concat(),
+            Okolje(ime) => [
+                ime.prevedi(št_klicev).as_slice(),
+                [Osnovni(ENVV)].as_slice(),
+            ].concat(),
+            Odpri(pot, način) => [
+                pot.prevedi(št_klicev).as_slice(),
+                način.prevedi(št_klicev).as_slice(),
+                [Osnovni(OPEN)].as_slice(),
+            ].concat(),
+            Zapri(deskriptor) => [
+                deskriptor.prevedi(št_klicev).as_slice(),
+                [Osnovni(CLOS)].as_slice(),
+            ].concat(),
+            PreberiZnak(deskriptor) => [
+                deskriptor.prevedi(št_klicev).as_slice(),
+                [Osnovni(FGTC)].as_slice(),
+            ].concat(),
+            ZapišiZnak(deskriptor, znak) => [
+                deskriptor.prevedi(št_klicev).as_slice(),
+                znak.prevedi(št_klicev).as_slice(),
+                [Osnovni(FPTC)].as_slice(),
+            ].concat(),
+            Alociraj(dolžina) => [
+                dolžina.prevedi(št_klicev).as_slice(),
+                [Osnovni(ALHP)].as_slice(),
+            ].concat(),
+            Matematična(funkcija, argumenti) => [
+                argumenti.iter().flat_map(|a| a.prevedi(št_klicev)).collect::<Vec<_>>().as_slice(),
+                [Osnovni(match funkcija {
+                    Matematika::Koren    => SQRT,
+                    Matematika::Sin      => SINF,
+                    Matematika::Cos      => COSF,
+                    Matematika::Tan      => TANF,
+                    Matematika::Atan2    => ATNF,
+                    Matematika::Exp      => EXPF,
+                    Matematika::Ln       => LOGF,
+                    Matematika::Navzdol  => FLRF,
+                    Matematika::Navzgor  => CEIF,
+                    Matematika::Zaokroži => RNDF,
+                    Matematika::AbsReal  => ABSF,
+                    Matematika::AbsCelo  => ABSI,
+                })].as_slice(),
+            ].concat(),
             Splakni => vec![Osnovni(FLUSH)],
+            Končaj(koda) => [
+                koda.prevedi(št_klicev).as_slice(),
+                [Osnovni(EXIT)].as_slice(),
+            ].concat(),
         }
     }
 
@@ -376,6 +662,54 @@ impl Vozlišče {
 
 }
 
+// indeks pomnožimo z velikostjo elementa (podseznami imajo še mesto za dolžino)
+fn pomnoži_indeks(seznam_ref: &Vozlišče, indeks: &Vozlišče, št_klicev: &HashMap<String, usize>) -> Vec<UkazPodatekRelative> {
+    match seznam_ref.tip().vsebuje_tip().sprememba_stacka() {
+        1 => indeks.prevedi(št_klicev),
+        velikost => [
+            indeks.prevedi(št_klicev).as_slice(),
+            [PUSHI(velikost), Osnovni(MULI)].as_slice(),
+        ].concat(),
+    }
+}
+
+fn naslov_elementa(seznam_ref: &Vozlišče, indeks: &Vozlišče, št_klicev: &HashMap<String, usize>) -> Vec<UkazPodatekRelative> {
+    [
+        naslov_seznama(seznam_ref, št_klicev).as_slice(),
+        pomnoži_indeks(seznam_ref, indeks, št_klicev).as_slice(),
+        [Osnovni(ADDI)].as_slice(),
+    ].concat()
+}
+
+// referenca na seznam je par [naslov, dolžina]
+fn naslov_seznama(seznam_ref: &Vozlišče, št_klicev: &HashMap<String, usize>) -> Vec<UkazPodatekRelative> {
+    match seznam_ref {
+        RefSeznama(seznam) => Referenca(seznam.clone()).prevedi(št_klicev),
+        Spremenljivka { naslov, z_odmikom, .. } =>
+            vec![Osnovni(if *z_odmikom { LDOF(*naslov) } else { LOAD(*naslov) })],
+        _ => [
+            seznam_ref.prevedi(št_klicev).as_slice(),
+            [Osnovni(ALOC(-1))].as_slice(),
+        ].concat(),
+    }
+}
+
+fn dolžina_seznama(seznam_ref: &Vozlišče, št_klicev: &HashMap<String, usize>) -> Vec<UkazPodatekRelative> {
+    match seznam_ref {
+        RefSeznama(seznam) => match seznam.tip() {
+            Tip::Seznam(_, dolžina) => vec![PUSHI(dolžina)],
+            _ => unreachable!("Referenca na seznam mora kazati na seznam"),
+        },
+        Spremenljivka { naslov, z_odmikom, .. } =>
+            vec![Osnovni(if *z_odmikom { LDOF(*naslov + 1) } else { LOAD(*naslov + 1) })],
+        // [naslov, dolžina] -> [dolžina]
+        _ => [
+            seznam_ref.prevedi(št_klicev).as_slice(),
+            [Osnovni(LOFF), Osnovni(TOP(-3)), Osnovni(LDOF(1)), Osnovni(STOF(0)), Osnovni(SOFF), Osnovni(ALOC(-1))].as_slice(),
+        ].concat(),
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -400,13 +734,15 @@ mod test {
         assert_eq!(NaložiOdmik.prevedi(&HashMap::new()), [Osnovni(LOFF)]);
 
         assert_eq!(Niz("šipa".to_string()).prevedi(&HashMap::new()), [
-                   PUSHC('a'),
-                   PUSHC('p'),
-                   PUSHC('i'),
-                   PUSHC('š'),
                    PUSHI(4),
+                   PUSHC('š'),
+                   PUSHC('i'),
+                   PUSHC('p'),
+                   PUSHC('a'),
         ]);
         assert_eq!(Real(-3.14).prevedi(&HashMap::new()), [PUSHF(-3.14)]);
+        assert_eq!(Celo64(1 << 40).prevedi(&HashMap::new()), [PUSHL(1 << 40)]);
+        assert_eq!(Real64(-3.14).prevedi(&HashMap::new()), [PUSHD(-3.14)]);
 
         assert_eq!(Spremenljivka { tip: Tip::Real, ime: "šmir".to_string(), naslov: 55, z_odmikom: true,  spremenljiva: false }.prevedi(&HashMap::new()), [Osnovni(LDOF(55))]);
         assert_eq!(Spremenljivka { tip: Tip::Celo, ime: "šmir".to_string(), naslov: 55, z_odmikom: false, spremenljiva: false }.prevedi(&HashMap::new()), [Osnovni(LOAD(55))]);
@@ -479,6 +815,93 @@ mod test {
                    Osnovni(SUBF),
                    Osnovni(ZERO),
         ]);
+        assert_eq!(Enako(Tip::Celo64, Celo64(13).rc(), CeloVCelo64(Celo(42).rc()).rc()).prevedi(&HashMap::new()), [
+                   PUSHL(13),
+                   PUSHI(42),
+                   Osnovni(ITOL),
+                   Osnovni(SUBL),
+                   Osnovni(ZERL),
+        ]);
+        assert_eq!(Enako(Tip::Seznam(Box::new(Tip::Real), 1), Seznam(vec![Real(1.0).rc()]).rc(), Seznam(vec![Real(2.0).rc()]).rc()).prevedi(&HashMap::new()), [
+                   Osnovni(ALOC(1)),
+                   Osnovni(LOFF),
+                   PUSHI(1),
+                   PUSHF(1.0),
+                   PUSHI(1),
+                   PUSHF(2.0),
+                   Osnovni(TOP(-4)),
+                   PUSHI(1),
+                   Osnovni(LDOF(0)),
+                   Osnovni(LDOF(2)),
+                   Osnovni(SUBI),
+                   Osnovni(ZERO),
+                   Osnovni(MULI),
+                   Osnovni(LDOF(1)),
+                   Osnovni(LDOF(3)),
+                   Osnovni(SUBF),
+                   Osnovni(ZERO),
+                   Osnovni(MULI),
+                   Osnovni(STOF(-2)),
+                   Osnovni(ALOC(-4)),
+                   Osnovni(SOFF),
+        ]);
+        let matrika = Spremenljivka { tip: Tip::Seznam(Box::new(Tip::Seznam(Box::new(Tip::Celo), 3)), 2), ime: "m".to_string(), naslov: 4, z_odmikom: false, spremenljiva: true }.rc();
+        let vrstica = Indeksiraj { seznam_ref: RefSeznama(matrika.clone()).rc(), indeks: Celo(1).rc() }.rc();
+        assert_eq!(Indeksiraj { seznam_ref: RefSeznama(vrstica.clone()).rc(), indeks: Celo(2).rc() }.prevedi(&HashMap::new()), [
+                   PUSHREF(5, false),
+                   PUSHI(1),
+                   PUSHI(4),
+                   Osnovni(MULI),
+                   Osnovni(ADDI),
+                   PUSHI(1),
+                   Osnovni(ADDI),
+                   PUSHI(2),
+                   LDINDEXED,
+        ]);
+        assert_eq!(vrstica.prevedi(&HashMap::new()), [
+                   Osnovni(ALOC(4)),
+                   Osnovni(LOFF),
+                   PUSHREF(5, false),
+                   PUSHI(1),
+                   PUSHI(4),
+                   Osnovni(MULI),
+                   Osnovni(ADDI),
+                   Osnovni(TOP(-6)),
+                   Osnovni(LOFF),
+                   Osnovni(COPY(4)),
+                   Osnovni(SOFF),
+        ]);
+        let seznam = Spremenljivka { tip: Tip::Seznam(Box::new(Tip::Celo), 5), ime: "s".to_string(), naslov: 2, z_odmikom: true, spremenljiva: true }.rc();
+        let rezina = Rezina { seznam_ref: RefSeznama(seznam.clone()).rc(), začetek: Celo(1).rc(), konec: Dolžina(seznam.clone()).rc() }.rc();
+        assert_eq!(rezina.prevedi(&HashMap::new()), [
+                   PUSHREF(3, true),
+                   PUSHI(5),
+                   PUSHI(1),
+                   PUSHI(5),
+                   Osnovni(SLCE(1)),
+        ]);
+        assert_eq!(Dolžina(rezina.clone()).prevedi(&HashMap::new()), [
+                   PUSHREF(3, true),
+                   PUSHI(5),
+                   PUSHI(1),
+                   PUSHI(5),
+                   Osnovni(SLCE(1)),
+                   Osnovni(LOFF),
+                   Osnovni(TOP(-3)),
+                   Osnovni(LDOF(1)),
+                   Osnovni(STOF(0)),
+                   Osnovni(SOFF),
+                   Osnovni(ALOC(-1)),
+        ]);
+        assert_eq!(Alociraj(Celo(8).rc()).prevedi(&HashMap::new()), [
+                   PUSHI(8),
+                   Osnovni(ALHP),
+        ]);
+        assert_eq!(Matematična(Matematika::Atan2, vec![Real(1.0).rc(), Real(2.0).rc()]).prevedi(&HashMap::new()), [
+                   PUSHF(1.0),
+                   PUSHF(2.0),
+                   Osnovni(ATNF),
+        ]);
         assert_eq!(Večje(Tip::Celo, Celo(13).rc(), Celo(42).rc()).prevedi(&HashMap::new()), [
                    PUSHI(13),
                    PUSHI(42),
@@ -20,6 +20,8 @@ impl Program {
                         Tip::Real => format!("PUSH #{:?}\n", unsafe { podatek.f }),
                         Tip::Celo => format!("PUSH #{}\n",   unsafe { podatek.i }),
                         Tip::Znak => format!("PUSH '{}'\n",  unsafe { podatek.c.to_string().escape() }),
+                        Tip::Real64 => format!("PUSH #{:?}D\n", unsafe { podatek.d }),
+                        Tip::Celo64 => format!("PUSH #{}L\n",   unsafe { podatek.l }),
                         _ => unreachable!()
                     }
                 },
@@ -32,6 +34,8 @@ impl Program {
                 STOR(naslov)  => format!("STOR @{naslov}\n"),
                 STOF(naslov)  => format!("STOF +{naslov}\n"),
                 STDY(naslov)  => format!("STDY +{naslov}\n"),
+                COPY(dolžina) => format!("COPY #{dolžina}\n"),
+                SLCE(velikost) => format!("SLCE #{velikost}\n"),
                 TOP(odmik)    => format!("TOP  {}{odmik}\n", if *odmik > 0 { "+" } else { "" }),
                 _             => format!("{ukaz_podatek:?}\n"),
             }
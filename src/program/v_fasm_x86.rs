@@ -38,13 +38,17 @@ enum Instr {
 
     ArOp(ArO, R, Op),
     IDiv(R),
+    UDiv(R),
     Cdq,
+    Cqo,
+    Movsxd(R, R),
 
     Inc(Op),
     Dec(Op),
 
     Cmp(Op, Op),
     Setg(R),
+    Seta(R),
     Sete(R),
     Setne(R),
 
@@ -52,13 +56,16 @@ enum Instr {
     Jne(String),
     Je(String),
     Jl(String),
+    Jg(String),
     Call(String),
     Syscall,
+    RepMovsq,
 
     Fld(Op),
     Fild(Op),
     Fstp(Op),
     Fistp(Op),
+    Fisttp(Op),
     Fadd(Op),
     Fsub(Op),
     Fmul(Op),
@@ -71,6 +78,7 @@ enum Instr {
 enum Op {
     UImm(u32),
     SImm(i32),
+    QImm(u64),
     Reg(R),
     Deref(Size, R, i32)
 }
@@ -110,10 +118,14 @@ impl Display for Instr {
 
             ArOp(ar_op, a, b)  => write!(f, "\t{ar_op} {a}, {b}\n"),
             IDiv(r)      => write!(f, "\tidiv {r}\n"),
+            UDiv(r)      => write!(f, "\tdiv  {r}\n"),
             Cdq         => write!(f, "\tcdq\n"),
+            Cqo         => write!(f, "\tcqo\n"),
+            Movsxd(a, b) => write!(f, "\tmovsxd {a}, {b}\n"),
 
             Cmp(a, b)   => write!(f, "\tcmp  {a}, {b}\n"),
             Setg(r)     => write!(f, "\tsetg {r}\n"),
+            Seta(r)     => write!(f, "\tseta {r}\n"),
             Sete(r)     => write!(f, "\tsete {r}\n"),
             Setne(r)    => write!(f, "\tsetne {r}\n"),
 
@@ -121,13 +133,16 @@ impl Display for Instr {
             Jne(label)  => write!(f, "\tjne  {label}\n"),
             Je(label)   => write!(f, "\tje   {label}\n"),
             Jl(label)   => write!(f, "\tjl   {label}\n"),
+            Jg(label)   => write!(f, "\tjg   {label}\n"),
             Call(label) => write!(f, "\tcall {label}\n"),
             Syscall     => write!(f, "\tsyscall\n"),
+            RepMovsq    => write!(f, "\trep movsq\n"),
 
             Fld(op)     => write!(f, "\tfld  {op}\n"),
             Fild(op)    => write!(f, "\tfild {op}\n"),
             Fstp(op)    => write!(f, "\tfstp {op}\n"),
             Fistp(op)   => write!(f, "\tfistp {op}\n"),
+            Fisttp(op)  => write!(f, "\tfisttp {op}\n"),
             Fadd(op)    => write!(f, "\tfadd {op}\n"),
             Fsub(op)    => write!(f, "\tfsub {op}\n"),
             Fmul(op)    => write!(f, "\tfmul {op}\n"),
@@ -142,6 +157,7 @@ impl Display for Op {
         match self {
             UImm(data)           => write!(f, "0x{data:X}"),
             SImm(data)           => write!(f, "{data}"),
+            QImm(data)           => write!(f, "0x{data:X}"),
             Reg(r)               => write!(f, "{r}"),
             Deref(size, r, off)  => write!(f, "{size} [{r}{}{}]",
                 if *off == 0 { "" } else if *off > 0 { " + " } else { " - " },
@@ -171,6 +187,7 @@ impl Display for super::ArO {
             And  => write!(f, "and "),
             Shl  => write!(f, "shl "),
             Shr  => write!(f, "shr "),
+            Sar  => write!(f, "sar "),
         }
     }
 }
@@ -188,6 +205,7 @@ impl Display for super::R {
             Cl  => write!(f, "cl"),
             Ecx => write!(f, "ecx"),
             Rdx => write!(f, "rdx"),
+            Edx => write!(f, "edx"),
             Rsi => write!(f, "rsi"),
             Rdi => write!(f, "rdi"),
             R8  => write!(f, "r8"),
@@ -219,6 +237,12 @@ impl ToFasmX86 for Vec<UkazPodatekRelative> {
                         push(unsafe { transmute::<f32, u32>(število) }),
                     PUSHC(znak) =>
                         push(unsafe { transmute::<i32, u32>(v_utf8(znak)) }),
+                    PUSHL(število) => vec![
+                        Mov(Reg(Rax), QImm(število as u64)),
+                        Push(Reg(Rax))],
+                    PUSHD(število) => vec![
+                        Mov(Reg(Rax), QImm(število.to_bits())),
+                        Push(Reg(Rax))],
                     PUSHREF(addr, true) => vec![
                             Lea(Rdi, Deref(Qword, R9, -addr * 8)),
                             Push(Reg(Rdi))],
@@ -249,8 +273,8 @@ impl ToFasmX86 for Vec<UkazPodatekRelative> {
                         ArOp(op, Eax, Deref(Dword, r2, -addr2 * 8)),
                         Push(Reg(Rax))],
                     LDST(r1, r2, src, dst) => vec![
-                        Mov(Reg(Eax), Deref(Dword, r1, -src * 8)),
-                        Mov(Deref(Dword, r2, -dst * 8), Reg(Eax))],
+                        Mov(Reg(Rax), Deref(Qword, r1, -src * 8)),
+                        Mov(Deref(Qword, r2, -dst * 8), Reg(Rax))],
 
                     Osnovni(NOOP) =>
                         vec![Nop],
@@ -267,6 +291,25 @@ impl ToFasmX86 for Vec<UkazPodatekRelative> {
                         Mov(Reg(Eax), SImm(0)),
                         Sete(Al),
                         Push(Reg(Rax))],
+                    Osnovni(POSL) => vec![
+                        Pop(Reg(Rax)),
+                        Cmp(Reg(Rax), SImm(0)),
+                        Mov(Reg(Eax), SImm(0)),
+                        Setg(Al),
+                        Push(Reg(Rax))],
+                    Osnovni(GTU) => vec![
+                        Pop(Reg(Rbx)),
+                        Pop(Reg(Rax)),
+                        Cmp(Reg(Eax), Reg(Ebx)),
+                        Mov(Reg(Eax), SImm(0)),
+                        Seta(Al),
+                        Push(Reg(Rax))],
+                    Osnovni(ZERL) => vec![
+                        Pop(Reg(Rax)),
+                        Cmp(Reg(Rax), SImm(0)),
+                        Mov(Reg(Eax), SImm(0)),
+                        Sete(Al),
+                        Push(Reg(Rax))],
 
                     // load
                     Osnovni(LOAD(addr)) => vec![
@@ -291,6 +334,39 @@ impl ToFasmX86 for Vec<UkazPodatekRelative> {
                     Osnovni(STDY(offs)) => vec![
                         Pop(Reg(Rdi)),
                         Pop(Deref(Qword, Rdi, -offs * 8))],
+                    // stack raste navzdol, zato kopiramo od najnižjega naslova naprej
+                    Osnovni(COPY(dolžina)) => vec![
+                        Pop(Reg(Rdi)),
+                        Pop(Reg(Rsi)),
+                        Lea(Rdi, Deref(Qword, Rdi, -(dolžina - 1) * 8)),
+                        Lea(Rsi, Deref(Qword, Rsi, -(dolžina - 1) * 8)),
+                        Mov(Reg(Rcx), SImm(dolžina)),
+                        RepMovsq],
+                    // [naslov, dolžina, začetek, konec] -> [naslov + začetek, konec - začetek]
+                    Osnovni(SLCE(velikost)) => vec![
+                        Pop(Reg(Rcx)),
+                        Pop(Reg(Rbx)),
+                        Pop(Reg(Rdx)),
+                        Pop(Reg(Rax)),
+                        Cmp(Reg(Ebx), SImm(0)),
+                        Jl("_slice_error".to_string()),
+                        Cmp(Reg(Ebx), Reg(Ecx)),
+                        Jg("_slice_error".to_string()),
+                        Cmp(Reg(Ecx), Reg(Edx)),
+                        Jg("_slice_error".to_string()),
+                        ArOp(Sub, Ecx, Reg(Ebx)),
+                        Movsxd(Rbx, Ebx),
+                        ArOp(IMul, Rbx, SImm(velikost * 8)),
+                        ArOp(Sub, Rax, Reg(Rbx)),
+                        Push(Reg(Rax)),
+                        Push(Reg(Rcx))],
+                    // [dolžina] -> [naslov, dolžina]
+                    Osnovni(ALHP) => vec![
+                        Mov(Reg(Rax), Deref(Qword, Rsp, 0)),
+                        Call("_alociraj".to_string()),
+                        Pop(Reg(Rbx)),
+                        Push(Reg(Rax)),
+                        Push(Reg(Rbx))],
                     STINDEXED => vec![
                         Pop(Reg(Rbx)),
                         Pop(Reg(Rax)),
@@ -327,13 +403,43 @@ impl ToFasmX86 for Vec<UkazPodatekRelative> {
                             MODI => Rdx,
                             _ => unreachable!()
                         }))],
-                    Osnovni(POWI) => vec![
+                    Osnovni(op @ (DIVU | MODU)) => vec![
+                        Pop(Reg(Rbx)),
+                        Pop(Reg(Rax)),
+                        ArOp(Xor, Rdx, Reg(Rdx)),
+                        UDiv(Ebx),
+                        Push(Reg(match op {
+                            DIVU => Rax,
+                            MODU => Rdx,
+                            _ => unreachable!()
+                        }))],
+                    Osnovni(op @ (ADDL | SUBL | MULL)) => vec![
+                        Pop(Reg(Rbx)),
+                        Pop(Reg(Rax)),
+                        ArOp(match op {
+                            ADDL => Add,
+                            SUBL => Sub,
+                            MULL => IMul,
+                            _ => unreachable!()
+                        }, Rax, Reg(Rbx)),
+                        Push(Reg(Rax))],
+                    Osnovni(op @ (DIVL | MODL)) => vec![
+                        Pop(Reg(Rbx)),
+                        Pop(Reg(Rax)),
+                        Cqo,
+                        IDiv(Rbx),
+                        Push(Reg(match op {
+                            DIVL => Rax,
+                            MODL => Rdx,
+                            _ => unreachable!()
+                        }))],
+                    Osnovni(POWI | POWL) => vec![
                         Pop(Reg(Rcx)),
                         Pop(Reg(Rbx)),
                         Mov(Reg(Rax), SImm(1)),
                         Call("_powi".to_string()),
                         Push(Reg(Rax))],
-                    Osnovni(op @ (BOR | BXOR | BAND | BSLL | BSLR)) => vec![
+                    Osnovni(op @ (BOR | BXOR | BAND | BSLL | BSLR | BSRU)) => vec![
                         Pop(Reg(Rcx)),
                         Pop(Reg(Rax)),
                         match op {
@@ -341,7 +447,8 @@ impl ToFasmX86 for Vec<UkazPodatekRelative> {
                             BXOR => ArOp(Xor, Eax, Reg(Ecx)),
                             BAND => ArOp(And, Eax, Reg(Ecx)),
                             BSLL => ArOp(Shl, Eax, Reg(Cl)),
-                            BSLR => ArOp(Shr, Eax, Reg(Cl)),
+                            BSLR => ArOp(Sar, Eax, Reg(Cl)),
+                            BSRU => ArOp(Shr, Eax, Reg(Cl)),
                             _ => unreachable!()
                         },
                         Push(Reg(Rax))],
@@ -366,6 +473,26 @@ impl ToFasmX86 for Vec<UkazPodatekRelative> {
                     Osnovni(POWF) => vec![
                         Macro("powf"),],
 
+                    Osnovni(op @ (ADDD | SUBD | MULD | DIVD)) => vec![
+                        Fld(Deref(Qword, Rsp, 8)),
+                        match op {
+                            ADDD => Fadd(Deref(Qword, Rsp, 0)),
+                            SUBD => Fsub(Deref(Qword, Rsp, 0)),
+                            MULD => Fmul(Deref(Qword, Rsp, 0)),
+                            DIVD => Fdiv(Deref(Qword, Rsp, 0)),
+                            _ => unreachable!()
+                        },
+                        Pop(Deref(Qword, Rsp, -8)),
+                        Fstp(Deref(Qword, Rsp, 0))],
+                    Osnovni(MODD) => vec![
+                        Fld(Deref(Qword, Rsp, 0)),
+                        Fld(Deref(Qword, Rsp, 8)),
+                        Fprem,
+                        Pop(Deref(Qword, Rsp, -8)),
+                        Fstp(Deref(Qword, Rsp, 0))],
+                    Osnovni(POWD) => vec![
+                        Macro("powd"),],
+
                     Osnovni(FTOI) => vec![
                         Fld  (Deref(Dword, Rsp, 0)),
                         Fistp(Deref(Dword, Rsp, 0))],
@@ -373,12 +500,98 @@ impl ToFasmX86 for Vec<UkazPodatekRelative> {
                         Fild(Deref(Dword, Rsp, 0)),
                         Fstp(Deref(Dword, Rsp, 0))],
 
+                    Osnovni(ITOL | LTOI) => vec![
+                        Pop(Reg(Rax)),
+                        Movsxd(Rax, Eax),
+                        Push(Reg(Rax))],
+                    Osnovni(UTOL) => vec![
+                        Pop(Reg(Rax)),
+                        Mov(Reg(Eax), Reg(Eax)),
+                        Push(Reg(Rax))],
+
+                    Osnovni(SQRT) => vec![Macro("sqrtf")],
+                    Osnovni(SINF) => vec![Macro("sinf")],
+                    Osnovni(COSF) => vec![Macro("cosf")],
+                    Osnovni(TANF) => vec![Macro("tanf")],
+                    Osnovni(ATNF) => vec![Macro("atan2f")],
+                    Osnovni(EXPF) => vec![Macro("expf")],
+                    Osnovni(LOGF) => vec![Macro("lnf")],
+                    Osnovni(FLRF) => vec![Macro("floorf")],
+                    Osnovni(CEIF) => vec![Macro("ceilf")],
+                    Osnovni(RNDF) => vec![Macro("roundf")],
+                    Osnovni(ABSF) => vec![Macro("absf")],
+                    Osnovni(ABSI) => vec![Macro("absi")],
+                    Osnovni(FTOD) => vec![
+                        Fld (Deref(Dword, Rsp, 0)),
+                        Fstp(Deref(Qword, Rsp, 0))],
+                    Osnovni(DTOF) => vec![
+                        Fld (Deref(Qword, Rsp, 0)),
+                        Fstp(Deref(Dword, Rsp, 0))],
+                    Osnovni(LTOD) => vec![
+                        Fild(Deref(Qword, Rsp, 0)),
+                        Fstp(Deref(Qword, Rsp, 0))],
+                    Osnovni(DTOL) => vec![
+                        Fld   (Deref(Qword, Rsp, 0)),
+                        Fisttp(Deref(Qword, Rsp, 0))],
+
                     Osnovni(PUTC) => vec![
                         Pop(Reg(Rax)),
                         Call("_putc".to_string())],
+                    Osnovni(PUTE) => vec![
+                        Pop(Reg(Rax)),
+                        Call("_pute".to_string())],
                     Osnovni(GETC) => vec![
                         Call("_getc".to_string()),
                         Push(Reg(Rax))],
+                    Osnovni(SEED) => vec![
+                        Call("_seme".to_string()),
+                        Push(Reg(Rax))],
+                    Osnovni(ARGC) => vec![
+                        Call("_argc".to_string()),
+                        Push(Reg(Rax))],
+                    // [indeks] -> [naslov, dolžina]
+                    Osnovni(ARGV) => vec![
+                        Pop(Reg(Rax)),
+                        Movsxd(Rax, Eax),
+                        Call("_argument".to_string()),
+                        Push(Reg(Rax)),
+                        Push(Reg(Rbx))],
+                    // [naslov, dolžina] -> [naslov, dolžina]
+                    Osnovni(ENVV) => vec![
+                        Pop(Reg(Rbx)),
+                        Pop(Reg(Rax)),
+                        Call("_okolje".to_string()),
+                        Push(Reg(Rax)),
+                        Push(Reg(Rbx))],
+                    Osnovni(EXIT) => vec![
+                        Pop(Reg(Rax)),
+                        Jmp("_koncaj".to_string())],
+                    // [naslov, dolžina, način] -> [deskriptor]
+                    Osnovni(OPEN) => vec![
+                        Pop(Reg(Rcx)),
+                        Pop(Reg(Rbx)),
+                        Pop(Reg(Rax)),
+                        Call("_odpri".to_string()),
+                        Push(Reg(Rax))],
+                    Osnovni(CLOS) => vec![
+                        Pop(Reg(Rax)),
+                        Call("_zapri".to_string()),
+                        Push(Reg(Rax))],
+                    Osnovni(FGTC) => vec![
+                        Pop(Reg(Rax)),
+                        Call("_fgetc".to_string()),
+                        Push(Reg(Rax))],
+                    // [deskriptor, znak] -> []
+                    Osnovni(FPTC) => vec![
+                        Pop(Reg(Rax)),
+                        Pop(Reg(Rbx)),
+                        Call("_fputc".to_string())],
+                    Osnovni(TIME) => vec![
+                        Call("_cas".to_string()),
+                        Push(Reg(Rax))],
+                    Osnovni(SLEP) => vec![
+                        Pop(Reg(Rax)),
+                        Call("_spi".to_string())],
                     Osnovni(FLUSH) => vec![
                         Call("_flush".to_string())],
 
@@ -733,7 +946,7 @@ mod testi {
     use std::process::{Command, Stdio};
 
     use super::*;
-    use crate::parser::drevo::{Drevo, Vozlišče};
+    use crate::parser::{drevo::{Drevo, Vozlišče}, napaka::Napake};
     use crate::parser::tip::Tip;
     use Vozlišče::*;
 
@@ -803,6 +1016,7 @@ mod testi {
                 Natisni(Znak('\n').rc()).rc(),
             ]).rc(),
             prostor: 0,
+            opozorila: Napake::new(),
         }
         .v_fasm_x86(0);
 
@@ -822,6 +1036,7 @@ mod testi {
                 Natisni(Preberi.rc()).rc(),
             ]).rc(),
             prostor: 0,
+            opozorila: Napake::new(),
         }
         .v_fasm_x86(0);
 
@@ -850,6 +1065,7 @@ mod testi {
                 št_spr: 11,
             }.rc(),
             prostor: 0,
+            opozorila: Napake::new(),
         }
         .v_fasm_x86(0);
 
@@ -869,6 +1085,7 @@ mod testi {
                 Natisni(RealVCelo(CeloVReal(ZnakVCelo(Znak('3').rc()).rc()).rc()).rc()).rc(),
             ]).rc(),
             prostor: 0,
+            opozorila: Napake::new(),
         }
         .v_fasm_x86(0);
 
@@ -893,6 +1110,7 @@ mod testi {
                 //Natisni(CeloVZnak(RealVCelo(Sub(Tip::Real, Real(128.0).rc(), Pow(Tip::Real, Real(-3.0).rc(), Real(4.0).rc()).rc()).rc()).rc()).rc()).rc(),
             ]).rc(),
             prostor: 0,
+            opozorila: Napake::new(),
         }
         .v_fasm_x86(0);
 
@@ -900,6 +1118,27 @@ mod testi {
         Ok(())
     }
 
+    #[test]
+    fn operacije_64() -> Result<(), io::Error> {
+        let asm = Drevo {
+            funkcije: vec![],
+            št_klicev: HashMap::new(),
+            main: Zaporedje(vec![
+                Natisni(CeloVZnak(Celo64VCelo(Div(Tip::Celo64, Celo64(49 << 33).rc(), Celo64(1 << 33).rc()).rc()).rc()).rc()).rc(),
+                Natisni(CeloVZnak(Celo64VCelo(Mod(Tip::Celo64, Celo64((1 << 40) + 51).rc(), Celo64(1 << 20).rc()).rc()).rc()).rc()).rc(),
+                Natisni(CeloVZnak(Celo64VCelo(Sub(Tip::Celo64, Mul(Tip::Celo64, Celo64(1 << 31).rc(), Celo64(4).rc()).rc(), Celo64((1 << 33) - 52).rc()).rc()).rc()).rc()).rc(),
+                Natisni(CeloVZnak(Celo64VCelo(Real64VCelo64(Mul(Tip::Real64, Real64(26.5).rc(), Real64(2.0).rc()).rc()).rc()).rc()).rc()).rc(),
+                Natisni(CeloVZnak(RealVCelo(Real64VReal(Add(Tip::Real64, RealVReal64(Real(50.0).rc()).rc(), Celo64VReal64(CeloVCelo64(Celo(4).rc()).rc()).rc()).rc()).rc()).rc()).rc()).rc(),
+            ]).rc(),
+            prostor: 0,
+            opozorila: Napake::new(),
+        }
+        .v_fasm_x86(0);
+
+        assert_eq!(test(&asm, "")?, "13456");
+        Ok(())
+    }
+
     #[test]
     fn bitne_operacije() -> Result<(), io::Error> {
         let asm = vec![
@@ -922,6 +1161,21 @@ mod testi {
         Ok(())
     }
 
+    #[test]
+    fn naravno_operacije() -> Result<(), io::Error> {
+        let asm = vec![
+            Oznaka("main".to_string()),
+            PUSHI(-2), PUSHI(1), Osnovni(BSRU), PUSHI(24), Osnovni(BSRU), Osnovni(PUTC),
+            PUSHI(-8), PUSHI(2), Osnovni(BSLR), PUSHI(52), Osnovni(ADDI), Osnovni(PUTC),
+            PUSHI(-1), PUSHI(10), Osnovni(DIVU), PUSHI(10), Osnovni(MODU), PUSHI(48), Osnovni(ADDI), Osnovni(PUTC),
+            PUSHI(-1), PUSHI(1), Osnovni(GTU), PUSHI(48), Osnovni(ADDI), Osnovni(PUTC),
+        ]
+        .v_fasm_x86(0);
+
+        assert_eq!(test(&asm, "")?, "\u{7f}291");
+        Ok(())
+    }
+
     #[test]
     fn jump() -> Result<(), io::Error> {
         let asm = vec![
@@ -1037,6 +1291,144 @@ mod testi {
         Ok(())
     }
 
+    #[test]
+    fn copy() -> Result<(), io::Error> {
+        let asm = vec![
+            Oznaka("main".to_string()),
+            PUSHC('1'), PUSHC('2'), PUSHC('3'),
+            PUSHC('0'), PUSHC('0'), PUSHC('0'),
+
+            PUSHREF(0, false), PUSHREF(3, false), Osnovni(COPY(3)),
+            Osnovni(PUTC), Osnovni(PUTC), Osnovni(PUTC),
+            Osnovni(ALOC(-3)),
+        ]
+        .v_fasm_x86(0);
+
+        assert_eq!(test(&asm, "")?, "321");
+        Ok(())
+    }
+
+    #[test]
+    fn slce() -> Result<(), io::Error> {
+        let asm = vec![
+            Oznaka("main".to_string()),
+            PUSHC('a'), PUSHC('b'), PUSHC('c'), PUSHC('d'),
+
+            PUSHREF(0, false), PUSHI(4), PUSHI(1), PUSHI(3), Osnovni(SLCE(1)),
+            PUSHC('0'), Osnovni(ADDI), Osnovni(PUTC),
+            Osnovni(LDDY(0)), Osnovni(PUTC),
+            Osnovni(ALOC(-4)),
+        ]
+        .v_fasm_x86(0);
+
+        assert_eq!(test(&asm, "")?, "2b");
+        Ok(())
+    }
+
+    #[test]
+    fn alhp() -> Result<(), io::Error> {
+        let asm = vec![
+            Oznaka("main".to_string()),
+            PUSHI(1), Osnovni(ALHP),
+            PUSHC('0'), Osnovni(LOAD(1)), Osnovni(ADDI), Osnovni(PUTC),
+            PUSHC('x'), Osnovni(LOAD(0)), Osnovni(STDY(0)),
+            Osnovni(LOAD(0)), Osnovni(LDDY(0)), Osnovni(PUTC),
+            Osnovni(ALOC(-2)),
+        ]
+        .v_fasm_x86(0);
+
+        assert_eq!(test(&asm, "")?, "1x");
+        Ok(())
+    }
+
+    #[test]
+    fn exit() {
+        let asm = vec![
+            Oznaka("main".to_string()),
+            PUSHI(1), PUSHI(7), Osnovni(EXIT),
+            PUSHC('b'), Osnovni(PUTC),
+        ]
+        .v_fasm_x86(0);
+
+        assert_eq!(test(&asm, "").unwrap_err().to_string(), "exit code: 7");
+    }
+
+    #[test]
+    fn argumenti() -> Result<(), io::Error> {
+        let asm = vec![
+            Oznaka("main".to_string()),
+            Osnovni(ARGC), PUSHC('0'), Osnovni(ADDI), Osnovni(PUTC),
+            PUSHI(5), Osnovni(ARGV), PUSHC('2'), Osnovni(ADDI), Osnovni(PUTC), Osnovni(ALOC(-1)),
+            PUSHI(0), Osnovni(ARGV), Osnovni(POS), PUSHC('0'), Osnovni(ADDI), Osnovni(PUTC), Osnovni(ALOC(-1)),
+        ]
+        .v_fasm_x86(0);
+
+        assert_eq!(test(&asm, "")?, "111");
+        Ok(())
+    }
+
+    #[test]
+    fn cas() -> Result<(), io::Error> {
+        let asm = vec![
+            Oznaka("main".to_string()),
+            Osnovni(TIME),
+            PUSHI(20), Osnovni(SLEP),
+            // začetek - konec + 20 ms ni pozitivno, če je program spal dovolj dolgo
+            Osnovni(TIME), Osnovni(SUBL),
+            PUSHL(20_000_000), Osnovni(ADDL), Osnovni(POS), PUSHC('0'), Osnovni(ADDI), Osnovni(PUTC),
+        ]
+        .v_fasm_x86(0);
+
+        assert_eq!(test(&asm, "")?, "0");
+        Ok(())
+    }
+
+    #[test]
+    fn pute() -> Result<(), io::Error> {
+        let asm = vec![
+            Oznaka("main".to_string()),
+            PUSHC('a'), Osnovni(PUTE),
+            PUSHC('b'), Osnovni(PUTC),
+        ]
+        .v_fasm_x86(0);
+
+        // standardni izhod za napake se ne meša z izhodom
+        assert_eq!(test(&asm, "")?, "b");
+        Ok(())
+    }
+
+    #[test]
+    fn datoteke() -> Result<(), io::Error> {
+        let asm = vec![
+            Oznaka("main".to_string()),
+            // deskriptor 0 je standardni vhod
+            PUSHI(0), Osnovni(FGTC), Osnovni(PUTC),
+            PUSHI(-1), Osnovni(CLOS), PUSHC('1'), Osnovni(ADDI), Osnovni(PUTC),
+            PUSHI(1), PUSHC('x'), Osnovni(FPTC),
+        ]
+        .v_fasm_x86(0);
+
+        assert_eq!(test(&asm, "č")?, "č0");
+        Ok(())
+    }
+
+    #[test]
+    fn matematika() -> Result<(), io::Error> {
+        let asm = vec![
+            Oznaka("main".to_string()),
+            PUSHF(9.0), Osnovni(SQRT), Osnovni(FTOI), PUSHC('0'), Osnovni(ADDI), Osnovni(PUTC),
+            PUSHF(-2.5), Osnovni(FLRF), Osnovni(ABSF), Osnovni(FTOI), PUSHC('0'), Osnovni(ADDI), Osnovni(PUTC),
+            PUSHF(-2.5), Osnovni(RNDF), Osnovni(FTOI), Osnovni(ABSI), PUSHC('0'), Osnovni(ADDI), Osnovni(PUTC),
+            PUSHF(1.0), PUSHF(0.0), Osnovni(ATNF), PUSHF(2.0), Osnovni(MULF),
+            PUSHF(3.1415927), Osnovni(SUBF), Osnovni(ABSF), PUSHF(0.001), Osnovni(SUBF), Osnovni(FTOI),
+            PUSHC('0'), Osnovni(ADDI), Osnovni(PUTC),
+        ]
+        .v_fasm_x86(0);
+
+        assert_eq!(test(&asm, "")?, "3330");
+        Ok(())
+    }
+
     #[test]
     fn loff_soff() -> Result<(), io::Error> {
         let asm = vec![
@@ -1,46 +1,163 @@
-use std::{str, io::{BufReader, BufWriter}};
+use std::{str, env, fs::{File, OpenOptions}, io::{BufReader, BufWriter, Write}};
+use std::{collections::hash_map::RandomState, hash::{BuildHasher, Hasher}};
+use std::{thread, time::{Duration, Instant}};
 
 use unsafe_unwrap::UnsafeUnwrap;
 
 use super::*;
 
+// programski števec po ukazu EXIT, izhodna koda ostane na vrhu stacka
+const KONČAJ: i32 = -1;
+// programski števec po napaki pri izvajanju, izhodna koda 1 ostane na vrhu stacka
+const NAPAKA: i32 = -2;
+
+// prvi deskriptor datoteke, manjši so rezervirani za standardni vhod in izhod
+const PRVA_DATOTEKA: i32 = 3;
+
+enum Datoteka {
+    Branje(BufReader<File>),
+    Pisanje(BufWriter<File>),
+}
+
+// ura gostitelja, v testih jo nadomestimo z namišljeno
+trait Ura {
+    // nanosekunde od poljubne (a nespremenljive) točke v preteklosti
+    fn zdaj(&self) -> i64;
+    fn spi(&mut self, ms: i32);
+}
+
+struct SistemskaUra(Instant);
+
+impl Ura for SistemskaUra {
+    fn zdaj(&self) -> i64 {
+        self.0.elapsed().as_nanos() as i64
+    }
+
+    fn spi(&mut self, ms: i32) {
+        if ms > 0 {
+            thread::sleep(Duration::from_millis(ms as u64));
+        }
+    }
+}
+
+// kar program dobi od gostitelja: argumente, tabelo odprtih datotek in uro
+struct Gostitelj<'a> {
+    argumenti: &'a [String],
+    datoteke: Vec<Option<Datoteka>>,
+    ura: Box<dyn Ura>,
+}
+
+impl Default for Gostitelj<'_> {
+    fn default() -> Self {
+        Gostitelj {
+            argumenti: &[],
+            datoteke: Vec::new(),
+            ura: Box::new(SistemskaUra(Instant::now())),
+        }
+    }
+}
+
+impl Gostitelj<'_> {
+    fn odpri(&mut self, pot: &str, način: i32) -> i32 {
+        let datoteka = match način {
+            0 => File::open(pot).map(|d| Datoteka::Branje(BufReader::new(d))),
+            1 => File::create(pot).map(|d| Datoteka::Pisanje(BufWriter::new(d))),
+            2 => OpenOptions::new().append(true).create(true).open(pot).map(|d| Datoteka::Pisanje(BufWriter::new(d))),
+            _ => return -1,
+        };
+        match datoteka {
+            Ok(datoteka) => {
+                self.datoteke.push(Some(datoteka));
+                PRVA_DATOTEKA + self.datoteke.len() as i32 - 1
+            },
+            Err(_) => -1,
+        }
+    }
+
+    fn datoteka(&mut self, deskriptor: i32) -> Option<&mut Datoteka> {
+        let indeks = usize::try_from(deskriptor - PRVA_DATOTEKA).ok()?;
+        self.datoteke.get_mut(indeks)?.as_mut()
+    }
+
+    fn zapri(&mut self, deskriptor: i32) -> i32 {
+        let datoteka = usize::try_from(deskriptor - PRVA_DATOTEKA).ok()
+            .and_then(|indeks| self.datoteke.get_mut(indeks))
+            .and_then(Option::take);
+        match datoteka {
+            Some(Datoteka::Pisanje(mut pisanje)) => pisanje.flush().map_or(-1, |_| 0),
+            Some(Datoteka::Branje(_)) => 0,
+            None => -1,
+        }
+    }
+
+    // deskriptor 0 je standardni vhod, na koncu ali ob napaki je prebran '\0'
+    fn preberi(&mut self, deskriptor: i32, vhod: &mut impl io::Read) -> char {
+        match deskriptor {
+            0 => preberi_znak(vhod),
+            _ => match self.datoteka(deskriptor) {
+                Some(Datoteka::Branje(branje)) => preberi_znak(branje),
+                _ => None,
+            },
+        }.unwrap_or('\0')
+    }
+
+    fn zapiši(&mut self, deskriptor: i32, znak: char) {
+        if let Some(Datoteka::Pisanje(pisanje)) = self.datoteka(deskriptor) {
+            let _ = pisanje.write_all(znak.encode_utf8(&mut [0; 4]).as_bytes());
+        }
+    }
+}
+
 impl Program {
-    pub fn zaženi(&self) {
-        self.zaženi_z_io(&mut BufReader::new(io::stdin()), &mut BufWriter::new(io::stdout()));
+    pub fn zaženi(&self) -> i32 {
+        self.zaženi_z_io(&mut BufReader::new(io::stdin()), &mut BufWriter::new(io::stdout()), &mut io::stderr())
     }
 
-    pub fn zaženi_debug(&self) {
+    pub fn zaženi_debug(&self) -> i32 {
         let mut pc: i32 = 0;
         let mut addroff: i32 = 0;
+        let mut kopica: Vec<Podatek> = Vec::new();
         let mut stack: Vec<Podatek> = Vec::with_capacity(32_768);
+        let mut gostitelj = Gostitelj { argumenti: &self.argumenti, ..Default::default() };
 
         while (pc as usize) < self.ukazi.len() {
             let ukaz = &self.ukazi[pc as usize];
 
             print!("{addroff}, {pc}, {ukaz:?}: ");
-            match Program::korak_debug(ukaz, &mut stack, &mut pc, &mut addroff, &mut io::stdin(), &mut io::stdout()) {
+            match Program::korak_debug(ukaz, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut io::stdin(), &mut io::stdout(), &mut io::stderr(), &mut gostitelj) {
                 Some(_) => (),
                 None => panic!("Napaka v ukazu #{pc}: {:?}", ukaz),
             }
             println!("{stack:?}");
         }
+        if pc == KONČAJ || pc == NAPAKA {
+            return unsafe { stack.last().unsafe_unwrap().i };
+        }
         assert!(stack.len() == 0, "Neprazen stack ob izhodu pomeni nepravilno izvajanje.");
+        0
     }
 
-    pub fn zaženi_z_io(&self, vhod: &mut impl io::Read, izhod: &mut impl io::Write) {
+    pub fn zaženi_z_io(&self, vhod: &mut impl io::Read, izhod: &mut impl io::Write, napake: &mut impl io::Write) -> i32 {
         let mut pc: i32 = 0;
         let mut addroff: i32 = 0;
+        let mut kopica: Vec<Podatek> = Vec::new();
         let mut stack: Vec<Podatek> = Vec::with_capacity(32_768);
+        let mut gostitelj = Gostitelj { argumenti: &self.argumenti, ..Default::default() };
 
         while (pc as usize) < self.ukazi.len() {
-            Program::korak(&self.ukazi[pc as usize], &mut stack, &mut pc, &mut addroff, vhod, izhod);
+            Program::korak(&self.ukazi[pc as usize], &mut stack, &mut kopica, &mut pc, &mut addroff, vhod, izhod, napake, &mut gostitelj);
         }
-        assert!(stack.len() == 0, "Neprazen stack ob izhodu pomeni nepravilno izvajanje.");
         let _ = izhod.flush();
+        if pc == KONČAJ || pc == NAPAKA {
+            return unsafe { stack.last().unsafe_unwrap().i };
+        }
+        assert!(stack.len() == 0, "Neprazen stack ob izhodu pomeni nepravilno izvajanje.");
+        0
     }
 
     #[inline]
-    fn korak(ukaz_podatek: &UkazPodatek, stack: &mut Vec<Podatek>, pc: &mut i32, addroff: &mut i32, vhod: &mut impl io::Read, izhod: &mut impl io::Write) {
+    #[allow(clippy::too_many_arguments)]
+    fn korak(ukaz_podatek: &UkazPodatek, stack: &mut Vec<Podatek>, kopica: &mut Vec<Podatek>, pc: &mut i32, addroff: &mut i32, vhod: &mut impl io::Read, izhod: &mut impl io::Write, napake: &mut impl io::Write, gostitelj: &mut Gostitelj) {
         *pc = unsafe {
             match ukaz_podatek {
                 NOOP => *pc + 1,
@@ -59,17 +176,45 @@ impl Program {
                 LDOF(naslov) => { stack.push(*stack.get((*addroff + *naslov) as usize).unsafe_unwrap()); *pc + 1 },
                 LDDY(naslov) => {
                     let dynaddr = stack.last().unsafe_unwrap().i;
-                    stack.last_mut().unsafe_unwrap().i = stack.get((*naslov + dynaddr) as usize).unsafe_unwrap().i;
+                    *stack.last_mut().unsafe_unwrap() = *celica(stack, kopica, *naslov + dynaddr).unsafe_unwrap();
                     *pc + 1
                 },
 
                 STOR(naslov) => { *stack.get_mut(*naslov as usize).unsafe_unwrap() = stack.pop().unsafe_unwrap(); *pc + 1 },
-                STOF(naslov) => { *stack.get_mut(*addroff as usize + *naslov as usize).unsafe_unwrap() = stack.pop().unsafe_unwrap(); *pc + 1 },
+                STOF(naslov) => { *stack.get_mut((*addroff + *naslov) as usize).unsafe_unwrap() = stack.pop().unsafe_unwrap(); *pc + 1 },
                 STDY(naslov) => {
                     let dynaddr = stack.pop().unsafe_unwrap().i;
-                    *stack.get_mut((*naslov + dynaddr) as usize).unsafe_unwrap() = stack.pop().unsafe_unwrap();
+                    let podatek = stack.pop().unsafe_unwrap();
+                    *celica(stack, kopica, *naslov + dynaddr).unsafe_unwrap() = podatek;
                     *pc + 1
                 }
+                COPY(dolžina) => {
+                    let kam = stack.pop().unsafe_unwrap().i;
+                    let od = stack.pop().unsafe_unwrap().i;
+                    kopiraj(stack, kopica, od, kam, *dolžina).unsafe_unwrap();
+                    *pc + 1
+                },
+                ALHP => {
+                    let dolžina = stack.last().unsafe_unwrap().i;
+                    if kopica.len() + dolžina as usize > VELIKOST_KOPICE {
+                        return končaj_z_napako(stack, napake, pc, POLNA_KOPICA);
+                    }
+                    *stack.last_mut().unsafe_unwrap() = Podatek { i: KOPICA + kopica.len() as i32 };
+                    kopica.resize(kopica.len() + dolžina as usize, NIČ);
+                    stack.push(Podatek { i: dolžina });
+                    *pc + 1
+                },
+                SLCE(velikost) => {
+                    let konec = stack.pop().unsafe_unwrap().i;
+                    let začetek = stack.pop().unsafe_unwrap().i;
+                    let dolžina = stack.pop().unsafe_unwrap().i;
+                    if začetek < 0 || začetek > konec || konec > dolžina {
+                        return končaj_z_napako(stack, napake, pc, IZVEN_MEJA);
+                    }
+                    stack.last_mut().unsafe_unwrap().i += začetek * velikost;
+                    stack.push(Podatek { i: konec - začetek });
+                    *pc + 1
+                },
 
                 TOP(naslov) => { *addroff = stack.len() as i32 + naslov; *pc + 1 },
 
@@ -84,15 +229,75 @@ impl Program {
                     }
                     *pc + 1
                 },
+                PUTE => {
+                    let c = stack.pop().unsafe_unwrap().c;
+                    write!(napake, "{c}").unwrap();
+                    *pc + 1
+                },
                 GETC => {
                     let c = preberi_znak(vhod).unwrap_or('\0');
                     stack.push(Podatek { c });
                     *pc + 1
                 }
+                SEED => {
+                    stack.push(Podatek { i: seme() });
+                    *pc + 1
+                },
+                ARGC => {
+                    stack.push(Podatek { i: gostitelj.argumenti.len() as i32 });
+                    *pc + 1
+                },
+                ARGV => {
+                    let indeks = stack.pop().unsafe_unwrap().i;
+                    let argument = usize::try_from(indeks).ok().and_then(|indeks| gostitelj.argumenti.get(indeks));
+                    if !nov_niz(stack, kopica, argument.map(String::as_str)) {
+                        return končaj_z_napako(stack, napake, pc, POLNA_KOPICA);
+                    }
+                    *pc + 1
+                },
+                ENVV => {
+                    let ime = preberi_niz(stack, kopica).unsafe_unwrap();
+                    if !nov_niz(stack, kopica, env::var(ime).ok().as_deref()) {
+                        return končaj_z_napako(stack, napake, pc, POLNA_KOPICA);
+                    }
+                    *pc + 1
+                },
+                OPEN => {
+                    let način = stack.pop().unsafe_unwrap().i;
+                    let pot = preberi_niz(stack, kopica).unsafe_unwrap();
+                    stack.push(Podatek { i: gostitelj.odpri(&pot, način) });
+                    *pc + 1
+                },
+                CLOS => {
+                    let deskriptor = stack.pop().unsafe_unwrap().i;
+                    stack.push(Podatek { i: gostitelj.zapri(deskriptor) });
+                    *pc + 1
+                },
+                FGTC => {
+                    let deskriptor = stack.pop().unsafe_unwrap().i;
+                    stack.push(Podatek { c: gostitelj.preberi(deskriptor, vhod) });
+                    *pc + 1
+                },
+                FPTC => {
+                    let znak = stack.pop().unsafe_unwrap().c;
+                    let deskriptor = stack.pop().unsafe_unwrap().i;
+                    gostitelj.zapiši(deskriptor, znak);
+                    *pc + 1
+                },
                 FLUSH => {
                     izhod.flush().unsafe_unwrap();
                     *pc + 1
                 },
+                EXIT => KONČAJ,
+                TIME => {
+                    stack.push(Podatek { l: gostitelj.ura.zdaj() });
+                    *pc + 1
+                },
+                SLEP => {
+                    let ms = stack.pop().unsafe_unwrap().i;
+                    gostitelj.ura.spi(ms);
+                    *pc + 1
+                },
 
                 ADDF => { stack.last_mut().unsafe_unwrap().f = stack.get(stack.len() - 2).unsafe_unwrap().f    + stack.pop().unsafe_unwrap().f;  *pc + 1 },
                 SUBF => { stack.last_mut().unsafe_unwrap().f = stack.get(stack.len() - 2).unsafe_unwrap().f    - stack.pop().unsafe_unwrap().f;  *pc + 1 },
@@ -117,12 +322,56 @@ impl Program {
 
                 FTOI => { stack.last_mut().unsafe_unwrap().i = stack.last().unsafe_unwrap().f as i32; *pc + 1 },
                 ITOF => { stack.last_mut().unsafe_unwrap().f = stack.last().unsafe_unwrap().i as f32; *pc + 1 },
+
+                ADDL => { stack.last_mut().unsafe_unwrap().l = stack.get(stack.len() - 2).unsafe_unwrap().l.wrapping_add(stack.pop().unsafe_unwrap().l);        *pc + 1 },
+                SUBL => { stack.last_mut().unsafe_unwrap().l = stack.get(stack.len() - 2).unsafe_unwrap().l.wrapping_sub(stack.pop().unsafe_unwrap().l);        *pc + 1 },
+                MULL => { stack.last_mut().unsafe_unwrap().l = stack.get(stack.len() - 2).unsafe_unwrap().l.wrapping_mul(stack.pop().unsafe_unwrap().l);        *pc + 1 },
+                DIVL => { stack.last_mut().unsafe_unwrap().l = stack.get(stack.len() - 2).unsafe_unwrap().l.wrapping_div(stack.pop().unsafe_unwrap().l);        *pc + 1 },
+                MODL => { stack.last_mut().unsafe_unwrap().l = stack.get(stack.len() - 2).unsafe_unwrap().l.wrapping_rem(stack.pop().unsafe_unwrap().l);        *pc + 1 },
+                POWL => { stack.last_mut().unsafe_unwrap().l = stack.get(stack.len() - 2).unsafe_unwrap().l.wrapping_pow(stack.pop().unsafe_unwrap().l as u32); *pc + 1 },
+
+                ADDD => { stack.last_mut().unsafe_unwrap().d = stack.get(stack.len() - 2).unsafe_unwrap().d    + stack.pop().unsafe_unwrap().d;  *pc + 1 },
+                SUBD => { stack.last_mut().unsafe_unwrap().d = stack.get(stack.len() - 2).unsafe_unwrap().d    - stack.pop().unsafe_unwrap().d;  *pc + 1 },
+                MULD => { stack.last_mut().unsafe_unwrap().d = stack.get(stack.len() - 2).unsafe_unwrap().d    * stack.pop().unsafe_unwrap().d;  *pc + 1 },
+                DIVD => { stack.last_mut().unsafe_unwrap().d = stack.get(stack.len() - 2).unsafe_unwrap().d    / stack.pop().unsafe_unwrap().d;  *pc + 1 },
+                MODD => { stack.last_mut().unsafe_unwrap().d = stack.get(stack.len() - 2).unsafe_unwrap().d    % stack.pop().unsafe_unwrap().d;  *pc + 1 },
+                POWD => { stack.last_mut().unsafe_unwrap().d = stack.get(stack.len() - 2).unsafe_unwrap().d.powf(stack.pop().unsafe_unwrap().d); *pc + 1 },
+
+                POSL => { *stack.last_mut().unsafe_unwrap() = if stack.last().unsafe_unwrap().l  > 0 { RESNICA } else { LAŽ }; *pc + 1 },
+                ZERL => { *stack.last_mut().unsafe_unwrap() = if stack.last().unsafe_unwrap().l == 0 { RESNICA } else { LAŽ }; *pc + 1 },
+
+                ITOL => { stack.last_mut().unsafe_unwrap().l = stack.last().unsafe_unwrap().i as i64; *pc + 1 },
+                LTOI => { stack.last_mut().unsafe_unwrap().i = stack.last().unsafe_unwrap().l as i32; *pc + 1 },
+                FTOD => { stack.last_mut().unsafe_unwrap().d = stack.last().unsafe_unwrap().f as f64; *pc + 1 },
+                DTOF => { stack.last_mut().unsafe_unwrap().f = stack.last().unsafe_unwrap().d as f32; *pc + 1 },
+                LTOD => { stack.last_mut().unsafe_unwrap().d = stack.last().unsafe_unwrap().l as f64; *pc + 1 },
+                DTOL => { stack.last_mut().unsafe_unwrap().l = stack.last().unsafe_unwrap().d as i64; *pc + 1 },
+
+                DIVU => { stack.last_mut().unsafe_unwrap().i = (stack.get(stack.len() - 2).unsafe_unwrap().i as u32 / stack.pop().unsafe_unwrap().i as u32) as i32;  *pc + 1 },
+                MODU => { stack.last_mut().unsafe_unwrap().i = (stack.get(stack.len() - 2).unsafe_unwrap().i as u32 % stack.pop().unsafe_unwrap().i as u32) as i32;  *pc + 1 },
+                GTU  => { *stack.last_mut().unsafe_unwrap() = if stack.get(stack.len() - 2).unsafe_unwrap().i as u32 > stack.pop().unsafe_unwrap().i as u32 { RESNICA } else { LAŽ }; *pc + 1 },
+                BSRU => { stack.last_mut().unsafe_unwrap().i = (stack.get(stack.len() - 2).unsafe_unwrap().i as u32 >> stack.pop().unsafe_unwrap().i) as i32;  *pc + 1 },
+                UTOL => { stack.last_mut().unsafe_unwrap().l = stack.last().unsafe_unwrap().i as u32 as i64; *pc + 1 },
+
+                SQRT => { stack.last_mut().unsafe_unwrap().f = stack.last().unsafe_unwrap().f.sqrt(); *pc + 1 },
+                SINF => { stack.last_mut().unsafe_unwrap().f = stack.last().unsafe_unwrap().f.sin(); *pc + 1 },
+                COSF => { stack.last_mut().unsafe_unwrap().f = stack.last().unsafe_unwrap().f.cos(); *pc + 1 },
+                TANF => { stack.last_mut().unsafe_unwrap().f = stack.last().unsafe_unwrap().f.tan(); *pc + 1 },
+                ATNF => { stack.last_mut().unsafe_unwrap().f = stack.get(stack.len() - 2).unsafe_unwrap().f.atan2(stack.pop().unsafe_unwrap().f); *pc + 1 },
+                EXPF => { stack.last_mut().unsafe_unwrap().f = stack.last().unsafe_unwrap().f.exp(); *pc + 1 },
+                LOGF => { stack.last_mut().unsafe_unwrap().f = stack.last().unsafe_unwrap().f.ln(); *pc + 1 },
+                FLRF => { stack.last_mut().unsafe_unwrap().f = stack.last().unsafe_unwrap().f.floor(); *pc + 1 },
+                CEIF => { stack.last_mut().unsafe_unwrap().f = stack.last().unsafe_unwrap().f.ceil(); *pc + 1 },
+                RNDF => { stack.last_mut().unsafe_unwrap().f = stack.last().unsafe_unwrap().f.round(); *pc + 1 },
+                ABSF => { stack.last_mut().unsafe_unwrap().f = stack.last().unsafe_unwrap().f.abs(); *pc + 1 },
+                ABSI => { stack.last_mut().unsafe_unwrap().i = stack.last().unsafe_unwrap().i.wrapping_abs(); *pc + 1 },
             }
         };
     }
 
     #[inline]
-    fn korak_debug(ukaz_podatek: &UkazPodatek, stack: &mut Vec<Podatek>, pc: &mut i32, addroff: &mut i32, vhod: &mut impl io::Read, izhod: &mut impl io::Write) -> Option<()> {
+    #[allow(clippy::too_many_arguments)]
+    fn korak_debug(ukaz_podatek: &UkazPodatek, stack: &mut Vec<Podatek>, kopica: &mut Vec<Podatek>, pc: &mut i32, addroff: &mut i32, vhod: &mut impl io::Read, izhod: &mut impl io::Write, napake: &mut impl io::Write, gostitelj: &mut Gostitelj) -> Option<()> {
         *pc = unsafe {
             match ukaz_podatek {
                 NOOP => *pc + 1,
@@ -131,29 +380,62 @@ impl Program {
                 JMPC(naslov) => if stack.pop()? != LAŽ { *naslov } else { *pc + 1 },
                 JMPD => stack.pop()?.i,
 
-                ALOC(razlika) => { stack.resize((stack.len() as i32 + razlika) as usize, LAŽ); *pc + 1 }
+                ALOC(razlika) => { stack.resize((stack.len() as i32 + razlika) as usize, NIČ); *pc + 1 }
                 PUSH(podatek) => { stack.push(*podatek); *pc + 1 },
 
                 LOAD(naslov) => { stack.push(*stack.get(*naslov as usize)?); *pc + 1 },
                 LDOF(naslov) => { stack.push(*stack.get(*addroff as usize + *naslov as usize)?); *pc + 1 },
                 LDDY(naslov) => {
                     let dynaddr = stack.last()?.i;
-                    stack.last_mut()?.i = stack.get((*naslov + dynaddr) as usize)?.i;
+                    *stack.last_mut()? = *celica(stack, kopica, *naslov + dynaddr)?;
                     *pc + 1
                 },
 
                 STOR(naslov) => { *stack.get_mut(*naslov as usize)? = stack.pop()?; *pc + 1 },
-                STOF(naslov) => { *stack.get_mut(*addroff as usize + *naslov as usize)? = stack.pop()?; *pc + 1 },
+                STOF(naslov) => { *stack.get_mut((*addroff + *naslov) as usize)? = stack.pop()?; *pc + 1 },
                 STDY(naslov) => {
                     let dynaddr = stack.pop()?.i;
-                    *stack.get_mut(*naslov as usize + dynaddr as usize)? = stack.pop()?;
+                    let podatek = stack.pop()?;
+                    *celica(stack, kopica, *naslov + dynaddr)? = podatek;
                     *pc + 1
                 }
+                COPY(dolžina) => {
+                    let kam = stack.pop()?.i;
+                    let od = stack.pop()?.i;
+                    kopiraj(stack, kopica, od, kam, *dolžina)?;
+                    *pc + 1
+                },
+                ALHP => {
+                    let dolžina = stack.last()?.i;
+                    if dolžina < 0 {
+                        return None;
+                    }
+                    if kopica.len() + dolžina as usize > VELIKOST_KOPICE {
+                        končaj_z_napako(stack, napake, pc, POLNA_KOPICA);
+                        return Some(());
+                    }
+                    *stack.last_mut()? = Podatek { i: KOPICA + kopica.len() as i32 };
+                    kopica.resize(kopica.len() + dolžina as usize, NIČ);
+                    stack.push(Podatek { i: dolžina });
+                    *pc + 1
+                },
+                SLCE(velikost) => {
+                    let konec = stack.pop()?.i;
+                    let začetek = stack.pop()?.i;
+                    let dolžina = stack.pop()?.i;
+                    if začetek < 0 || začetek > konec || konec > dolžina {
+                        končaj_z_napako(stack, napake, pc, IZVEN_MEJA);
+                        return Some(());
+                    }
+                    stack.last_mut()?.i += začetek * velikost;
+                    stack.push(Podatek { i: konec - začetek });
+                    *pc + 1
+                },
 
                 TOP(naslov) => { *addroff = stack.len() as i32 + naslov; *pc + 1 },
 
-                POS  => { *stack.last_mut()? = if stack.last()?.f  > 0.0 { RESNICA } else { LAŽ }; *pc + 1 },
-                ZERO => { *stack.last_mut()? = if stack.last()?.f == 0.0 { RESNICA } else { LAŽ }; *pc + 1 },
+                POS  => { *stack.last_mut()? = if stack.last()?.i  > 0 { RESNICA } else { LAŽ }; *pc + 1 },
+                ZERO => { *stack.last_mut()? = if stack.last()?.i == 0 { RESNICA } else { LAŽ }; *pc + 1 },
 
                 SOFF => { *addroff = stack.pop()?.i;   *pc + 1 },
                 LOFF => { stack.push(Podatek { i: *addroff as i32 }); *pc + 1 },
@@ -166,15 +448,77 @@ impl Program {
                     }
                     *pc + 1
                 },
+                PUTE => {
+                    let c = stack.pop()?.c;
+                    write!(napake, "{c}").ok()?;
+                    *pc + 1
+                },
                 GETC => {
                     let c = preberi_znak(vhod)?;
                     stack.push(Podatek { c });
                     *pc + 1
                 },
+                SEED => {
+                    stack.push(Podatek { i: seme() });
+                    *pc + 1
+                },
+                ARGC => {
+                    stack.push(Podatek { i: gostitelj.argumenti.len() as i32 });
+                    *pc + 1
+                },
+                ARGV => {
+                    let indeks = stack.pop().unsafe_unwrap().i;
+                    let argument = usize::try_from(indeks).ok().and_then(|indeks| gostitelj.argumenti.get(indeks));
+                    if !nov_niz(stack, kopica, argument.map(String::as_str)) {
+                        končaj_z_napako(stack, napake, pc, POLNA_KOPICA);
+                        return Some(());
+                    }
+                    *pc + 1
+                },
+                ENVV => {
+                    let ime = preberi_niz(stack, kopica).unsafe_unwrap();
+                    if !nov_niz(stack, kopica, env::var(ime).ok().as_deref()) {
+                        končaj_z_napako(stack, napake, pc, POLNA_KOPICA);
+                        return Some(());
+                    }
+                    *pc + 1
+                },
+                OPEN => {
+                    let način = stack.pop().unsafe_unwrap().i;
+                    let pot = preberi_niz(stack, kopica).unsafe_unwrap();
+                    stack.push(Podatek { i: gostitelj.odpri(&pot, način) });
+                    *pc + 1
+                },
+                CLOS => {
+                    let deskriptor = stack.pop().unsafe_unwrap().i;
+                    stack.push(Podatek { i: gostitelj.zapri(deskriptor) });
+                    *pc + 1
+                },
+                FGTC => {
+                    let deskriptor = stack.pop().unsafe_unwrap().i;
+                    stack.push(Podatek { c: gostitelj.preberi(deskriptor, vhod) });
+                    *pc + 1
+                },
+                FPTC => {
+                    let znak = stack.pop().unsafe_unwrap().c;
+                    let deskriptor = stack.pop().unsafe_unwrap().i;
+                    gostitelj.zapiši(deskriptor, znak);
+                    *pc + 1
+                },
                 FLUSH => {
                     izhod.flush().ok()?;
                     *pc + 1
                 },
+                EXIT => KONČAJ,
+                TIME => {
+                    stack.push(Podatek { l: gostitelj.ura.zdaj() });
+                    *pc + 1
+                },
+                SLEP => {
+                    let ms = stack.pop()?.i;
+                    gostitelj.ura.spi(ms);
+                    *pc + 1
+                },
 
                 ADDF => { stack.last_mut()?.f = stack.get(stack.len() - 2)?.f    + stack.pop()?.f;  *pc + 1 },
                 SUBF => { stack.last_mut()?.f = stack.get(stack.len() - 2)?.f    - stack.pop()?.f;  *pc + 1 },
@@ -183,12 +527,12 @@ impl Program {
                 MODF => { stack.last_mut()?.f = stack.get(stack.len() - 2)?.f    % stack.pop()?.f;  *pc + 1 },
                 POWF => { stack.last_mut()?.f = stack.get(stack.len() - 2)?.f.powf(stack.pop()?.f); *pc + 1 },
 
-                ADDI => { stack.last_mut()?.i = stack.get(stack.len() - 2)?.i   + stack.pop()?.i;         *pc + 1 },
-                SUBI => { stack.last_mut()?.i = stack.get(stack.len() - 2)?.i   - stack.pop()?.i;         *pc + 1 },
-                MULI => { stack.last_mut()?.i = stack.get(stack.len() - 2)?.i   * stack.pop()?.i;         *pc + 1 },
-                DIVI => { stack.last_mut()?.i = stack.get(stack.len() - 2)?.i   / stack.pop()?.i;         *pc + 1 },
-                MODI => { stack.last_mut()?.i = stack.get(stack.len() - 2)?.i   % stack.pop()?.i;         *pc + 1 },
-                POWI => { stack.last_mut()?.i = stack.get(stack.len() - 2)?.i.pow(stack.pop()?.i as u32); *pc + 1 },
+                ADDI => { stack.last_mut()?.i = stack.get(stack.len() - 2)?.i.wrapping_add(stack.pop()?.i);         *pc + 1 },
+                SUBI => { stack.last_mut()?.i = stack.get(stack.len() - 2)?.i.wrapping_sub(stack.pop()?.i);         *pc + 1 },
+                MULI => { stack.last_mut()?.i = stack.get(stack.len() - 2)?.i.wrapping_mul(stack.pop()?.i);         *pc + 1 },
+                DIVI => { stack.last_mut()?.i = stack.get(stack.len() - 2)?.i.wrapping_div(neničelno(stack.pop()?.i)?);         *pc + 1 },
+                MODI => { stack.last_mut()?.i = stack.get(stack.len() - 2)?.i.wrapping_rem(neničelno(stack.pop()?.i)?);         *pc + 1 },
+                POWI => { stack.last_mut()?.i = stack.get(stack.len() - 2)?.i.wrapping_pow(stack.pop()?.i as u32); *pc + 1 },
 
                 BOR  => { stack.last_mut()?.i = stack.get(stack.len() - 2)?.i | stack.pop()?.i;  *pc + 1 },
                 BXOR => { stack.last_mut()?.i = stack.get(stack.len() - 2)?.i ^ stack.pop()?.i;  *pc + 1 },
@@ -199,12 +543,166 @@ impl Program {
 
                 FTOI => { stack.last_mut()?.i = stack.last()?.f as i32; *pc + 1 },
                 ITOF => { stack.last_mut()?.f = stack.last()?.i as f32; *pc + 1 },
+
+                ADDL => { stack.last_mut()?.l = stack.get(stack.len() - 2)?.l.wrapping_add(stack.pop()?.l);         *pc + 1 },
+                SUBL => { stack.last_mut()?.l = stack.get(stack.len() - 2)?.l.wrapping_sub(stack.pop()?.l);         *pc + 1 },
+                MULL => { stack.last_mut()?.l = stack.get(stack.len() - 2)?.l.wrapping_mul(stack.pop()?.l);         *pc + 1 },
+                DIVL => { stack.last_mut()?.l = stack.get(stack.len() - 2)?.l.wrapping_div(neničelno(stack.pop()?.l)?);         *pc + 1 },
+                MODL => { stack.last_mut()?.l = stack.get(stack.len() - 2)?.l.wrapping_rem(neničelno(stack.pop()?.l)?);         *pc + 1 },
+                POWL => { stack.last_mut()?.l = stack.get(stack.len() - 2)?.l.wrapping_pow(stack.pop()?.l as u32); *pc + 1 },
+
+                ADDD => { stack.last_mut()?.d = stack.get(stack.len() - 2)?.d    + stack.pop()?.d;  *pc + 1 },
+                SUBD => { stack.last_mut()?.d = stack.get(stack.len() - 2)?.d    - stack.pop()?.d;  *pc + 1 },
+                MULD => { stack.last_mut()?.d = stack.get(stack.len() - 2)?.d    * stack.pop()?.d;  *pc + 1 },
+                DIVD => { stack.last_mut()?.d = stack.get(stack.len() - 2)?.d    / stack.pop()?.d;  *pc + 1 },
+                MODD => { stack.last_mut()?.d = stack.get(stack.len() - 2)?.d    % stack.pop()?.d;  *pc + 1 },
+                POWD => { stack.last_mut()?.d = stack.get(stack.len() - 2)?.d.powf(stack.pop()?.d); *pc + 1 },
+
+                POSL => { *stack.last_mut()? = if stack.last()?.l  > 0 { RESNICA } else { LAŽ }; *pc + 1 },
+                ZERL => { *stack.last_mut()? = if stack.last()?.l == 0 { RESNICA } else { LAŽ }; *pc + 1 },
+
+                ITOL => { stack.last_mut()?.l = stack.last()?.i as i64; *pc + 1 },
+                LTOI => { stack.last_mut()?.i = stack.last()?.l as i32; *pc + 1 },
+                FTOD => { stack.last_mut()?.d = stack.last()?.f as f64; *pc + 1 },
+                DTOF => { stack.last_mut()?.f = stack.last()?.d as f32; *pc + 1 },
+                LTOD => { stack.last_mut()?.d = stack.last()?.l as f64; *pc + 1 },
+                DTOL => { stack.last_mut()?.l = stack.last()?.d as i64; *pc + 1 },
+
+                DIVU => { stack.last_mut()?.i = (stack.get(stack.len() - 2)?.i as u32 / neničelno(stack.pop()?.i)? as u32) as i32;  *pc + 1 },
+                MODU => { stack.last_mut()?.i = (stack.get(stack.len() - 2)?.i as u32 % neničelno(stack.pop()?.i)? as u32) as i32;  *pc + 1 },
+                GTU  => { *stack.last_mut()? = if stack.get(stack.len() - 2)?.i as u32 > stack.pop()?.i as u32 { RESNICA } else { LAŽ }; *pc + 1 },
+                BSRU => { stack.last_mut()?.i = (stack.get(stack.len() - 2)?.i as u32 >> stack.pop()?.i) as i32;  *pc + 1 },
+                UTOL => { stack.last_mut()?.l = stack.last()?.i as u32 as i64; *pc + 1 },
+
+                SQRT => { stack.last_mut()?.f = stack.last()?.f.sqrt(); *pc + 1 },
+                SINF => { stack.last_mut()?.f = stack.last()?.f.sin(); *pc + 1 },
+                COSF => { stack.last_mut()?.f = stack.last()?.f.cos(); *pc + 1 },
+                TANF => { stack.last_mut()?.f = stack.last()?.f.tan(); *pc + 1 },
+                ATNF => { stack.last_mut()?.f = stack.get(stack.len() - 2)?.f.atan2(stack.pop()?.f); *pc + 1 },
+                EXPF => { stack.last_mut()?.f = stack.last()?.f.exp(); *pc + 1 },
+                LOGF => { stack.last_mut()?.f = stack.last()?.f.ln(); *pc + 1 },
+                FLRF => { stack.last_mut()?.f = stack.last()?.f.floor(); *pc + 1 },
+                CEIF => { stack.last_mut()?.f = stack.last()?.f.ceil(); *pc + 1 },
+                RNDF => { stack.last_mut()?.f = stack.last()?.f.round(); *pc + 1 },
+                ABSF => { stack.last_mut()?.f = stack.last()?.f.abs(); *pc + 1 },
+                ABSI => { stack.last_mut()?.i = stack.last()?.i.wrapping_abs(); *pc + 1 },
             }
         };
         Some(())
     }
 }
 
+// stanje stroja, ki se ohrani med zaporednimi programi (interaktivna lupina)
+pub struct Stroj {
+    stack: Vec<Podatek>,
+    kopica: Vec<Podatek>,
+    gostitelj: Gostitelj<'static>,
+}
+
+impl Default for Stroj {
+    fn default() -> Self {
+        Stroj {
+            stack: Vec::with_capacity(32_768),
+            kopica: Vec::new(),
+            gostitelj: Gostitelj::default(),
+        }
+    }
+}
+
+// izid programa v lupini
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Izid {
+    Uspeh,
+    Končaj(i32),
+    // napaka pri izvajanju se izpiše, lupina pa teče naprej
+    Napaka,
+}
+
+impl Stroj {
+    pub fn zaženi(&mut self, drevo: Drevo, vhod: &mut impl io::Read, izhod: &mut impl io::Write, napake: &mut impl io::Write) -> Izid {
+        // globalne spremenljivke prejšnjih programov so že na stacku, nove dodamo za njimi
+        if self.stack.len() < drevo.prostor as usize {
+            self.stack.resize(drevo.prostor as usize, NIČ);
+        }
+        let globalne = self.stack.len();
+        let program = Drevo { prostor: 0, ..drevo }.v_program();
+
+        let mut pc: i32 = 0;
+        let mut addroff: i32 = 0;
+
+        // ukaze preverjamo, da napaka pri izvajanju ne konča lupine
+        while (pc as usize) < program.ukazi.len() {
+            let ukaz = &program.ukazi[pc as usize];
+            if Program::korak_debug(ukaz, &mut self.stack, &mut self.kopica, &mut pc, &mut addroff, vhod, izhod, napake, &mut self.gostitelj).is_none() {
+                let _ = writeln!(napake, "Napaka pri izvajanju: {ukaz:?}");
+                pc = NAPAKA;
+            }
+        }
+        let _ = izhod.flush();
+        match pc {
+            KONČAJ => Izid::Končaj(unsafe { self.stack.last().unsafe_unwrap().i }),
+            NAPAKA => {
+                // po napaki na stacku ostanejo samo globalne spremenljivke
+                self.stack.truncate(globalne);
+                Izid::Napaka
+            },
+            _ => Izid::Uspeh,
+        }
+    }
+}
+
+// naslovi od KOPICA naprej kažejo na kopico, nižji na stack
+const KOPICA: i32 = 1 << 28;
+
+// kopica ima toliko mest kot v prevedenem programu (kopica.data v footer.asm), sproščanja ni
+const VELIKOST_KOPICE: usize = 1 << 20;
+
+const IZVEN_MEJA: &str = "Napaka: rezina je izven meja seznama";
+const POLNA_KOPICA: &str = "Napaka: zmanjkalo je prostora na kopici";
+
+// deljenje z 0 je napaka pri izvajanju
+fn neničelno<T: Default + PartialEq>(delitelj: T) -> Option<T> {
+    if delitelj == T::default() { None } else { Some(delitelj) }
+}
+
+// tako kot _slice_error in _heap_error v prevedenem programu izpiše napako in konča z izhodno kodo 1
+fn končaj_z_napako(stack: &mut Vec<Podatek>, napake: &mut impl io::Write, pc: &mut i32, sporočilo: &str) {
+    let _ = writeln!(napake, "{sporočilo}");
+    stack.push(Podatek { i: 1 });
+    *pc = NAPAKA;
+}
+
+#[inline]
+fn celica<'a>(stack: &'a mut [Podatek], kopica: &'a mut [Podatek], naslov: i32) -> Option<&'a mut Podatek> {
+    if naslov >= KOPICA {
+        kopica.get_mut((naslov - KOPICA) as usize)
+    }
+    else {
+        stack.get_mut(naslov as usize)
+    }
+}
+
+fn kopiraj(stack: &mut [Podatek], kopica: &mut [Podatek], od: i32, kam: i32, dolžina: i32) -> Option<()> {
+    match (od >= KOPICA, kam >= KOPICA) {
+        (false, false) => {
+            stack.get(od as usize..(od + dolžina) as usize)?;
+            stack.get(kam as usize..(kam + dolžina) as usize)?;
+            stack.copy_within(od as usize..(od + dolžina) as usize, kam as usize);
+        },
+        (true, true) => {
+            let (od, kam) = (od - KOPICA, kam - KOPICA);
+            kopica.get(od as usize..(od + dolžina) as usize)?;
+            kopica.get(kam as usize..(kam + dolžina) as usize)?;
+            kopica.copy_within(od as usize..(od + dolžina) as usize, kam as usize);
+        },
+        _ => for i in 0..dolžina {
+            let podatek = *celica(stack, kopica, od + i)?;
+            *celica(stack, kopica, kam + i)? = podatek;
+        },
+    }
+    Some(())
+}
+
 fn preberi_znak(vhod: &mut impl io::Read) -> Option<char> {
     let mut buf = [0u8; 4];
     let _ = vhod.read(&mut buf[..1]).unwrap();
@@ -225,6 +723,39 @@ fn preberi_znak(vhod: &mut impl io::Read) -> Option<char> {
     str::from_utf8(&buf).ok()?.chars().next()
 }
 
+// niz na kopici, brez vrednosti je naslov 0 in dolžina -1
+// vrne laž, če na kopici ni dovolj prostora
+fn nov_niz(stack: &mut Vec<Podatek>, kopica: &mut Vec<Podatek>, vrednost: Option<&str>) -> bool {
+    match vrednost {
+        Some(vrednost) if kopica.len() + vrednost.chars().count() > VELIKOST_KOPICE => return false,
+        Some(vrednost) => {
+            stack.push(Podatek { i: KOPICA + kopica.len() as i32 });
+            let dolžina = kopica.len();
+            kopica.extend(vrednost.chars().map(|c| Podatek { c }));
+            stack.push(Podatek { i: (kopica.len() - dolžina) as i32 });
+        },
+        None => stack.extend([Podatek { i: 0 }, Podatek { i: -1 }]),
+    }
+    true
+}
+
+// vzame niz z vrha stacka
+fn preberi_niz(stack: &mut Vec<Podatek>, kopica: &mut [Podatek]) -> Option<String> {
+    let dolžina = unsafe { stack.pop()?.i };
+    let naslov = unsafe { stack.pop()?.i };
+    (naslov..naslov + dolžina)
+        .map(|naslov| celica(stack, kopica, naslov).map(|podatek| unsafe { podatek.c }))
+        .collect()
+}
+
+// seme iz spremenljivke okolja SLJ_SEME (za ponovljive izvedbe) ali iz entropije sistema
+fn seme() -> i32 {
+    match env::var("SLJ_SEME").ok().and_then(|seme| seme.parse::<i32>().ok()) {
+        Some(seme) => seme,
+        None => RandomState::new().build_hasher().finish() as i32,
+    }
+}
+
 #[cfg(test)]
 mod testi {
     use std::io::Cursor;
@@ -235,6 +766,7 @@ mod testi {
     fn zaženi() {
         let mut pc: i32 = 0;
         let mut addroff: i32 = 0;
+        let mut kopica: Vec<Podatek> = Vec::new();
         let mut stack: Vec<Podatek> = Vec::new();
 
         assert_eq!(stack, []);
@@ -243,185 +775,186 @@ mod testi {
 
         let mut vhod = Cursor::new(Vec::<u8>::new());
         let mut izhod = Vec::<u8>::new();
+        let mut napake = Vec::<u8>::new();
 
         // x (@0)
-        Program::korak(&PUSH(Podatek { f: 1.0 }), &mut stack, &mut pc, &mut addroff, &mut vhod, &mut izhod);
+        Program::korak(&PUSH(Podatek { f: 1.0 }), &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
         assert_eq!(stack, [Podatek { f: 1.0 }]);
         assert_eq!(pc, 1);
         assert_eq!(addroff, 0);
 
         // y (@1)
-        Program::korak(&PUSH(Podatek { f: 3.14 }), &mut stack, &mut pc, &mut addroff, &mut vhod, &mut izhod);
+        Program::korak(&PUSH(Podatek { f: 3.14 }), &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
         assert_eq!(stack, [Podatek { f: 1.0 }, Podatek { f: 3.14 }]);
         assert_eq!(pc, 2);
         assert_eq!(addroff, 0);
 
         // LOAD y
-        Program::korak(&LOAD(1), &mut stack, &mut pc, &mut addroff, &mut vhod, &mut izhod);
+        Program::korak(&LOAD(1), &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
         assert_eq!(stack, [Podatek { f: 1.0 }, Podatek { f: 3.14 }, Podatek { f: 3.14 }]);
         assert_eq!(pc, 3);
         assert_eq!(addroff, 0);
 
         // LOAD x
-        Program::korak(&LOAD(0), &mut stack, &mut pc, &mut addroff, &mut vhod, &mut izhod);
+        Program::korak(&LOAD(0), &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
         assert_eq!(stack, [Podatek { f: 1.0 }, Podatek { f: 3.14 }, Podatek { f: 3.14 }, Podatek { f: 1.0 }]);
         assert_eq!(pc, 4);
         assert_eq!(addroff, 0);
 
         // y - x
-        Program::korak(&SUBF, &mut stack, &mut pc, &mut addroff, &mut vhod, &mut izhod);
+        Program::korak(&SUBF, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
         assert_eq!(stack, [Podatek { f: 1.0 }, Podatek { f: 3.14 }, Podatek { f: 2.14 }]);
         assert_eq!(pc, 5);
         assert_eq!(addroff, 0);
 
         // y > x (y - x > 0 <=> y > x)
-        Program::korak(&POS, &mut stack, &mut pc, &mut addroff, &mut vhod, &mut izhod);
+        Program::korak(&POS, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
         assert_eq!(stack, [Podatek { f: 1.0 }, Podatek { f: 3.14 }, Podatek { i: 1 }]);
         assert_eq!(pc, 6);
         assert_eq!(addroff, 0);
 
         // NOOP
-        Program::korak(&NOOP, &mut stack, &mut pc, &mut addroff, &mut vhod, &mut izhod);
+        Program::korak(&NOOP, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
         assert_eq!(stack, [Podatek { f: 1.0 }, Podatek { f: 3.14 }, Podatek { i: 1 }]);
         assert_eq!(pc, 7);
         assert_eq!(addroff, 0);
 
         // JMPC #0
-        Program::korak(&JMPC(0), &mut stack, &mut pc, &mut addroff, &mut vhod, &mut izhod);
+        Program::korak(&JMPC(0), &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
         assert_eq!(stack, [Podatek { f: 1.0 }, Podatek { f: 3.14 }]);
         assert_eq!(pc, 0);
         assert_eq!(addroff, 0);
 
         // PUSH #8
-        Program::korak(&PUSH(Podatek { i: 8 }), &mut stack, &mut pc, &mut addroff, &mut vhod, &mut izhod);
+        Program::korak(&PUSH(Podatek { i: 8 }), &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
         assert_eq!(stack, [Podatek { f: 1.0 }, Podatek { f: 3.14 }, Podatek { i: 8 }]);
         assert_eq!(pc, 1);
         assert_eq!(addroff, 0);
 
         // JMPD
-        Program::korak(&JMPD, &mut stack, &mut pc, &mut addroff, &mut vhod, &mut izhod);
+        Program::korak(&JMPD, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
         assert_eq!(stack, [Podatek { f: 1.0 }, Podatek { f: 3.14 }]);
         assert_eq!(pc, 8);
         assert_eq!(addroff, 0);
 
         // JUMP #13
-        Program::korak(&JUMP(13), &mut stack, &mut pc, &mut addroff, &mut vhod, &mut izhod);
+        Program::korak(&JUMP(13), &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
         assert_eq!(stack, [Podatek { f: 1.0 }, Podatek { f: 3.14 }]);
         assert_eq!(pc, 13);
         assert_eq!(addroff, 0);
 
         // PUSH #0.0
-        Program::korak(&PUSH(Podatek { f: 0.0 }), &mut stack, &mut pc, &mut addroff, &mut vhod, &mut izhod);
+        Program::korak(&PUSH(Podatek { f: 0.0 }), &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
         assert_eq!(stack, [Podatek { f: 1.0 }, Podatek { f: 3.14 }, Podatek { f: 0.0 }]);
         assert_eq!(pc, 14);
         assert_eq!(addroff, 0);
 
         // ZERO (0.0 == 0.0)
-        Program::korak(&ZERO, &mut stack, &mut pc, &mut addroff, &mut vhod, &mut izhod);
+        Program::korak(&ZERO, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
         assert_eq!(stack, [Podatek { f: 1.0 }, Podatek { f: 3.14 }, Podatek { i: 1 }]);
         assert_eq!(pc, 15);
         assert_eq!(addroff, 0);
 
         // PUSH 'c'
-        Program::korak(&PUSH(Podatek { c: '\n' }), &mut stack, &mut pc, &mut addroff, &mut vhod, &mut izhod);
+        Program::korak(&PUSH(Podatek { c: '\n' }), &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
         assert_eq!(stack, [Podatek { f: 1.0 }, Podatek { f: 3.14 }, Podatek { i: 1 }, Podatek { c: '\n' }]);
         assert_eq!(pc, 16);
         assert_eq!(addroff, 0);
 
         // PUSH '\n'
-        Program::korak(&PUSH(Podatek { c: 'c' }), &mut stack, &mut pc, &mut addroff, &mut vhod, &mut izhod);
+        Program::korak(&PUSH(Podatek { c: 'c' }), &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
         assert_eq!(stack, [Podatek { f: 1.0 }, Podatek { f: 3.14 }, Podatek { i: 1 }, Podatek { c: '\n' }, Podatek { c: 'c' }]);
         assert_eq!(pc, 17);
         assert_eq!(addroff, 0);
 
         // PRTC
         // PRTC
-        Program::korak(&PUTC, &mut stack, &mut pc, &mut addroff, &mut vhod, &mut izhod);
-        Program::korak(&PUTC, &mut stack, &mut pc, &mut addroff, &mut vhod, &mut izhod);
+        Program::korak(&PUTC, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
+        Program::korak(&PUTC, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
         assert_eq!(stack, [Podatek { f: 1.0 }, Podatek { f: 3.14 }, Podatek { i: 1 }]);
         assert_eq!(pc, 19);
         assert_eq!(addroff, 0);
 
         // POP
-        Program::korak(&ALOC(-1), &mut stack, &mut pc, &mut addroff, &mut vhod, &mut izhod);
+        Program::korak(&ALOC(-1), &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
         assert_eq!(stack, [Podatek { f: 1.0 }, Podatek { f: 3.14 }]);
         assert_eq!(pc, 20);
         assert_eq!(addroff, 0);
 
         // PUSH #1.0
-        Program::korak(&PUSH(Podatek { f: 1.0 }), &mut stack, &mut pc, &mut addroff, &mut vhod, &mut izhod);
+        Program::korak(&PUSH(Podatek { f: 1.0 }), &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
         assert_eq!(stack, [Podatek { f: 1.0 }, Podatek { f: 3.14 }, Podatek { f: 1.0 }]);
         assert_eq!(pc, 21);
         assert_eq!(addroff, 0);
 
         // PUSH #0.0
-        Program::korak(&PUSH(Podatek { f: 0.0 }), &mut stack, &mut pc, &mut addroff, &mut vhod, &mut izhod);
+        Program::korak(&PUSH(Podatek { f: 0.0 }), &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
         assert_eq!(stack, [Podatek { f: 1.0 }, Podatek { f: 3.14 }, Podatek { f: 1.0 }, Podatek { f: 0.0 }]);
         assert_eq!(pc, 22);
         assert_eq!(addroff, 0);
 
         // MUL (0.0 * 1.0) = 0.0
-        Program::korak(&MULF, &mut stack, &mut pc, &mut addroff, &mut vhod, &mut izhod);
+        Program::korak(&MULF, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
         assert_eq!(stack, [Podatek { f: 1.0 }, Podatek { f: 3.14 }, Podatek { f: 0.0 }]);
         assert_eq!(pc, 23);
         assert_eq!(addroff, 0);
 
         // STOR @0 (x = 0.0)
-        Program::korak(&STOR(0), &mut stack, &mut pc, &mut addroff, &mut vhod, &mut izhod);
+        Program::korak(&STOR(0), &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
         assert_eq!(stack, [Podatek { f: 0.0 }, Podatek { f: 3.14 }]);
         assert_eq!(pc, 24);
         assert_eq!(addroff, 0);
 
         // LOFF
-        Program::korak(&LOFF, &mut stack, &mut pc, &mut addroff, &mut vhod, &mut izhod);
+        Program::korak(&LOFF, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
         assert_eq!(stack, [Podatek { f: 0.0 }, Podatek { f: 3.14 }, Podatek { i: 0 }]);
         assert_eq!(pc, 25);
         assert_eq!(addroff, 0);
 
         // PUSH #3.01
-        Program::korak(&PUSH(Podatek { f: 3.01 }), &mut stack, &mut pc, &mut addroff, &mut vhod, &mut izhod);
+        Program::korak(&PUSH(Podatek { f: 3.01 }), &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
         assert_eq!(stack, [Podatek { f: 0.0 }, Podatek { f: 3.14 }, Podatek { i: 0 }, Podatek { f: 3.01 }]);
         assert_eq!(pc, 26);
         assert_eq!(addroff, 0);
 
         // TOP -3
-        Program::korak(&TOP(-3), &mut stack, &mut pc, &mut addroff, &mut vhod, &mut izhod);
+        Program::korak(&TOP(-3), &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
         assert_eq!(stack, [Podatek { f: 0.0 }, Podatek { f: 3.14 }, Podatek { i: 0 }, Podatek { f: 3.01 }]);
         assert_eq!(pc, 27);
         assert_eq!(addroff, 1);
 
         // LDOF @0
-        Program::korak(&LDOF(0), &mut stack, &mut pc, &mut addroff, &mut vhod, &mut izhod);
+        Program::korak(&LDOF(0), &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
         assert_eq!(stack, [Podatek { f: 0.0 }, Podatek { f: 3.14 }, Podatek { i: 0 }, Podatek { f: 3.01 }, Podatek { f: 3.14 }]);
         assert_eq!(pc, 28);
         assert_eq!(addroff, 1);
 
         // ADD
-        Program::korak(&ADDF, &mut stack, &mut pc, &mut addroff, &mut vhod, &mut izhod);
+        Program::korak(&ADDF, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
         assert_eq!(stack, [Podatek { f: 0.0 }, Podatek { f: 3.14 }, Podatek { i: 0 }, Podatek { f: 3.01 + 3.14 }]);
         assert_eq!(pc, 29);
         assert_eq!(addroff, 1);
 
         // PUSH 1.0
-        Program::korak(&PUSH(Podatek { f: 1.0 }), &mut stack, &mut pc, &mut addroff, &mut vhod, &mut izhod);
+        Program::korak(&PUSH(Podatek { f: 1.0 }), &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
         assert_eq!(stack, [Podatek { f: 0.0 }, Podatek { f: 3.14 }, Podatek { i: 0 }, Podatek { f: 3.01 + 3.14 }, Podatek { f: 1.0 }]);
         assert_eq!(pc, 30);
         assert_eq!(addroff, 1);
 
         // DIV
-        Program::korak(&DIVF, &mut stack, &mut pc, &mut addroff, &mut vhod, &mut izhod);
+        Program::korak(&DIVF, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
         assert_eq!(stack, [Podatek { f: 0.0 }, Podatek { f: 3.14 }, Podatek { i: 0 }, Podatek { f: (3.01 + 3.14) / 1.0 }]);
         assert_eq!(pc, 31);
         assert_eq!(addroff, 1);
 
         // STOF @0
-        Program::korak(&STOF(0), &mut stack, &mut pc, &mut addroff, &mut vhod, &mut izhod);
+        Program::korak(&STOF(0), &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
         assert_eq!(stack, [Podatek { f: 0.0 }, Podatek { f: 3.01 + 3.14 }, Podatek { i: 0 }]);
         assert_eq!(pc, 32);
         assert_eq!(addroff, 1);
 
         // SOFF
-        Program::korak(&SOFF, &mut stack, &mut pc, &mut addroff, &mut vhod, &mut izhod);
+        Program::korak(&SOFF, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
         assert_eq!(stack, [Podatek { f: 0.0 }, Podatek { f: 3.01 + 3.14 }]);
         assert_eq!(pc, 33);
         assert_eq!(addroff, 0);
@@ -430,7 +963,7 @@ mod testi {
         stack[1].f = 3.0;
 
         // MOD
-        Program::korak(&MODF, &mut stack, &mut pc, &mut addroff, &mut vhod, &mut izhod);
+        Program::korak(&MODF, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
         assert_eq!(stack, [Podatek { f: 2.0 }]);
         assert_eq!(pc, 34);
         assert_eq!(addroff, 0);
@@ -438,7 +971,7 @@ mod testi {
         stack.push(Podatek { f: 5.0 });
 
         // POW
-        Program::korak(&POWF, &mut stack, &mut pc, &mut addroff, &mut vhod, &mut izhod);
+        Program::korak(&POWF, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
         assert_eq!(stack, [Podatek { f: 32.0 }]);
         assert_eq!(pc, 35);
         assert_eq!(addroff, 0);
@@ -446,7 +979,7 @@ mod testi {
         stack = vec![Podatek { i: 1234 }, Podatek { i: 5678 }];
 
         // BAND
-        Program::korak(&BAND, &mut stack, &mut pc, &mut addroff, &mut vhod, &mut izhod);
+        Program::korak(&BAND, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
         assert_eq!(stack, [Podatek { i: 1234 & 5678 }]);
         assert_eq!(pc, 36);
         assert_eq!(addroff, 0);
@@ -454,7 +987,7 @@ mod testi {
         stack = vec![Podatek { i: 1234 }, Podatek { i: 5678 }];
 
         // BXOR
-        Program::korak(&BXOR, &mut stack, &mut pc, &mut addroff, &mut vhod, &mut izhod);
+        Program::korak(&BXOR, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
         assert_eq!(stack, [Podatek { i: 1234 ^ 5678 }]);
         assert_eq!(pc, 37);
         assert_eq!(addroff, 0);
@@ -462,10 +995,407 @@ mod testi {
         stack = vec![Podatek { i: 1234 }, Podatek { i: 5678 }];
 
         // BOR
-        Program::korak(&BOR, &mut stack, &mut pc, &mut addroff, &mut vhod, &mut izhod);
+        Program::korak(&BOR, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
         assert_eq!(stack, [Podatek { i: 1234 | 5678 }]);
         assert_eq!(pc, 38);
         assert_eq!(addroff, 0);
     }
 
+    #[test]
+    fn zaženi_64() {
+        let mut pc: i32 = 0;
+        let mut addroff: i32 = 0;
+        let mut kopica: Vec<Podatek> = Vec::new();
+        let mut stack: Vec<Podatek> = vec![Podatek { l: 1 << 40 }, Podatek { l: 3 }];
+
+        let mut vhod = Cursor::new(Vec::<u8>::new());
+        let mut izhod = Vec::<u8>::new();
+        let mut napake = Vec::<u8>::new();
+
+        // MULL
+        Program::korak(&MULL, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
+        assert_eq!(unsafe { stack[0].l }, 3 << 40);
+        assert_eq!(stack.len(), 1);
+
+        // POSL
+        Program::korak(&POSL, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
+        assert_eq!(stack, [RESNICA]);
+
+        stack = vec![Podatek { i: i32::MAX }];
+
+        // ITOL, ADDL
+        Program::korak(&ITOL, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
+        stack.push(Podatek { l: 1 });
+        Program::korak(&ADDL, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
+        assert_eq!(unsafe { stack[0].l }, i32::MAX as i64 + 1);
+
+        // LTOD, DIVD
+        Program::korak(&LTOD, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
+        stack.push(Podatek { d: 3.0 });
+        Program::korak(&DIVD, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
+        assert_eq!(unsafe { stack[0].d }, (i32::MAX as f64 + 1.0) / 3.0);
+
+        // DTOL
+        Program::korak(&DTOL, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
+        assert_eq!(unsafe { stack[0].l }, 715_827_882);
+
+        // ZERL
+        stack.push(Podatek { l: 715_827_882 });
+        Program::korak(&SUBL, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
+        Program::korak(&ZERL, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
+        assert_eq!(stack, [RESNICA]);
+        assert_eq!(pc, 9);
+    }
+
+    #[test]
+    fn zaženi_naravno() {
+        let mut pc: i32 = 0;
+        let mut addroff: i32 = 0;
+        let mut kopica: Vec<Podatek> = Vec::new();
+        let mut stack: Vec<Podatek> = vec![Podatek { i: -2 }, Podatek { i: 3 }];
+
+        let mut vhod = Cursor::new(Vec::<u8>::new());
+        let mut izhod = Vec::<u8>::new();
+        let mut napake = Vec::<u8>::new();
+
+        // DIVU
+        Program::korak(&DIVU, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
+        assert_eq!(stack, [Podatek { i: ((u32::MAX - 1) / 3) as i32 }]);
+
+        stack = vec![Podatek { i: -1 }, Podatek { i: 10 }];
+
+        // MODU
+        Program::korak(&MODU, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
+        assert_eq!(stack, [Podatek { i: 5 }]);
+
+        stack = vec![Podatek { i: -1 }, Podatek { i: 1 }];
+
+        // GTU
+        Program::korak(&GTU, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
+        assert_eq!(stack, [RESNICA]);
+
+        stack = vec![Podatek { i: i32::MIN }, Podatek { i: 31 }];
+
+        // BSRU, BSLR
+        Program::korak(&BSRU, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
+        assert_eq!(stack, [Podatek { i: 1 }]);
+        stack = vec![Podatek { i: i32::MIN }, Podatek { i: 31 }];
+        Program::korak(&BSLR, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
+        assert_eq!(stack, [Podatek { i: -1 }]);
+
+        // UTOL
+        Program::korak(&UTOL, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
+        assert_eq!(unsafe { stack[0].l }, u32::MAX as i64);
+        assert_eq!(pc, 6);
+    }
+
+    #[test]
+    fn zaženi_copy() {
+        let mut pc: i32 = 0;
+        let mut addroff: i32 = 0;
+        let mut kopica: Vec<Podatek> = Vec::new();
+        let mut stack: Vec<Podatek> = vec![
+            Podatek { i: 3 }, Podatek { i: 1 }, Podatek { i: 2 }, Podatek { l: 1 << 40 },
+            Podatek { i: 0 }, Podatek { i: 0 }, Podatek { i: 0 }, Podatek { i: 0 },
+            Podatek { i: 0 }, Podatek { i: 4 },
+        ];
+
+        let mut vhod = Cursor::new(Vec::<u8>::new());
+        let mut izhod = Vec::<u8>::new();
+        let mut napake = Vec::<u8>::new();
+
+        // COPY
+        Program::korak(&COPY(4), &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
+        assert_eq!(stack.len(), 8);
+        assert_eq!(stack[4..7], [Podatek { i: 3 }, Podatek { i: 1 }, Podatek { i: 2 }]);
+        assert_eq!(unsafe { stack[7].l }, 1 << 40);
+        assert_eq!(pc, 1);
+    }
+
+    #[test]
+    fn zaženi_slce() {
+        let mut pc: i32 = 0;
+        let mut addroff: i32 = 0;
+        let mut kopica: Vec<Podatek> = Vec::new();
+        let mut stack: Vec<Podatek> = vec![Podatek { i: 10 }, Podatek { i: 6 }, Podatek { i: 1 }, Podatek { i: 4 }];
+
+        let mut vhod = Cursor::new(Vec::<u8>::new());
+        let mut izhod = Vec::<u8>::new();
+        let mut napake = Vec::<u8>::new();
+
+        // SLCE
+        Program::korak(&SLCE(2), &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
+        assert_eq!(stack, [Podatek { i: 12 }, Podatek { i: 3 }]);
+        assert_eq!(pc, 1);
+    }
+
+    #[test]
+    fn zaženi_slce_izven_meja() {
+        let mut pc: i32 = 0;
+        let mut addroff: i32 = 0;
+        let mut kopica: Vec<Podatek> = Vec::new();
+        let mut stack: Vec<Podatek> = vec![Podatek { i: 10 }, Podatek { i: 6 }, Podatek { i: 2 }, Podatek { i: 7 }];
+
+        let mut vhod = Cursor::new(Vec::<u8>::new());
+        let mut izhod = Vec::<u8>::new();
+        let mut napake = Vec::<u8>::new();
+
+        Program::korak(&SLCE(1), &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
+        assert_eq!(String::from_utf8(napake).unwrap(), "Napaka: rezina je izven meja seznama\n");
+        assert_eq!(stack.last(), Some(&Podatek { i: 1 }));
+        assert_eq!(pc, NAPAKA);
+    }
+
+    #[test]
+    fn zaženi_getc() {
+        let mut pc: i32 = 0;
+        let mut addroff: i32 = 0;
+        let mut kopica: Vec<Podatek> = Vec::new();
+        let mut stack: Vec<Podatek> = Vec::new();
+
+        let mut vhod = Cursor::new("š".as_bytes().to_vec());
+        let mut izhod = Vec::<u8>::new();
+        let mut napake = Vec::<u8>::new();
+
+        // na koncu vhoda GETC prebere '\0'
+        Program::korak(&GETC, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
+        Program::korak(&GETC, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
+        assert_eq!(stack, [Podatek { c: 'š' }, Podatek { c: '\0' }]);
+        assert_eq!(pc, 2);
+    }
+
+    #[test]
+    fn zaženi_alhp() {
+        let mut pc: i32 = 0;
+        let mut addroff: i32 = 0;
+        let mut kopica: Vec<Podatek> = Vec::new();
+        let mut stack: Vec<Podatek> = vec![Podatek { i: 3 }];
+
+        let mut vhod = Cursor::new(Vec::<u8>::new());
+        let mut izhod = Vec::<u8>::new();
+        let mut napake = Vec::<u8>::new();
+
+        // ALHP
+        Program::korak(&ALHP, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
+        assert_eq!(stack, [Podatek { i: KOPICA }, Podatek { i: 3 }]);
+        assert_eq!(kopica.len(), 3);
+        assert_eq!(pc, 1);
+
+        // STDY, LDDY na kopici
+        stack.extend([Podatek { i: 42 }, Podatek { i: KOPICA + 1 }]);
+        Program::korak(&STDY(0), &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
+        assert_eq!(kopica[1], Podatek { i: 42 });
+        stack.push(Podatek { i: KOPICA });
+        Program::korak(&LDDY(1), &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
+        assert_eq!(stack.last(), Some(&Podatek { i: 42 }));
+
+        // kopica je omejena tako kot v prevedenem programu
+        stack.push(Podatek { i: VELIKOST_KOPICE as i32 });
+        Program::korak(&ALHP, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
+        assert_eq!(String::from_utf8(napake).unwrap(), "Napaka: zmanjkalo je prostora na kopici\n");
+        assert_eq!(stack.last(), Some(&Podatek { i: 1 }));
+        assert_eq!(kopica.len(), 3);
+        assert_eq!(pc, NAPAKA);
+    }
+
+    #[test]
+    fn zaženi_matematika() {
+        let mut pc: i32 = 0;
+        let mut addroff: i32 = 0;
+        let mut kopica: Vec<Podatek> = Vec::new();
+        let mut stack: Vec<Podatek> = Vec::new();
+
+        let mut vhod = Cursor::new(Vec::<u8>::new());
+        let mut izhod = Vec::<u8>::new();
+        let mut napake = Vec::<u8>::new();
+
+        let mut izračunaj = |ukaz: UkazPodatek, argumenti: &[Podatek]| {
+            stack.extend(argumenti);
+            Program::korak(&ukaz, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
+            stack.pop().unwrap()
+        };
+
+        assert_eq!(izračunaj(SQRT, &[Podatek { f: 2.0 }]), Podatek { f: 2.0f32.sqrt() });
+        assert_eq!(izračunaj(SINF, &[Podatek { f: 1.0 }]), Podatek { f: 1.0f32.sin() });
+        assert_eq!(izračunaj(COSF, &[Podatek { f: 1.0 }]), Podatek { f: 1.0f32.cos() });
+        assert_eq!(izračunaj(TANF, &[Podatek { f: 1.0 }]), Podatek { f: 1.0f32.tan() });
+        assert_eq!(izračunaj(ATNF, &[Podatek { f: 1.0 }, Podatek { f: -1.0 }]), Podatek { f: 1.0f32.atan2(-1.0) });
+        assert_eq!(izračunaj(EXPF, &[Podatek { f: 1.0 }]), Podatek { f: 1.0f32.exp() });
+        assert_eq!(izračunaj(LOGF, &[Podatek { f: 2.0 }]), Podatek { f: 2.0f32.ln() });
+        assert_eq!(izračunaj(FLRF, &[Podatek { f: -2.5 }]), Podatek { f: -3.0 });
+        assert_eq!(izračunaj(CEIF, &[Podatek { f: -2.5 }]), Podatek { f: -2.0 });
+        assert_eq!(izračunaj(RNDF, &[Podatek { f: -2.5 }]), Podatek { f: -3.0 });
+        assert_eq!(izračunaj(ABSF, &[Podatek { f: -1.5 }]), Podatek { f: 1.5 });
+        assert_eq!(izračunaj(ABSI, &[Podatek { i: -7 }]), Podatek { i: 7 });
+        assert!(stack.is_empty());
+        assert_eq!(pc, 12);
+    }
+
+    #[test]
+    fn zaženi_pute() {
+        let program = Program {
+            push_tipi: vec![],
+            argumenti: vec![],
+            ukazi: vec![
+                PUSH(Podatek { c: 'a' }), PUTC,
+                PUSH(Podatek { c: 'ž' }), PUTE,
+                PUSH(Podatek { c: '\n' }), PUTE,
+            ],
+        };
+        let mut izhod = Vec::<u8>::new();
+        let mut napake = Vec::<u8>::new();
+
+        assert_eq!(program.zaženi_z_io(&mut Cursor::new(Vec::<u8>::new()), &mut izhod, &mut napake), 0);
+        assert_eq!(izhod, "a".as_bytes());
+        assert_eq!(napake, "ž\n".as_bytes());
+    }
+
+    #[test]
+    fn zaženi_exit() {
+        let program = Program {
+            push_tipi: vec![],
+            argumenti: vec![],
+            ukazi: vec![
+                PUSH(Podatek { c: 'a' }), PUTC,
+                PUSH(Podatek { i: 1 }), PUSH(Podatek { i: 7 }), EXIT,
+                PUSH(Podatek { c: 'b' }), PUTC,
+            ],
+        };
+        let mut izhod = Vec::<u8>::new();
+        let mut napake = Vec::<u8>::new();
+
+        assert_eq!(program.zaženi_z_io(&mut Cursor::new(Vec::<u8>::new()), &mut izhod, &mut napake), 7);
+        assert_eq!(izhod, "a".as_bytes());
+    }
+
+    #[test]
+    fn zaženi_argumenti() {
+        let mut pc: i32 = 0;
+        let mut addroff: i32 = 0;
+        let mut kopica: Vec<Podatek> = Vec::new();
+        let mut stack: Vec<Podatek> = Vec::new();
+
+        let mut vhod = Cursor::new(Vec::<u8>::new());
+        let mut izhod = Vec::<u8>::new();
+        let mut napake = Vec::<u8>::new();
+        let argumenti = ["program".to_string(), "čas".to_string()];
+        let mut gostitelj = Gostitelj { argumenti: &argumenti, ..Default::default() };
+
+        Program::korak(&ARGC, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut gostitelj);
+        assert_eq!(stack, [Podatek { i: 2 }]);
+
+        Program::korak(&ARGV, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut gostitelj);
+        assert_eq!(stack, [Podatek { i: 0 }, Podatek { i: -1 }]);
+        stack.clear();
+
+        stack.push(Podatek { i: 1 });
+        Program::korak(&ARGV, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut gostitelj);
+        assert_eq!(stack, [Podatek { i: KOPICA }, Podatek { i: 3 }]);
+        assert_eq!(kopica, [Podatek { c: 'č' }, Podatek { c: 'a' }, Podatek { c: 's' }]);
+
+        // ime spremenljivke okolja je niz, ki smo ga prebrali zgoraj
+        env::set_var("čas", "ura");
+        Program::korak(&ENVV, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut gostitelj);
+        assert_eq!(stack, [Podatek { i: KOPICA + 3 }, Podatek { i: 3 }]);
+        assert_eq!(&kopica[3..], [Podatek { c: 'u' }, Podatek { c: 'r' }, Podatek { c: 'a' }]);
+        assert_eq!(pc, 4);
+    }
+
+    #[test]
+    fn zaženi_datoteke() {
+        let mut pc: i32 = 0;
+        let mut addroff: i32 = 0;
+        let mut kopica: Vec<Podatek> = Vec::new();
+        let mut stack: Vec<Podatek> = Vec::new();
+
+        let mut vhod = Cursor::new("č".as_bytes());
+        let mut izhod = Vec::<u8>::new();
+        let mut napake = Vec::<u8>::new();
+        let mut gostitelj = Gostitelj::default();
+
+        let pot = env::temp_dir().join("slj_zaženi_datoteke.txt");
+        let pot = pot.to_str().unwrap();
+        kopica.extend(pot.chars().map(|c| Podatek { c }));
+
+        stack.extend([Podatek { i: KOPICA }, Podatek { i: pot.chars().count() as i32 }, Podatek { i: 1 }]);
+        Program::korak(&OPEN, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut gostitelj);
+        assert_eq!(stack, [Podatek { i: PRVA_DATOTEKA }]);
+
+        stack.push(Podatek { c: 'ž' });
+        Program::korak(&FPTC, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut gostitelj);
+        assert_eq!(stack, []);
+
+        // druga zapora istega deskriptorja ni veljavna
+        stack.push(Podatek { i: PRVA_DATOTEKA });
+        Program::korak(&CLOS, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut gostitelj);
+        stack.push(Podatek { i: PRVA_DATOTEKA });
+        Program::korak(&CLOS, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut gostitelj);
+        assert_eq!(stack, [Podatek { i: 0 }, Podatek { i: -1 }]);
+        stack.clear();
+
+        stack.extend([Podatek { i: KOPICA }, Podatek { i: pot.chars().count() as i32 }, Podatek { i: 0 }]);
+        Program::korak(&OPEN, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut gostitelj);
+        Program::korak(&FGTC, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut gostitelj);
+        stack.push(Podatek { i: PRVA_DATOTEKA + 1 });
+        Program::korak(&FGTC, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut gostitelj);
+        stack.push(Podatek { i: 0 });
+        Program::korak(&FGTC, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut gostitelj);
+        assert_eq!(stack, [Podatek { c: 'ž' }, Podatek { c: '\0' }, Podatek { c: 'č' }]);
+        assert_eq!(pc, 8);
+
+        std::fs::remove_file(pot).unwrap();
+    }
+
+    struct NamišljenaUra(i64);
+
+    impl Ura for NamišljenaUra {
+        fn zdaj(&self) -> i64 {
+            self.0
+        }
+
+        fn spi(&mut self, ms: i32) {
+            self.0 += ms as i64 * 1_000_000;
+        }
+    }
+
+    #[test]
+    fn zaženi_čas() {
+        let mut pc: i32 = 0;
+        let mut addroff: i32 = 0;
+        let mut kopica: Vec<Podatek> = Vec::new();
+        let mut stack: Vec<Podatek> = Vec::new();
+
+        let mut vhod = Cursor::new(Vec::<u8>::new());
+        let mut izhod = Vec::<u8>::new();
+        let mut napake = Vec::<u8>::new();
+        let mut gostitelj = Gostitelj { ura: Box::new(NamišljenaUra(7)), ..Default::default() };
+
+        Program::korak(&TIME, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut gostitelj);
+        stack.push(Podatek { i: 25 });
+        Program::korak(&SLEP, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut gostitelj);
+        Program::korak(&TIME, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut gostitelj);
+        assert_eq!(unsafe { [stack[0].l, stack[1].l] }, [7, 25_000_007]);
+        assert_eq!(pc, 3);
+    }
+
+    #[test]
+    fn zaženi_seed() {
+        let mut pc: i32 = 0;
+        let mut addroff: i32 = 0;
+        let mut kopica: Vec<Podatek> = Vec::new();
+        let mut stack: Vec<Podatek> = Vec::new();
+
+        let mut vhod = Cursor::new(Vec::<u8>::new());
+        let mut izhod = Vec::<u8>::new();
+        let mut napake = Vec::<u8>::new();
+
+        env::set_var("SLJ_SEME", "-42");
+        Program::korak(&SEED, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
+        env::remove_var("SLJ_SEME");
+        Program::korak(&SEED, &mut stack, &mut kopica, &mut pc, &mut addroff, &mut vhod, &mut izhod, &mut napake, &mut Gostitelj::default());
+        assert_eq!(stack.len(), 2);
+        assert_eq!(stack[0], Podatek { i: -42 });
+        assert_eq!(pc, 2);
+    }
+
 }
@@ -1,15 +1,47 @@
 use slj::{parser::{Parse, lekser::{Razčleni, Žeton::*, L}}, program::ToProgram};
-use std::io::Cursor;
+use std::{env, io::{self, Cursor, Write}, process::{Command, Stdio}};
 
 fn test(src: &str, vhod: &str) -> String {
+    test_koda(src, vhod).0
+}
+
+fn test_koda(src: &str, vhod: &str) -> (String, i32) {
+    let mut izhod = Vec::<u8>::new();
+
+    let koda = src
+        .razčleni("[test]")
+        .analiziraj()
+        .unwrap()
+        .v_program()
+        .zaženi_z_io(&mut Cursor::new(vhod), &mut izhod, &mut io::sink());
+
+    return (String::from_utf8(izhod).unwrap(), koda);
+}
+
+fn test_napake(src: &str) -> (String, String) {
     let mut izhod = Vec::<u8>::new();
+    let mut napake = Vec::<u8>::new();
 
     src
         .razčleni("[test]")
         .analiziraj()
         .unwrap()
         .v_program()
-        .zaženi_z_io(&mut Cursor::new(vhod), &mut izhod);
+        .zaženi_z_io(&mut Cursor::new(""), &mut izhod, &mut napake);
+
+    return (String::from_utf8(izhod).unwrap(), String::from_utf8(napake).unwrap());
+}
+
+fn test_argumenti(src: &str, argumenti: &[&str]) -> String {
+    let mut izhod = Vec::<u8>::new();
+
+    src
+        .razčleni("[test]")
+        .analiziraj()
+        .unwrap()
+        .v_program()
+        .z_argumenti(["[test]"].iter().chain(argumenti).map(|a| a.to_string()).collect())
+        .zaženi_z_io(&mut Cursor::new(""), &mut izhod, &mut io::sink());
 
     return String::from_utf8(izhod).unwrap();
 }
@@ -329,3 +361,660 @@ fn konstante() {
     assert_eq!(test(program, ""), "81.25");
 }
 
+#[test]
+fn naštevanje() {
+    let program = r#"
+        naštevanje Barva { Rdeča, Zelena, Modra }
+        spr b = Barva.Zelena
+        natisni(b)
+        natisni(' ')
+        natisni(b kot celo)
+        natisni(' ')
+        natisni!(b == Barva.Zelena, b != Barva.Modra)
+        funkcija naslednja(b: Barva) -> Barva {
+            če b == Barva.Rdeča {
+                vrni Barva.Zelena
+            }
+            vrni Barva.Modra
+        }
+        b = naslednja(Barva.Rdeča)
+        natisni(' ')
+        natisni(b)
+    "#;
+    assert_eq!(test(program, ""), "Zelena 1 resnicaresnica Zelena");
+}
+
+#[test]
+fn izberi() {
+    let program = r#"
+        naštevanje Smer { Sever, Jug, Vzhod, Zahod }
+        za i = 0, i < 4, i += 1 {
+            spr s = Smer.Sever
+            če i == 1 { s = Smer.Jug } čene če i == 2 { s = Smer.Vzhod } čene če i == 3 { s = Smer.Zahod }
+            izberi s {
+                Smer.Sever, Smer.Jug {
+                    natisni('|')
+                }
+                Smer.Vzhod {
+                    natisni('-')
+                }
+                čene {
+                    natisni('?')
+                }
+            }
+        }
+        izberi 2 + 1 {
+            1 { natisni("ena") }
+            3 { natisni("tri") }
+        }
+    "#;
+    assert_eq!(test(program, ""), "||-?tri");
+}
+
+#[test]
+fn celo64_real64() {
+    let program = r#"
+        naj a = 2_147_483_647 kot celo64
+        natisni!(a + 1 kot celo64, '\n')
+        naj b = 3_000_000_000 * 3_000_000_000
+        natisni!(-b / 7 kot celo64, '\n')
+        naj pi = 3.141592653589793 kot real64
+        natisni!(pi, ' ', pi kot real, '\n')
+        natisni!(b > a, (1 kot celo64) kot real64 / (3.0 kot real64))
+    "#;
+    assert_eq!(test(program, ""), "2147483648\n-1285714285714285714\n3.1415926536 3.14159\nresnica0.3333333333");
+}
+
+#[test]
+fn naravno() {
+    let program = r#"
+        funkcija rotiraj(a: naravno, b: celo) -> naravno {
+            vrni (a << b) | (a >> (32 - b))
+        }
+        naj a = 4_000_000_000 kot naravno
+        natisni!(a, ' ', a / 3 kot naravno, ' ', a % 7 kot naravno, '\n')
+        natisni!(a > 1 kot naravno, ' ', -1 kot naravno, ' ', -8 >> 1, ' ', (-8 kot naravno) >> 1, '\n')
+        natisni!(rotiraj(-2 kot naravno, 4), ' ', a kot celo64, ' ', 0 kot naravno - 1 kot naravno)
+    "#;
+    assert_eq!(test(program, ""), "4000000000 1333333333 3\nresnica 4294967295 -4 2147483644\n4294967279 4000000000 4294967295");
+}
+
+#[test]
+fn seznami_vrednosti() {
+    let program = r#"
+        funkcija spremeni(s: [celo; 3]) -> celo {
+            s[0] = 100
+            vrni s[0] + s[1]
+        }
+        funkcija podvoji(s: [celo; 3]) -> [celo; 3] {
+            vrni [s[0] * 2, s[1] * 2, s[2] * 2]
+        }
+        spr a = [1, 2, 3]
+        spr b: [celo; 3]
+        b = a
+        a[0] = 10
+        natisni!(b[0], ' ', a[0], ' ', spremeni(a), ' ', a[0], '\n')
+        naj c = podvoji(a)
+        natisni!(c[0], ' ', c[1], ' ', c[2], '\n')
+        natisni!(a == [10, 2, 3], ' ', a != [10, 2, 3], ' ', a == b, '\n')
+        naj m = [[1.5, 2.5], [3.5, 4.5]]
+        naj n = m
+        natisni!(m == n, ' ', m == [[1.5, 2.5], [3.5, 5.5]], ' ', "abc" == "abc")
+    "#;
+    assert_eq!(test(program, ""), "1 10 102 10\n20 4 6\nresnica laž laž\nresnica laž resnica");
+}
+
+#[test]
+fn večdimenzionalni_seznami() {
+    let program = r#"
+        funkcija vsota(vrstica: @[celo]) -> celo {
+            spr s = 0
+            za i = 0, i < vrstica.dolžina, i += 1 {
+                s += vrstica[i]
+            }
+            vrni s
+        }
+        funkcija sled(m: @[[celo; 3]]) -> celo {
+            vrni m[0][0] + m[1][1]
+        }
+        spr m: [[celo; 3]; 4]
+        za i = 0, i < m.dolžina, i += 1 {
+            za j = 0, j < m[i].dolžina, j += 1 {
+                m[i][j] = i * 10 + j
+            }
+        }
+        natisni!(m[1][2], ' ', m[3][1], ' ', vsota(@m[2]), ' ', sled(@m), '\n')
+        spr r = m[1]
+        m[0] = [7, 8, 9]
+        m[3] = r
+        natisni!(m[0][2], ' ', m[3][2], ' ', m[1] == m[3], '\n')
+        spr k: [[[real; 2]; 2]; 2]
+        k[1][0][1] = 2.5
+        natisni!(k[1][0][1], ' ', k[0][1][1])
+    "#;
+    assert_eq!(test(program, ""), "12 31 63 11\n9 12 resnica\n2.5 0.0");
+}
+
+#[test]
+fn rezine() {
+    let program = r#"
+        funkcija vsota(s: @[celo]) -> celo {
+            spr v = 0
+            za i = 0, i < s.dolžina, i += 1 {
+                v += s[i]
+            }
+            vrni v
+        }
+        funkcija podvoji(s: @[celo]) {
+            za i = 0, i < s.dolžina, i += 1 {
+                s[i] = 2 * s[i]
+            }
+        }
+        spr a = [1, 2, 3, 4, 5, 6]
+        naj r = @a[1..4]
+        natisni!(r.dolžina, ' ', r[0], ' ', vsota(r), '\n')
+        natisni!(vsota(@a[..2]), ' ', vsota(@a[3..]), ' ', vsota(@a[2..2]), '\n')
+        podvoji(@a[4..])
+        naj rr = @r[1..]
+        rr[0] = 0
+        natisni!(a[2], ' ', a[5], ' ', rr.dolžina, '\n')
+        naj niz = "dober dan"
+        natisni(@niz[6..])
+    "#;
+    assert_eq!(test(program, ""), "3 2 9\n3 15 0\n0 12 2\ndan");
+
+    let izven_meja = r#"
+        naj a = [1, 2, 3]
+        natisni("pred\n")
+        naj r = @a[1..5]
+        natisni("za\n")
+    "#;
+    assert_eq!(test_koda(izven_meja, ""), ("pred\n".to_string(), 1));
+    assert_eq!(test_napake(izven_meja).1, "Napaka: rezina je izven meja seznama\n");
+}
+
+#[test]
+fn polna_kopica() {
+    let program = r#"
+        spr s = "" kot niz
+        za i = 0, i < 5000, i += 1 {
+            s = s + "a"
+        }
+        natisni!(s.dolžina, '\n')
+    "#;
+    assert_eq!(test_koda(program, ""), ("".to_string(), 1));
+    assert_eq!(test_napake(program).1, "Napaka: zmanjkalo je prostora na kopici\n");
+}
+
+#[test]
+fn nizi() {
+    let program = r#"
+        spr pozdrav = "zdravo" kot niz
+        spr s = pozdrav + ", " + "svet"
+        natisni!(s, ' ', s.dolžina, ' ', s[1], '\n')
+        natisni!(pozdrav == "zdravo", ' ', pozdrav < s, ' ', s < pozdrav, '\n')
+        natisni!(najdi(s, "svet" kot niz), ' ', vsebuje(s, "luna" kot niz), '\n')
+        spr št = 42 kot niz + " " + (-17) kot niz + " " + 3.25 kot niz
+        natisni!(št, '\n')
+        natisni!("123" kot niz kot celo + 1, ' ', "-2.5" kot niz kot real, '\n')
+        natisni(@s[8..])
+        natisni(preberi(10))
+    "#;
+    assert_eq!(test(program, "vrstica\n"), "zdravo, svet 12 d\nresnica resnica laž\n8 laž\n42 -17 3.25\n124 -2.5\nsvetvrstica\n");
+}
+
+#[test]
+fn interpolacija() {
+    let program = r#"
+        naj x = 42
+        naj y = 3.14159
+        naj ime = "svet" kot niz
+        natisni("x = {x}, y = {y:.2}\n")
+        natisni("[{x:5}] [{x:<5}] [{-x:06}] [{255:04x}]\n")
+        natisni("[{y:8.3}] [{ime:<6}] {x > 3} {{}}\n")
+        naj s = "zdravo, {ime}!"
+        natisni!(s, ' ', s.dolžina)
+    "#;
+    assert_eq!(test(program, ""), "x = 42, y = 3.14\n[   42] [42   ] [-00042] [00ff]\n[   3.142] [svet  ] resnica {}\nzdravo, svet! 13");
+}
+
+#[test]
+fn oblikovanje_realnih() {
+    let program = r#"
+        natisni!(3.14159, ' ', 0.9999999, ' ', -0.5, '\n')
+        natisni(2.5, 8, 3)
+        natisni(-0.0004, 6, 2)
+        natisni(12345.678, 12, 3, Zapis.Znanstveni)
+        natisni(-9.9996, 11, 3, Zapis.Znanstveni)
+        natisni('\n')
+        naj y = 0.000125
+        natisni("{y:.3} {y:.2e} {y:10.1e}|{1.5:.0}")
+    "#;
+    assert_eq!(test(program, ""), "3.14159 1.0 -0.5\n   2.500  0.00   1.235e+04 -1.000e+01\n0.000 1.25e-04    1.3e-04|2");
+}
+
+#[test]
+fn oblikovanje_realnih_skrajnosti() {
+    let program = r#"
+        naj velik = 1000000000000000000000.0
+        naj nič = 0.0
+        natisni!(velik, ' ', -velik, ' ', nič / nič, ' ', 1.0 / nič, ' ', -1.0 / nič, '\n')
+        natisni(velik, 10, 2)
+        natisni(-1.0 / nič, 6, 2)
+        natisni(2.5, 0, 25)
+        natisni('\n')
+        natisni("{velik:.1} {nič / nič:5}|{1.0 / nič:.3e}")
+    "#;
+    assert_eq!(test(program, ""), "1.00000e+21 -1.00000e+21 NaN inf -inf\n  1.00e+21  -inf2.50000000000000000\n1.0e+21   NaN|inf");
+}
+
+#[test]
+fn branje() {
+    let program = r#"
+        spr n = 0
+        spr x = 0.0
+        spr vsota = 0
+        dokler preberi_celo(@n) == Branje.Uspeh {
+            vsota += n
+        }
+        natisni!(vsota, '\n')
+        natisni!(preberi_real(@x), ' ', x, '\n')
+        natisni!(preberi_real(@x), ' ', x, '\n')
+        natisni!(preberi_real(@x), '\n')
+        spr vrstica: [znak; 8]
+        spr dolžina = 0
+        natisni!(preberi_vrstico(@vrstica, @dolžina), ' ')
+        natisni(@vrstica[..dolžina])
+        natisni!(' ', preberi_vrstico(@vrstica, @dolžina), ' ')
+        natisni(@vrstica[..dolžina])
+        natisni!(' ', preberi_besedo(@vrstica, @dolžina), ' ', preberi_celo(@n))
+    "#;
+    assert_eq!(test(program, "1 2\n -3 4x\n-1.5e2 0.25 1e\nkratka\nzelo dolga vrstica\n"),
+        "0\nUspeh -150.0\nUspeh 0.25\nNeveljavno\nUspeh kratka Neveljavno zelo dol Konec Konec");
+
+    let meje = r#"
+        spr n = 0
+        za i = 0, i < 4, i += 1 {
+            naj izid = preberi_celo(@n)
+            natisni!(izid, ' ', n == -2_147_483_647 - 1, ' ', n == 2_147_483_647, '\n')
+        }
+    "#;
+    assert_eq!(test(meje, "-2147483648 2147483647 2147483648 -2147483649"),
+        "Uspeh resnica laž\nUspeh laž resnica\nNeveljavno laž resnica\nNeveljavno laž resnica\n");
+}
+
+#[test]
+fn matematika() {
+    let program = r#"
+        natisni("{koren(2.0):.4} {sin(PI / 6.0):.4} {cos(PI):.4} {tan(PI / 4.0):.4}\n")
+        natisni("{atan2(1.0, -1.0):.4} {exp(1.0):.4} {ln(10.0):.4}\n")
+        natisni("{navzdol(-2.5)} {navzgor(-2.5)} {zaokroži(-2.5)} {zaokroži(2.4)}\n")
+        natisni("{abs(-7)} {abs(7)} {abs(-1.5)}")
+    "#;
+    assert_eq!(test(program, ""), "1.4142 0.5000 -1.0000 1.0000\n2.3562 2.7183 2.3026\n-3.0 -2.0 -3.0 2.0\n7 7 1.5");
+}
+
+#[test]
+fn nakljucno() {
+    let program = r#"
+        seme(123)
+        natisni!(naključno(), ' ', naključno(), '\n')
+        spr vsota = 0
+        za i = 0, i < 100, i += 1 {
+            naj x = naključno(-3, 4)
+            če x < -3 || x >= 4 {
+                natisni("izven meja\n")
+            }
+            vsota += x
+        }
+        naj r = naključno_real()
+        natisni!(vsota, ' ', r >= 0.0 && r < 1.0, '\n')
+        spr s = [1, 2, 3, 4, 5]
+        premešaj(@s)
+        natisni!(s[0], s[1], s[2], s[3], s[4])
+    "#;
+    assert_eq!(test(program, ""), "821630680 646795656\n-14 resnica\n52341");
+}
+
+#[test]
+fn nakljucno_prazen_interval() {
+    let napaka = "[jedro]/nakljucno.slj:52:9: naključno(od, do) zahteva od < do\n";
+    for program in ["natisni(naključno(3, 3))", "natisni(naključno(4, -3))"] {
+        assert_eq!(test_napake(program), ("".to_string(), napaka.to_string()));
+        assert_eq!(test_koda(program, "").1, 1);
+    }
+}
+
+#[test]
+fn urejanje() {
+    let program = r#"
+        spr s: [celo; 10000]
+        seme(42)
+        za i = 0, i < s.dolžina, i += 1 {
+            s[i] = naključno(-100000, 100000)
+        }
+        naj pred = vsota(@s)
+        uredi(@s)
+        spr urejen = resnica
+        za j = 1, j < s.dolžina, j += 1 {
+            če s[j - 1] > s[j] {
+                urejen = laž
+            }
+        }
+        natisni!(urejen, ' ', vsota(@s) == pred, ' ', min(@s) == s[0], ' ', max(@s) == s[9999], '\n')
+        natisni!(binarno_poišči(@s, s[1234]) <= 1234, ' ', binarno_poišči(@s, 100001), '\n')
+
+        spr enaki: [celo; 1000]
+        napolni(@enaki, 7)
+        enaki[500] = 3
+        uredi(@enaki)
+        natisni!(enaki[0], enaki[1], enaki[999], ' ', binarno_poišči(@enaki, 7), '\n')
+
+        spr r: [real; 1000]
+        za k = 0, k < r.dolžina, k += 1 {
+            r[k] = naključno_real()
+        }
+        uredi(@r)
+        obrni(@r)
+        spr padajoč = resnica
+        za l = 1, l < r.dolžina, l += 1 {
+            če r[l - 1] < r[l] {
+                padajoč = laž
+            }
+        }
+        natisni!(padajoč, '\n')
+
+        spr z = ['s', 'l', 'o', 'v', 'e', 'n', 'š', 'č', 'i', 'n', 'a']
+        uredi(@z)
+        natisni(@z)
+    "#;
+    assert_eq!(test(program, ""), "resnica resnica resnica resnica\nresnica -1\n377 1\nresnica\naeilnnosvčš");
+}
+
+#[test]
+fn min_max_prazen_seznam() {
+    let primeri = [
+        ("spr s = [1, 2]\nnatisni(min(@s[0..0]))", "[jedro]/seznami.slj:147:9: min praznega seznama\n"),
+        ("spr s = [1.0]\nnatisni(max(@s[1..]))", "[jedro]/seznami.slj:348:9: max praznega seznama\n"),
+    ];
+    for (program, napaka) in primeri {
+        assert_eq!(test_napake(program), ("".to_string(), napaka.to_string()));
+        assert_eq!(test_koda(program, "").1, 1);
+    }
+}
+
+#[test]
+fn natisni_napako() {
+    let program = r#"
+        natisni("izhod\n")
+        naj koda = 4
+        natisni_napako!("napaka: ", koda, ' ', 2.5, ' ', laž, '\n')
+        natisni_napako("niz {koda}\n")
+        natisni("še")
+    "#;
+    assert_eq!(test_napake(program), ("izhod\nše".to_string(), "napaka: 4 2.5 laž\nniz 4\n".to_string()));
+}
+
+#[test]
+fn cas() {
+    let program = r#"
+        naj začetek = čas_ms()
+        naj začetek_ns = čas_ns()
+        spi(20)
+        naj ms = čas_ms() - začetek
+        naj ns = čas_ns() - začetek_ns
+        natisni!(ms >= 20 kot celo64, ' ', ns >= 20_000_000 kot celo64, ' ', ms < 10_000 kot celo64)
+    "#;
+    assert_eq!(test(program, ""), "resnica resnica resnica");
+}
+
+#[test]
+fn preizkus() {
+    let program = r#"
+        funkcija kvadrat(x: celo) -> celo {
+            vrni x * x
+        }
+        preizkus "uspešen" {
+            trdi(kvadrat(3) == 9, "3 na kvadrat")
+        }
+        preizkus "neuspešen" {
+            natisni("izpis\n")
+            trdi(kvadrat(2) == 5)
+            natisni("nedosegljivo\n")
+        }
+        trdi(kvadrat(2) == 4, "{kvadrat(2)} == 4")
+        natisni("glavni program\n")
+        trdi(laž, "konec")
+    "#;
+    assert_eq!(test_napake(program), ("glavni program\n".to_string(), "[test]:15:9: konec\n".to_string()));
+
+    fn preizkusi(program: &str, vhod: &str) -> Vec<(String, i32, String, String)> {
+        program
+            .razčleni("[test]")
+            .analiziraj_preizkuse()
+            .unwrap()
+            .into_iter()
+            .map(|(ime, drevo)| {
+                let mut izhod = Vec::<u8>::new();
+                let mut napake = Vec::<u8>::new();
+                let koda = drevo.v_program().zaženi_z_io(&mut Cursor::new(vhod), &mut izhod, &mut napake);
+                (ime, koda, String::from_utf8(izhod).unwrap(), String::from_utf8(napake).unwrap())
+            })
+            .collect()
+    }
+
+    assert_eq!(preizkusi(program, ""), [
+        ("uspešen".to_string(), 0, "".to_string(), "".to_string()),
+        ("neuspešen".to_string(), 1, "izpis\n".to_string(), "[test]:10:13: trditev ne drži\n".to_string()),
+    ]);
+
+    // pred preizkusom se izvedejo samo deklaracije, ostala koda na najvišjem nivoju ne
+    let deklaracije = r#"
+        kons N = 3
+        spr g = 10
+        natisni("glavni program\n")
+        spr x = 0
+        preberi_celo(@x)
+        končaj(3)
+        naštevanje Barva { Rdeča, Modra }
+        preizkus "prvi" {
+            trdi(N == 3 && g == 10 && x == 0)
+            natisni("prvi\n")
+        }
+        funkcija kvadrat(a: celo) -> celo {
+            vrni a * a
+        }
+        spr h = kvadrat(4)
+        preizkus "drugi" {
+            trdi(h + g == 26 && Barva.Modra kot celo == 1)
+            natisni("drugi\n")
+        }
+    "#;
+    assert_eq!(preizkusi(deklaracije, "7"), [
+        ("prvi".to_string(), 0, "prvi\n".to_string(), "".to_string()),
+        ("drugi".to_string(), 0, "drugi\n".to_string(), "".to_string()),
+    ]);
+}
+
+#[test]
+fn panika() {
+    let program = r#"
+        funkcija koren(x: celo) -> celo {
+            če x < 0 {
+                panika("negativno število")
+            }
+            spr k = 0
+            dokler (k + 1) * (k + 1) <= x {
+                k += 1
+            }
+            vrni k
+        }
+        trdi(koren(17) == 4)
+        natisni("{koren(9)}\n")
+        natisni("{koren(-1)}\n")
+    "#;
+    assert_eq!(test_napake(program), ("3\n".to_string(), "[test]:4:17: negativno število\n".to_string()));
+    assert_eq!(test_koda(program, "").1, 1);
+}
+
+#[test]
+fn brez_trditev() {
+    let program = r#"
+        trdi(laž, "izpuščena")
+        natisni("naprej")
+    "#;
+    let mut izhod = Vec::<u8>::new();
+    let koda = program
+        .razčleni("[test]")
+        .analiziraj_brez_trditev()
+        .unwrap()
+        .v_program()
+        .zaženi_z_io(&mut Cursor::new(""), &mut izhod, &mut io::sink());
+
+    assert_eq!((String::from_utf8(izhod).unwrap(), koda), ("naprej".to_string(), 0));
+    assert_eq!(test_koda(program, ""), ("".to_string(), 1));
+}
+
+#[test]
+fn lupina() {
+    let vnos = r#"naj x = 5
+x * 2
+funkcija kvadrat(a: celo) -> celo {
+    vrni a * a
+}
+kvadrat(x)
+naj y = neznana
+spr vsota = 0
+za i = 0, i < 4, i += 1 {
+    vsota += i
+}
+natisni("vsota: {vsota}\n")
+naj q = x / (x - x)
+x / (x - x)
+naj sez = [1, 2]
+naj r = @sez[1..3]
+:tip kvadrat(x) kot real
+:drevo 2 * 3
+"niz"
+končaj(x)
+natisni("nedosegljivo")
+"#;
+    let mut lupina = Command::new(env!("CARGO_BIN_EXE_slj"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    lupina.stdin.take().unwrap().write_all(vnos.as_bytes()).unwrap();
+    let izhod = lupina.wait_with_output().unwrap();
+
+    assert_eq!(String::from_utf8(izhod.stdout).unwrap(), "10\n25\nvsota: 6\nreal\n*\n  2\n  3\nniz\n");
+    assert_eq!(String::from_utf8(izhod.stderr).unwrap(), "Napaka E2: Neznana spremenljivka | [vnos 5]:1:9\nNapaka pri izvajanju: DIVI\nNapaka pri izvajanju: DIVI\nNapaka: rezina je izven meja seznama\n");
+    assert_eq!(izhod.status.code(), Some(5));
+}
+
+#[test]
+fn oblika_napak() {
+    let pot = env::temp_dir().join("slj_oblika_napak.slj");
+    std::fs::write(&pot, "naj x = 1\nnatisni(y)\n").unwrap();
+
+    let izhod = Command::new(env!("CARGO_BIN_EXE_slj"))
+        .arg(&pot)
+        .arg("--oblika-napak=json")
+        .output()
+        .unwrap();
+
+    let napake: serde_json::Value = serde_json::from_slice(&izhod.stderr).unwrap();
+    assert_eq!(napake[0]["code"], "E2");
+    assert_eq!(napake[0]["severity"], "error");
+    assert_eq!(napake[0]["file"], pot.to_str().unwrap());
+    assert_eq!(napake[0]["start"], serde_json::json!({ "line": 2, "column": 9 }));
+    assert_eq!(napake[0]["end"], serde_json::json!({ "line": 2, "column": 10 }));
+    assert!(izhod.stdout.is_empty());
+    assert_eq!(izhod.status.code(), Some(1));
+
+    let izhod = Command::new(env!("CARGO_BIN_EXE_slj"))
+        .arg(&pot)
+        .arg("--oblika-napak=xml")
+        .output()
+        .unwrap();
+
+    assert_eq!(String::from_utf8(izhod.stderr).unwrap(), "Napaka: neznana oblika napak 'xml', možne oblike so: besedilo, json, sarif\n");
+    assert_eq!(izhod.status.code(), Some(2));
+}
+
+#[test]
+fn koncaj() {
+    let program = r#"
+        funkcija preveri(x: celo) {
+            če x > 2 {
+                natisni("konec pri {x}\n")
+                končaj(x + 4)
+            }
+        }
+        za i = 0, i < 10, i += 1 {
+            preveri(i)
+        }
+        natisni("nedosegljivo\n")
+    "#;
+    assert_eq!(test_koda(program, ""), ("konec pri 3\n".to_string(), 7));
+    assert_eq!(test_koda("natisni('a')", ""), ("a".to_string(), 0));
+}
+
+#[test]
+fn datoteke() {
+    let pot = env::temp_dir().join("slj_datoteke_interpretiran.txt");
+    let program = r#"
+        naj pot = argument(1)
+        spr d = odpri(pot, Način.Piši)
+        zapiši(d, "prva vrstica\n")
+        zapiši(d, "čšž ")
+        zapri(d)
+        d = odpri(pot, Način.Dodaj)
+        zapiši(d, "-12 3.5\n")
+        natisni!(zapri(d), ' ', zapri(d), '\n')
+
+        d = odpri(pot, Način.Beri)
+        spr vrstica: [znak; 32]
+        spr dolžina = 0
+        preberi_vrstico(d, @vrstica, @dolžina)
+        natisni(@vrstica[..dolžina])
+        preberi_besedo(d, @vrstica, @dolžina)
+        natisni!('|', @vrstica[..dolžina], '|')
+        spr c = 0
+        spr r = 0.0
+        preberi_celo(d, @c)
+        preberi_real(d, @r)
+        natisni!(c, ' ', r, ' ', preberi_znak(d) == '\0', '\n')
+        zapri(d)
+
+        natisni!(odpri("/ni/take/datoteke", Način.Beri), ' ', zapri(-1))
+    "#;
+    let izhod = test_argumenti(program, &[pot.to_str().unwrap()]);
+    std::fs::remove_file(pot).unwrap();
+    assert_eq!(izhod, "resnica laž\nprva vrstica|čšž|-12 3.5 resnica\n-1 laž");
+}
+
+#[test]
+fn argumenti() {
+    env::set_var("SLJ_TEST_OKOLJE", "čaj");
+    let program = r#"
+        natisni("{argumenti()}:")
+        za i = 1, i < argumenti(), i += 1 {
+            natisni(" [{argument(i)}]")
+        }
+        natisni("[{argument(argumenti())}]\n")
+
+        spr buf: [znak; 4]
+        spr d = 0
+        natisni!(argument(1, @buf, @d), ' ', d, ' ', argument(2, @buf, @d), '\n')
+
+        naj vrednost = okolje("SLJ_TEST_OKOLJE")
+        naj ni = okolje("SLJ_TEST_NI")
+        natisni("{vrednost}|{ni}|\n")
+        natisni!(okolje("SLJ_TEST_OKOLJE", @buf, @d), ' ', d, ' ', okolje("SLJ_TEST_NI", @buf, @d))
+    "#;
+    assert_eq!(test_argumenti(program, &["ena", "dva tri", "-d"]),
+        "4: [ena] [dva tri] [-d][]\nresnica 3 laž\nčaj||\nresnica 3 laž");
+}